@@ -452,6 +452,8 @@ impl BehaviorHarness for MedicalRecordSerdeHarness {
                     active: true,
                     did_reference: Some(self.string("did:example:doctor")),
                     qkd_capable: false,
+                    specialty: Some(self.string("cardiology")),
+                    license_hash: None,
                 };
                 let xdr_bytes = profile.to_xdr(&self.env);
                 let deserialized =
@@ -6,9 +6,10 @@
 #[cfg(test)]
 mod test;
 
+use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    IntoVal, Map, String, Symbol, Val, Vec,
 };
 
 // ==================== Existing Types ====================
@@ -50,6 +51,8 @@ pub struct AccessGrant {
     pub expires_at: u64,
     pub is_active: bool,
     pub conditions: Vec<AccessCondition>,
+    /// Delegate that issued the grant on the grantor's behalf, if any
+    pub granted_by: Option<Address>,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -131,6 +134,7 @@ pub struct Delegation {
     pub can_grant: bool,
     pub can_revoke: bool,
     pub can_manage_emergency: bool,
+    pub max_permission: PermissionLevel, // Highest level the delegate may grant
     pub created_at: u64,
     pub expires_at: u64,
     pub is_active: bool,
@@ -176,6 +180,35 @@ pub enum SwapStatus {
     Expired,
 }
 
+/// Outcome of propagating a revocation to the grantee's chain via the bridge
+#[derive(Clone)]
+#[contracttype]
+pub struct RevocationNotice {
+    pub grant_id: u64,
+    pub grantee_chain: ChainId,
+    pub grantee_address: String,
+    pub revoked_by: Address,
+    pub revoked_at: u64,
+    pub notified: bool, // false if the bridge call failed
+}
+
+/// Portable proof of consent; `receipt_hash` is the SHA-256 of the XDR
+/// encoding of every other field, as a tuple in declaration order.
+#[derive(Clone)]
+#[contracttype]
+pub struct ConsentReceipt {
+    pub grant_id: u64,
+    pub grantor: Address,
+    pub grantee_chain: ChainId,
+    pub grantee_address: String,
+    pub permission_level: PermissionLevel,
+    pub record_scope: AccessScope,
+    pub granted_at: u64,
+    pub expires_at: u64,
+    pub is_active: bool,
+    pub receipt_hash: BytesN<32>,
+}
+
 // ==================== Storage Keys (DataKey Enum) ====================
 // BUG FIX: delegation_key and emergency_config_key always returned the same
 // symbol ("deleg_key" / "emerg_key"), causing all delegations and emergency
@@ -200,12 +233,22 @@ pub enum DataKey {
     Delegation(Address, Address), // (delegator, delegate) — was "deleg_key"
     EmergencyConfig(Address),     // patient address — was "emerg_key"
     Swap(u64),
+    GrantConsumed(u64),         // grant_id of a spent SingleUse grant
+    EmergencyAttestations(u64), // request_id — trusted providers that attested
+    PatientAudit(Address),      // patient — audit entry ids, oldest first
+    GrantorGrants(Address),     // grantor — grant ids, oldest first
+    RevocationNotice(u64),      // grant_id
+    GrantEscrow(u64),           // grant_id -> swap_id holding it in escrow
+    RecordsContract,            // medical_records contract used to validate request IDs
+    ValidateRecords,            // bool — check requested records exist before storing
 }
 
 // Constants
 const DEFAULT_GRANT_DURATION: u64 = 2_592_000; // 30 days
 const REQUEST_EXPIRY: u64 = 86_400; // 24 hours
 const DEFAULT_SWAP_DURATION: u64 = 3_600; // 1 hour timelock
+const MAX_PAGE_SIZE: u32 = 50;
+const MAX_BATCH_SIZE: u32 = 50;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -236,6 +279,9 @@ pub enum Error {
     SwapNotFound = 21,
     SwapExpired = 22,
     SwapAlreadyProcessed = 23,
+    InvalidSecret = 24,
+    BatchTooLarge = 25,
+    RecordNotFound = 26,
 }
 
 impl core::fmt::Display for Error {
@@ -264,6 +310,9 @@ impl core::fmt::Display for Error {
             Error::SwapNotFound => write!(f, "swap not found"),
             Error::SwapExpired => write!(f, "swap expired"),
             Error::SwapAlreadyProcessed => write!(f, "swap already processed"),
+            Error::InvalidSecret => write!(f, "invalid secret"),
+            Error::BatchTooLarge => write!(f, "batch too large"),
+            Error::RecordNotFound => write!(f, "record not found"),
         }
     }
 }
@@ -323,21 +372,77 @@ impl CrossChainAccessContract {
         grantor.require_auth();
         Self::require_not_paused(&env)?;
 
-        let now = env.ledger().timestamp();
-        let grant_id = Self::get_and_increment_grant_count(&env)?;
+        Self::store_grant(
+            &env,
+            grantor,
+            None,
+            grantee_chain,
+            grantee_address,
+            permission_level,
+            record_scope,
+            duration,
+            conditions,
+        )
+    }
 
-        let grant = AccessGrant {
-            grant_id,
-            grantor: grantor.clone(),
-            grantee_chain: grantee_chain.clone(),
-            grantee_address: grantee_address.clone(),
+    /// Issue a grant on behalf of `delegator` through a `can_grant` delegation.
+    pub fn grant_access_as_delegate(
+        env: Env,
+        delegate: Address,
+        delegator: Address,
+        grantee_chain: ChainId,
+        grantee_address: String,
+        permission_level: PermissionLevel,
+        record_scope: AccessScope,
+        duration: u64,
+        conditions: Vec<AccessCondition>,
+    ) -> Result<u64, Error> {
+        delegate.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let delegation = Self::active_delegation(&env, &delegator, &delegate)?;
+        if !delegation.can_grant
+            || !Self::permission_sufficient(&delegation.max_permission, &permission_level)
+        {
+            return Err(Error::InsufficientPermissions);
+        }
+
+        Self::store_grant(
+            &env,
+            delegator,
+            Some(delegate),
+            grantee_chain,
+            grantee_address,
             permission_level,
             record_scope,
-            granted_at: now,
-            expires_at: now.checked_add(duration).ok_or(Error::Overflow)?,
-            is_active: true,
+            duration,
             conditions,
-        };
+        )
+    }
+
+    pub fn revoke_access(env: Env, caller: Address, grant_id: u64) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+
+        Self::revoke_grant(&env, &caller, grant_id)?;
+        Ok(true)
+    }
+
+    /// Revoke every grant in `grant_ids` the caller has authority over.
+    /// Unknown, inactive, and unauthorized grants are skipped rather than
+    /// failing the batch; escrowed grants are revoked and their swap
+    /// cancelled. Returns the number revoked.
+    pub fn revoke_access_batch(
+        env: Env,
+        caller: Address,
+        grant_ids: Vec<u64>,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+
+        if grant_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::BatchTooLarge);
+        }
 
         let mut grants: Map<u64, AccessGrant> = env
             .storage()
@@ -345,41 +450,119 @@ impl CrossChainAccessContract {
             .get(&DataKey::Grants)
             .unwrap_or(Map::new(&env));
 
-        grants.set(grant_id, grant);
-        env.storage().persistent().set(&DataKey::Grants, &grants);
+        let mut revoked = 0u32;
+        for grant_id in grant_ids.iter() {
+            let Some(mut grant) = grants.get(grant_id) else {
+                continue;
+            };
+            let escrowed = Self::escrowed_in(&env, grant_id).is_some();
+            if (!grant.is_active && !escrowed) || !Self::can_revoke_access(&env, &caller, &grant) {
+                continue;
+            }
+
+            grant.is_active = false;
+            grants.set(grant_id, grant);
+            Self::cancel_escrowing_swap(&env, &caller, &mut grants, grant_id);
+            revoked += 1;
+
+            env.events().publish(
+                (Symbol::new(&env, "access_revoked"),),
+                (caller.clone(), grant_id),
+            );
+        }
+
+        if revoked > 0 {
+            env.storage().persistent().set(&DataKey::Grants, &grants);
+        }
+
+        Ok(revoked)
+    }
+
+    /// Revoke a grant and tell the grantee's chain through the bridge.
+    ///
+    /// Calls the bridge's
+    /// `submit_access_revoke(source, grant_id, grantee_chain, grantee_address)`,
+    /// which queues an `AccessRevoke` message and requires this contract to be
+    /// one of the bridge's authorized relayers. The call is best-effort: the
+    /// local revocation stands even if the bridge rejects it, and the returned
+    /// flag (also kept on the `RevocationNotice`) says whether it went through.
+    pub fn revoke_and_notify(env: Env, caller: Address, grant_id: u64) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let grant = Self::revoke_grant(&env, &caller, grant_id)?;
+
+        let notified = Self::notify_bridge_revocation(&env, &grant);
+
+        let notice = RevocationNotice {
+            grant_id,
+            grantee_chain: grant.grantee_chain,
+            grantee_address: grant.grantee_address,
+            revoked_by: caller,
+            revoked_at: env.ledger().timestamp(),
+            notified,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::RevocationNotice(grant_id), &notice);
 
         env.events().publish(
-            (Symbol::new(&env, "access_granted"),),
-            (grantor, grantee_chain, grantee_address, grant_id),
+            (Symbol::new(&env, "revocation_notified"),),
+            (grant_id, notified),
         );
 
-        Ok(grant_id)
+        Ok(notified)
     }
 
-    pub fn revoke_access(env: Env, caller: Address, grant_id: u64) -> Result<bool, Error> {
+    pub fn get_revocation_notice(env: Env, grant_id: u64) -> Option<RevocationNotice> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RevocationNotice(grant_id))
+    }
+
+    /// Deactivate any of `grant_ids` that are active but past expiry.
+    /// Anyone may sweep; returns the number of grants deactivated.
+    pub fn sweep_expired_grants(
+        env: Env,
+        caller: Address,
+        grant_ids: Vec<u64>,
+    ) -> Result<u32, Error> {
         caller.require_auth();
         Self::require_not_paused(&env)?;
 
+        if grant_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::BatchTooLarge);
+        }
+
         let mut grants: Map<u64, AccessGrant> = env
             .storage()
             .persistent()
             .get(&DataKey::Grants)
             .unwrap_or(Map::new(&env));
 
-        let mut grant = grants.get(grant_id).ok_or(Error::GrantNotFound)?;
+        let now = env.ledger().timestamp();
+        let mut swept = 0u32;
 
-        if !Self::can_revoke_access(&env, &caller, &grant) {
-            return Err(Error::NotAuthorized);
-        }
+        for grant_id in grant_ids.iter() {
+            if let Some(mut grant) = grants.get(grant_id) {
+                if grant.is_active && now > grant.expires_at {
+                    grant.is_active = false;
+                    grants.set(grant_id, grant.clone());
+                    swept += 1;
 
-        grant.is_active = false;
-        grants.set(grant_id, grant);
-        env.storage().persistent().set(&DataKey::Grants, &grants);
+                    env.events().publish(
+                        (Symbol::new(&env, "GrantExpired"),),
+                        (grant_id, grant.grantor),
+                    );
+                }
+            }
+        }
 
-        env.events()
-            .publish((Symbol::new(&env, "access_revoked"),), (caller, grant_id));
+        if swept > 0 {
+            env.storage().persistent().set(&DataKey::Grants, &grants);
+        }
 
-        Ok(true)
+        Ok(swept)
     }
 
     pub fn update_grant_conditions(
@@ -441,6 +624,33 @@ impl CrossChainAccessContract {
         Ok(true)
     }
 
+    /// Point request validation at a medical_records deployment. When
+    /// `enabled`, `request_access` rejects IDs the records contract does not
+    /// know; disable it for deployments whose records live off-chain.
+    pub fn configure_record_validation(
+        env: Env,
+        caller: Address,
+        records_contract: Address,
+        enabled: bool,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecordsContract, &records_contract);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ValidateRecords, &enabled);
+
+        env.events().publish(
+            (Symbol::new(&env, "record_validation_configured"),),
+            (records_contract, enabled),
+        );
+
+        Ok(true)
+    }
+
     // ==================== Access Request Functions ====================
 
     pub fn request_access(
@@ -453,6 +663,7 @@ impl CrossChainAccessContract {
         is_emergency: bool,
     ) -> Result<u64, Error> {
         Self::require_not_paused(&env)?;
+        Self::require_records_exist(&env, &requested_records)?;
 
         let now = env.ledger().timestamp();
         let request_id = Self::get_and_increment_request_count(&env)?;
@@ -482,10 +693,8 @@ impl CrossChainAccessContract {
             .persistent()
             .set(&DataKey::Requests, &requests);
 
-        if is_emergency {
-            Self::handle_emergency_request(&env, request_id, &requester_address, &patient)?;
-        }
-
+        // The requester is not authenticated, so an emergency request stays
+        // Pending until trusted providers approve it via `attest_emergency`.
         env.events().publish(
             (Symbol::new(&env, "access_requested"),),
             (
@@ -522,7 +731,9 @@ impl CrossChainAccessContract {
         }
 
         let now = env.ledger().timestamp();
-        if replay_protection::check_message_expired(&env, request.created_at, REQUEST_EXPIRY).is_err() {
+        if replay_protection::check_message_expired(&env, request.created_at, REQUEST_EXPIRY)
+            .is_err()
+        {
             request.status = RequestStatus::Expired;
             requests.set(request_id, request);
             env.storage()
@@ -549,7 +760,7 @@ impl CrossChainAccessContract {
             .set(&DataKey::Requests, &requests);
 
         if approve {
-            Self::create_request_grant(&env, &request)?;
+            Self::create_request_grant(&env, &request, DEFAULT_GRANT_DURATION)?;
         }
 
         env.events().publish(
@@ -573,6 +784,7 @@ impl CrossChainAccessContract {
         can_grant: bool,
         can_revoke: bool,
         can_manage_emergency: bool,
+        max_permission: PermissionLevel,
         duration: u64,
     ) -> Result<bool, Error> {
         delegator.require_auth();
@@ -588,6 +800,7 @@ impl CrossChainAccessContract {
             can_grant,
             can_revoke,
             can_manage_emergency,
+            max_permission,
             created_at: now,
             expires_at: now.checked_add(duration).ok_or(Error::Overflow)?,
             is_active: true,
@@ -672,6 +885,100 @@ impl CrossChainAccessContract {
         Ok(true)
     }
 
+    /// Record a trusted provider's attestation for a pending emergency
+    /// request. Once the patient's `required_attestations` is reached the
+    /// request is approved and a grant valid for `auto_approve_duration` is
+    /// issued. Requests older than `REQUEST_EXPIRY` can no longer be attested.
+    /// Returns the number of distinct attestations so far.
+    pub fn attest_emergency(env: Env, attester: Address, request_id: u64) -> Result<u32, Error> {
+        attester.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let mut requests: Map<u64, AccessRequest> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Requests)
+            .unwrap_or(Map::new(&env));
+
+        let mut request = requests.get(request_id).ok_or(Error::RequestNotFound)?;
+
+        if request.status != RequestStatus::Pending {
+            return Err(Error::RequestAlreadyProcessed);
+        }
+        if replay_protection::check_message_expired(&env, request.created_at, REQUEST_EXPIRY)
+            .is_err()
+        {
+            request.status = RequestStatus::Expired;
+            requests.set(request_id, request);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Requests, &requests);
+            return Err(Error::RequestExpired);
+        }
+        if !request.is_emergency {
+            return Err(Error::EmergencyNotEnabled);
+        }
+
+        let config: EmergencyConfig = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EmergencyConfig(request.patient.clone()))
+            .ok_or(Error::EmergencyNotEnabled)?;
+
+        if !config.is_enabled {
+            return Err(Error::EmergencyNotEnabled);
+        }
+        if !config.trusted_providers.contains(attester.to_string()) {
+            return Err(Error::EmergencyNotAuthorized);
+        }
+
+        let attestations_key = DataKey::EmergencyAttestations(request_id);
+        let mut attestations: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&attestations_key)
+            .unwrap_or(Vec::new(&env));
+
+        if !attestations.contains(&attester) {
+            attestations.push_back(attester.clone());
+            env.storage()
+                .persistent()
+                .set(&attestations_key, &attestations);
+
+            env.events().publish(
+                (Symbol::new(&env, "emergency_attested"),),
+                (request_id, attester.clone()),
+            );
+        }
+
+        let count = attestations.len();
+        if count >= config.required_attestations.max(1) {
+            request.status = RequestStatus::Approved;
+            request.decision_by = Some(attester);
+            request.decision_at = Some(env.ledger().timestamp());
+            requests.set(request_id, request.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::Requests, &requests);
+
+            Self::create_request_grant(&env, &request, config.auto_approve_duration)?;
+
+            env.events().publish(
+                (Symbol::new(&env, "emergency_auto_approved"),),
+                (request_id, request.patient),
+            );
+        }
+
+        Ok(count)
+    }
+
+    pub fn get_emergency_attestations(env: Env, request_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EmergencyAttestations(request_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
     // ==================== Audit Functions ====================
 
     pub fn log_access(
@@ -686,38 +993,48 @@ impl CrossChainAccessContract {
     ) -> Result<u64, Error> {
         Self::require_not_paused(&env)?;
 
-        let now = env.ledger().timestamp();
-        let entry_id = Self::get_and_increment_audit_count(&env)?;
-
-        let entry = AuditEntry {
-            entry_id,
-            accessor_chain: accessor_chain.clone(),
-            accessor_address: accessor_address.clone(),
-            patient: patient.clone(),
+        Self::append_audit_entry(
+            &env,
+            accessor_chain,
+            accessor_address,
+            patient,
             record_id,
-            action: action.clone(),
-            timestamp: now,
+            action,
             ip_hash,
             success,
-        };
+        )
+    }
 
-        let mut audit_log: Map<u64, AuditEntry> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AuditLog)
-            .unwrap_or(Map::new(&env));
+    /// A patient's access history, oldest first. Readable by the patient or admin.
+    pub fn get_audit_entries(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<AuditEntry>, Error> {
+        caller.require_auth();
+        Self::require_patient_or_admin(&env, &caller, &patient)?;
 
-        audit_log.set(entry_id, entry);
-        env.storage()
-            .persistent()
-            .set(&DataKey::AuditLog, &audit_log);
+        Ok(Self::page_patient_audit(
+            &env, &patient, page, page_size, false,
+        ))
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "access_logged"),),
-            (accessor_chain, patient, record_id, action, success),
-        );
+    /// Failed access attempts against a patient's records, for security review.
+    pub fn get_failed_accesses(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<AuditEntry>, Error> {
+        caller.require_auth();
+        Self::require_patient_or_admin(&env, &caller, &patient)?;
 
-        Ok(entry_id)
+        Ok(Self::page_patient_audit(
+            &env, &patient, page, page_size, true,
+        ))
     }
 
     // ==================== Atomic Access Swap Functions ====================
@@ -738,21 +1055,32 @@ impl CrossChainAccessContract {
         Self::require_not_paused(&env)?;
 
         // Verify offered grant exists and initiator owns it
-        let grants: Map<u64, AccessGrant> = env
+        let mut grants: Map<u64, AccessGrant> = env
             .storage()
             .persistent()
             .get(&DataKey::Grants)
             .unwrap_or(Map::new(&env));
 
-        let grant = grants.get(offered_grant_id).ok_or(Error::GrantNotFound)?;
+        let mut grant = grants.get(offered_grant_id).ok_or(Error::GrantNotFound)?;
 
         if grant.grantor != initiator {
             return Err(Error::NotAuthorized);
         }
+        if !grant.is_active {
+            return Err(Error::GrantRevoked);
+        }
 
         let now = env.ledger().timestamp();
         let swap_id = Self::get_and_increment_swap_count(&env)?;
 
+        // Hold the offered grant in escrow until the swap settles
+        grant.is_active = false;
+        grants.set(offered_grant_id, grant);
+        env.storage().persistent().set(&DataKey::Grants, &grants);
+        env.storage()
+            .persistent()
+            .set(&DataKey::GrantEscrow(offered_grant_id), &swap_id);
+
         let swap = SwapProposal {
             swap_id,
             initiator: initiator.clone(),
@@ -811,17 +1139,27 @@ impl CrossChainAccessContract {
         }
 
         // Verify the offered grant exists and belongs to acceptor
-        let grants: Map<u64, AccessGrant> = env
+        let mut grants: Map<u64, AccessGrant> = env
             .storage()
             .persistent()
             .get(&DataKey::Grants)
             .unwrap_or(Map::new(&env));
 
-        let counterpart_grant = grants.get(offered_grant_id).ok_or(Error::GrantNotFound)?;
+        let mut counterpart_grant = grants.get(offered_grant_id).ok_or(Error::GrantNotFound)?;
 
         if counterpart_grant.grantor != acceptor {
             return Err(Error::NotAuthorized);
         }
+        if !counterpart_grant.is_active {
+            return Err(Error::GrantRevoked);
+        }
+
+        counterpart_grant.is_active = false;
+        grants.set(offered_grant_id, counterpart_grant);
+        env.storage().persistent().set(&DataKey::Grants, &grants);
+        env.storage()
+            .persistent()
+            .set(&DataKey::GrantEscrow(offered_grant_id), &swap_id);
 
         swap.status = SwapStatus::Accepted;
         swap.accepted_grant_id = offered_grant_id;
@@ -871,19 +1209,64 @@ impl CrossChainAccessContract {
         let secret_hash = env.crypto().sha256(&secret.into());
         let secret_hash_bytes: BytesN<32> = secret_hash.into();
         if secret_hash_bytes != swap.hash_lock {
-            return Err(Error::NotAuthorized);
+            return Err(Error::InvalidSecret);
+        }
+
+        // Both sides must still be held for this swap; a grant revoked in
+        // the meantime has lost its escrow marker and is not revived here.
+        for grant_id in [swap.offered_grant_id, swap.accepted_grant_id] {
+            if Self::escrowed_in(&env, grant_id) != Some(swap_id) {
+                return Err(Error::GrantRevoked);
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::GrantEscrow(grant_id));
         }
 
+        // Release both escrowed grants, each pointed at the other party
+        let mut grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(&env));
+
+        let mut offered = grants
+            .get(swap.offered_grant_id)
+            .ok_or(Error::GrantNotFound)?;
+        offered.grantee_chain = swap.counterpart_chain.clone();
+        offered.grantee_address = swap.counterpart_address.clone();
+        offered.is_active = true;
+        grants.set(swap.offered_grant_id, offered);
+
+        let mut accepted = grants
+            .get(swap.accepted_grant_id)
+            .ok_or(Error::GrantNotFound)?;
+        accepted.grantee_chain = ChainId::Stellar;
+        accepted.grantee_address = swap.initiator.to_string();
+        accepted.is_active = true;
+        grants.set(swap.accepted_grant_id, accepted);
+
+        env.storage().persistent().set(&DataKey::Grants, &grants);
+
         swap.status = SwapStatus::Completed;
         env.storage().persistent().set(&swap_key, &swap);
 
-        env.events()
-            .publish((Symbol::new(&env, "swap_completed"),), (swap_id, caller));
+        env.events().publish(
+            (Symbol::new(&env, "SwapFinalized"),),
+            (
+                swap_id,
+                caller,
+                swap.offered_grant_id,
+                swap.accepted_grant_id,
+            ),
+        );
 
         Ok(true)
     }
 
-    /// Cancel a proposed swap (only initiator or after timelock expiry)
+    /// Cancel an open swap and release still-escrowed grants to their owners.
+    /// The initiator may cancel a proposed swap at any time; an accepted swap
+    /// can only be cancelled by either party once the timelock has passed.
     pub fn cancel_access_swap(env: Env, caller: Address, swap_id: u64) -> Result<bool, Error> {
         caller.require_auth();
         Self::require_not_paused(&env)?;
@@ -895,23 +1278,33 @@ impl CrossChainAccessContract {
             .get::<DataKey, SwapProposal>(&swap_key)
             .ok_or(Error::SwapNotFound)?;
 
-        if swap.status == SwapStatus::Completed || swap.status == SwapStatus::Cancelled {
+        if swap.status != SwapStatus::Proposed && swap.status != SwapStatus::Accepted {
             return Err(Error::SwapAlreadyProcessed);
         }
 
-        let now = env.ledger().timestamp();
-        let is_expired = now > swap.timelock;
-
-        // Can cancel if: initiator (before acceptance), or anyone after timelock
-        if caller != swap.initiator && !is_expired {
-            return Err(Error::NotAuthorized);
-        }
+        let mut grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(&env));
 
-        swap.status = if is_expired {
-            SwapStatus::Expired
+        let is_expired = env.ledger().timestamp() > swap.timelock;
+        let allowed = if swap.status == SwapStatus::Proposed {
+            caller == swap.initiator || is_expired
         } else {
-            SwapStatus::Cancelled
+            let acceptor = grants
+                .get(swap.accepted_grant_id)
+                .map(|grant| grant.grantor);
+            is_expired && (caller == swap.initiator || Some(caller.clone()) == acceptor)
         };
+        if !allowed {
+            return Err(Error::NotAuthorized);
+        }
+
+        Self::release_escrow(&env, &mut grants, &swap);
+        env.storage().persistent().set(&DataKey::Grants, &grants);
+
+        swap.status = SwapStatus::Cancelled;
         env.storage().persistent().set(&swap_key, &swap);
 
         env.events()
@@ -957,6 +1350,108 @@ impl CrossChainAccessContract {
         false
     }
 
+    /// Exercise a single grant, enforcing its expiry, status and every
+    /// attached condition. Unlike `verify_access` this reports why access was
+    /// refused, and it records use: a `SingleUse` grant is consumed and an
+    /// `AuditRequired` grant writes an audit entry.
+    ///
+    /// The caller must be the grantee itself or the bridge relaying for an
+    /// off-chain grantee. An emergency claim is only honoured when the grantee
+    /// is one of the grantor's trusted emergency providers.
+    ///
+    /// `TimeRestricted(start, end)` is a window in seconds since midnight UTC.
+    pub fn verify_grant_access(
+        env: Env,
+        caller: Address,
+        grant_id: u64,
+        is_emergency: bool,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(&env));
+
+        let grant = grants.get(grant_id).ok_or(Error::GrantNotFound)?;
+
+        let bridge: Option<Address> = env.storage().persistent().get(&DataKey::Bridge);
+        if caller.to_string() != grant.grantee_address && Some(caller) != bridge {
+            return Err(Error::NotAuthorized);
+        }
+        if is_emergency && !Self::is_trusted_emergency_provider(&env, &grant) {
+            return Err(Error::EmergencyNotAuthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        if !grant.is_active {
+            return Err(Error::GrantRevoked);
+        }
+        if now > grant.expires_at {
+            return Err(Error::GrantExpired);
+        }
+
+        let consumed_key = DataKey::GrantConsumed(grant_id);
+        let mut single_use = false;
+        let mut audit_required = false;
+
+        for condition in grant.conditions.iter() {
+            match condition {
+                AccessCondition::EmergencyOnly => {
+                    if !is_emergency {
+                        return Err(Error::EmergencyNotAuthorized);
+                    }
+                },
+                AccessCondition::SingleUse => {
+                    if env.storage().persistent().has(&consumed_key) {
+                        return Err(Error::SingleUseConsumed);
+                    }
+                    single_use = true;
+                },
+                AccessCondition::TimeRestricted(start, end) => {
+                    let time_of_day = now % 86_400;
+                    if time_of_day < start || time_of_day > end {
+                        return Err(Error::TimeRestrictionViolated);
+                    }
+                },
+                AccessCondition::AuditRequired => audit_required = true,
+                AccessCondition::RequireConsent => {},
+            }
+        }
+
+        if single_use {
+            env.storage().persistent().set(&consumed_key, &true);
+        }
+
+        if audit_required {
+            let action = if is_emergency {
+                AccessAction::EmergencyAccess
+            } else {
+                AccessAction::View
+            };
+            Self::append_audit_entry(
+                &env,
+                grant.grantee_chain,
+                grant.grantee_address,
+                grant.grantor,
+                0,
+                action,
+                BytesN::from_array(&env, &[0u8; 32]),
+                true,
+            )?;
+        }
+
+        Ok(true)
+    }
+
+    pub fn is_grant_consumed(env: Env, grant_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::GrantConsumed(grant_id))
+    }
+
     // ==================== Query Functions ====================
 
     pub fn get_grant(env: Env, grant_id: u64) -> Option<AccessGrant> {
@@ -969,6 +1464,124 @@ impl CrossChainAccessContract {
         grants.get(grant_id)
     }
 
+    /// Grants issued by `grantor`, oldest first. Readable by the grantor or admin.
+    pub fn get_grants_by_grantor(
+        env: Env,
+        caller: Address,
+        grantor: Address,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<AccessGrant>, Error> {
+        caller.require_auth();
+        Self::require_patient_or_admin(&env, &caller, &grantor)?;
+
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::GrantorGrants(grantor))
+            .unwrap_or(Vec::new(&env));
+        let grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(&env));
+
+        let limit = page_size.min(MAX_PAGE_SIZE);
+        let start = page.saturating_mul(limit);
+        let end = start.saturating_add(limit).min(index.len());
+
+        let mut result = Vec::new(&env);
+        for i in start..end {
+            if let Some(grant) = index.get(i).and_then(|grant_id| grants.get(grant_id)) {
+                result.push_back(grant);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Whether a record falls inside a grant's scope. `record_category` is
+    /// required for `CategoryBased` grants and `record_timestamp` for
+    /// `TimeRanged` grants; omitting the one the scope needs is `InvalidScope`.
+    pub fn is_record_in_scope(
+        env: Env,
+        grant_id: u64,
+        record_id: u64,
+        record_category: Option<String>,
+        record_timestamp: Option<u64>,
+    ) -> Result<bool, Error> {
+        let grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(&env));
+
+        let grant = grants.get(grant_id).ok_or(Error::GrantNotFound)?;
+
+        match grant.record_scope {
+            AccessScope::AllRecords => Ok(true),
+            AccessScope::SpecificRecords(ids) => Ok(ids.contains(record_id)),
+            AccessScope::CategoryBased(category) => {
+                let record_category = record_category.ok_or(Error::InvalidScope)?;
+                Ok(record_category == category)
+            },
+            AccessScope::TimeRanged(start, end) => {
+                let record_timestamp = record_timestamp.ok_or(Error::InvalidScope)?;
+                Ok(record_timestamp >= start && record_timestamp <= end)
+            },
+        }
+    }
+
+    /// Export a verifiable receipt for a grant. Available to the grantor, the
+    /// grantee when it is a Stellar address, and the admin.
+    pub fn export_consent_receipt(
+        env: Env,
+        caller: Address,
+        grant_id: u64,
+    ) -> Result<ConsentReceipt, Error> {
+        caller.require_auth();
+
+        let grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(&env));
+
+        let grant = grants.get(grant_id).ok_or(Error::GrantNotFound)?;
+
+        let is_grantee =
+            grant.grantee_chain == ChainId::Stellar && grant.grantee_address == caller.to_string();
+        if !is_grantee {
+            Self::require_patient_or_admin(&env, &caller, &grant.grantor)?;
+        }
+
+        let canonical = (
+            grant.grant_id,
+            grant.grantor.clone(),
+            grant.grantee_chain.clone(),
+            grant.grantee_address.clone(),
+            grant.permission_level.clone(),
+            grant.record_scope.clone(),
+            grant.granted_at,
+            grant.expires_at,
+            grant.is_active,
+        );
+        let receipt_hash: BytesN<32> = env.crypto().sha256(&canonical.to_xdr(&env)).into();
+
+        Ok(ConsentReceipt {
+            grant_id: grant.grant_id,
+            grantor: grant.grantor,
+            grantee_chain: grant.grantee_chain,
+            grantee_address: grant.grantee_address,
+            permission_level: grant.permission_level,
+            record_scope: grant.record_scope,
+            granted_at: grant.granted_at,
+            expires_at: grant.expires_at,
+            is_active: grant.is_active,
+            receipt_hash,
+        })
+    }
+
     pub fn get_request(env: Env, request_id: u64) -> Option<AccessRequest> {
         let requests: Map<u64, AccessRequest> = env
             .storage()
@@ -1058,6 +1671,17 @@ impl CrossChainAccessContract {
         Ok(())
     }
 
+    fn require_patient_or_admin(
+        env: &Env,
+        caller: &Address,
+        patient: &Address,
+    ) -> Result<(), Error> {
+        if caller == patient {
+            return Ok(());
+        }
+        Self::require_admin(env, caller)
+    }
+
     #[must_use]
     fn require_not_paused(env: &Env) -> Result<(), Error> {
         if env
@@ -1134,6 +1758,79 @@ impl CrossChainAccessContract {
         Ok(new_count)
     }
 
+    fn store_grant(
+        env: &Env,
+        grantor: Address,
+        granted_by: Option<Address>,
+        grantee_chain: ChainId,
+        grantee_address: String,
+        permission_level: PermissionLevel,
+        record_scope: AccessScope,
+        duration: u64,
+        conditions: Vec<AccessCondition>,
+    ) -> Result<u64, Error> {
+        // Only the contract admin holds, and can hand out, Admin access
+        if permission_level == PermissionLevel::Admin && Self::require_admin(env, &grantor).is_err()
+        {
+            return Err(Error::InsufficientPermissions);
+        }
+
+        let now = env.ledger().timestamp();
+        let grant_id = Self::get_and_increment_grant_count(env)?;
+
+        let grant = AccessGrant {
+            grant_id,
+            grantor: grantor.clone(),
+            grantee_chain: grantee_chain.clone(),
+            grantee_address: grantee_address.clone(),
+            permission_level,
+            record_scope,
+            granted_at: now,
+            expires_at: now.checked_add(duration).ok_or(Error::Overflow)?,
+            is_active: true,
+            conditions,
+            granted_by,
+        };
+
+        let mut grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(env));
+
+        grants.set(grant_id, grant);
+        env.storage().persistent().set(&DataKey::Grants, &grants);
+        Self::index_grant(env, &grantor, grant_id);
+
+        env.events().publish(
+            (Symbol::new(env, "access_granted"),),
+            (grantor, grantee_chain, grantee_address, grant_id),
+        );
+
+        Ok(grant_id)
+    }
+
+    fn active_delegation(
+        env: &Env,
+        delegator: &Address,
+        delegate: &Address,
+    ) -> Result<Delegation, Error> {
+        let delegation: Delegation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Delegation(delegator.clone(), delegate.clone()))
+            .ok_or(Error::DelegationNotFound)?;
+
+        if !delegation.is_active {
+            return Err(Error::NotAuthorized);
+        }
+        if env.ledger().timestamp() > delegation.expires_at {
+            return Err(Error::DelegationExpired);
+        }
+
+        Ok(delegation)
+    }
+
     fn can_revoke_access(env: &Env, caller: &Address, grant: &AccessGrant) -> bool {
         if caller == &grant.grantor {
             return true;
@@ -1149,17 +1846,9 @@ impl CrossChainAccessContract {
             }
         }
 
-        // Check delegation
-        if let Some(delegation) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Delegation>(&DataKey::Delegation(grant.grantor.clone(), caller.clone()))
-        {
-            let now = env.ledger().timestamp();
-            return delegation.is_active && delegation.can_revoke && now <= delegation.expires_at;
-        }
-
-        false
+        Self::active_delegation(env, &grant.grantor, caller)
+            .map(|delegation| delegation.can_revoke)
+            .unwrap_or(false)
     }
 
     fn can_process_request(env: &Env, caller: &Address, request: &AccessRequest) -> bool {
@@ -1182,46 +1871,12 @@ impl CrossChainAccessContract {
         false
     }
 
-    fn handle_emergency_request(
+    #[must_use]
+    fn create_request_grant(
         env: &Env,
-        request_id: u64,
-        requester_address: &String,
-        patient: &Address,
+        request: &AccessRequest,
+        duration: u64,
     ) -> Result<(), Error> {
-        if let Some(config) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, EmergencyConfig>(&DataKey::EmergencyConfig(patient.clone()))
-        {
-            if config.is_enabled && config.trusted_providers.contains(requester_address) {
-                let mut requests: Map<u64, AccessRequest> = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Requests)
-                    .unwrap_or(Map::new(&env));
-
-                if let Some(mut request) = requests.get(request_id) {
-                    let now = env.ledger().timestamp();
-                    request.status = RequestStatus::Approved;
-                    request.decision_at = Some(now);
-                    requests.set(request_id, request);
-                    env.storage()
-                        .persistent()
-                        .set(&DataKey::Requests, &requests);
-
-                    env.events().publish(
-                        (Symbol::new(&env, "emergency_auto_approved"),),
-                        (request_id, patient.clone()),
-                    );
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    #[must_use]
-    fn create_request_grant(env: &Env, request: &AccessRequest) -> Result<(), Error> {
         let now = env.ledger().timestamp();
         let grant_id = Self::get_and_increment_grant_count(&env)?;
 
@@ -1233,11 +1888,10 @@ impl CrossChainAccessContract {
             permission_level: PermissionLevel::Read,
             record_scope: AccessScope::SpecificRecords(request.requested_records.clone()),
             granted_at: now,
-            expires_at: now
-                .checked_add(DEFAULT_GRANT_DURATION)
-                .ok_or(Error::Overflow)?,
+            expires_at: now.checked_add(duration).ok_or(Error::Overflow)?,
             is_active: true,
             conditions: Vec::new(&env),
+            granted_by: None,
         };
 
         let mut grants: Map<u64, AccessGrant> = env
@@ -1248,10 +1902,170 @@ impl CrossChainAccessContract {
 
         grants.set(grant_id, grant);
         env.storage().persistent().set(&DataKey::Grants, &grants);
+        Self::index_grant(env, &request.patient, grant_id);
+
+        Ok(())
+    }
+
+    fn revoke_grant(env: &Env, caller: &Address, grant_id: u64) -> Result<AccessGrant, Error> {
+        let mut grants: Map<u64, AccessGrant> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Grants)
+            .unwrap_or(Map::new(env));
+
+        let mut grant = grants.get(grant_id).ok_or(Error::GrantNotFound)?;
+
+        if !Self::can_revoke_access(env, caller, &grant) {
+            return Err(Error::NotAuthorized);
+        }
+
+        grant.is_active = false;
+        grants.set(grant_id, grant.clone());
+        Self::cancel_escrowing_swap(env, caller, &mut grants, grant_id);
+        env.storage().persistent().set(&DataKey::Grants, &grants);
+
+        env.events().publish(
+            (Symbol::new(env, "access_revoked"),),
+            (caller.clone(), grant_id),
+        );
+
+        Ok(grant)
+    }
+
+    fn is_trusted_emergency_provider(env: &Env, grant: &AccessGrant) -> bool {
+        env.storage()
+            .persistent()
+            .get::<DataKey, EmergencyConfig>(&DataKey::EmergencyConfig(grant.grantor.clone()))
+            .map(|config| {
+                config.is_enabled && config.trusted_providers.contains(&grant.grantee_address)
+            })
+            .unwrap_or(false)
+    }
+
+    fn escrowed_in(env: &Env, grant_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GrantEscrow(grant_id))
+    }
+
+    /// Give back every grant still escrowed for `swap`, reactivating it.
+    /// Grants whose escrow marker is gone (revoked since) stay inactive.
+    fn release_escrow(env: &Env, grants: &mut Map<u64, AccessGrant>, swap: &SwapProposal) {
+        for grant_id in [swap.offered_grant_id, swap.accepted_grant_id] {
+            if Self::escrowed_in(env, grant_id) != Some(swap.swap_id) {
+                continue;
+            }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::GrantEscrow(grant_id));
+            if let Some(mut grant) = grants.get(grant_id) {
+                grant.is_active = true;
+                grants.set(grant_id, grant);
+            }
+        }
+    }
+
+    /// Revoking an escrowed grant cancels the swap holding it: the revoked
+    /// grant loses its escrow marker and the other side is handed back.
+    fn cancel_escrowing_swap(
+        env: &Env,
+        caller: &Address,
+        grants: &mut Map<u64, AccessGrant>,
+        grant_id: u64,
+    ) {
+        let Some(swap_id) = Self::escrowed_in(env, grant_id) else {
+            return;
+        };
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GrantEscrow(grant_id));
+
+        let swap_key = DataKey::Swap(swap_id);
+        let Some(mut swap) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, SwapProposal>(&swap_key)
+        else {
+            return;
+        };
+        if swap.status != SwapStatus::Proposed && swap.status != SwapStatus::Accepted {
+            return;
+        }
+        Self::release_escrow(env, grants, &swap);
+        swap.status = SwapStatus::Cancelled;
+        env.storage().persistent().set(&swap_key, &swap);
+
+        env.events().publish(
+            (Symbol::new(env, "swap_cancelled"),),
+            (swap_id, caller.clone()),
+        );
+    }
 
+    fn notify_bridge_revocation(env: &Env, grant: &AccessGrant) -> bool {
+        let Some(bridge) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Address>(&DataKey::Bridge)
+        else {
+            return false;
+        };
+
+        let args = soroban_sdk::vec![
+            env,
+            env.current_contract_address().into_val(env),
+            grant.grant_id.into_val(env),
+            grant.grantee_chain.clone().into_val(env),
+            grant.grantee_address.clone().into_val(env),
+        ];
+        env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &bridge,
+            &Symbol::new(env, "submit_access_revoke"),
+            args,
+        )
+        .is_ok()
+    }
+
+    fn require_records_exist(env: &Env, record_ids: &Vec<u64>) -> Result<(), Error> {
+        let enabled: bool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ValidateRecords)
+            .unwrap_or(false);
+        if !enabled {
+            return Ok(());
+        }
+
+        let records: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RecordsContract)
+            .ok_or(Error::RecordNotFound)?;
+
+        for record_id in record_ids.iter() {
+            let exists: bool = env.invoke_contract(
+                &records,
+                &Symbol::new(env, "record_exists"),
+                soroban_sdk::vec![env, record_id.into_val(env)],
+            );
+            if !exists {
+                return Err(Error::RecordNotFound);
+            }
+        }
         Ok(())
     }
 
+    fn index_grant(env: &Env, grantor: &Address, grant_id: u64) {
+        let index_key = DataKey::GrantorGrants(grantor.clone());
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or(Vec::new(env));
+        index.push_back(grant_id);
+        env.storage().persistent().set(&index_key, &index);
+    }
+
     fn permission_sufficient(granted: &PermissionLevel, required: &PermissionLevel) -> bool {
         match (granted, required) {
             (PermissionLevel::Admin, _) => true,
@@ -1274,6 +2088,111 @@ impl CrossChainAccessContract {
         }
     }
 
+    fn append_audit_entry(
+        env: &Env,
+        accessor_chain: ChainId,
+        accessor_address: String,
+        patient: Address,
+        record_id: u64,
+        action: AccessAction,
+        ip_hash: BytesN<32>,
+        success: bool,
+    ) -> Result<u64, Error> {
+        let entry_id = Self::get_and_increment_audit_count(env)?;
+
+        let entry = AuditEntry {
+            entry_id,
+            accessor_chain: accessor_chain.clone(),
+            accessor_address,
+            patient: patient.clone(),
+            record_id,
+            action: action.clone(),
+            timestamp: env.ledger().timestamp(),
+            ip_hash,
+            success,
+        };
+
+        let mut audit_log: Map<u64, AuditEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuditLog)
+            .unwrap_or(Map::new(env));
+
+        audit_log.set(entry_id, entry);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuditLog, &audit_log);
+
+        let index_key = DataKey::PatientAudit(patient.clone());
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or(Vec::new(env));
+        index.push_back(entry_id);
+        env.storage().persistent().set(&index_key, &index);
+
+        env.events().publish(
+            (Symbol::new(env, "access_logged"),),
+            (accessor_chain, patient, record_id, action, success),
+        );
+
+        Ok(entry_id)
+    }
+
+    fn page_patient_audit(
+        env: &Env,
+        patient: &Address,
+        page: u32,
+        page_size: u32,
+        failed_only: bool,
+    ) -> Vec<AuditEntry> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PatientAudit(patient.clone()))
+            .unwrap_or(Vec::new(env));
+        let audit_log: Map<u64, AuditEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuditLog)
+            .unwrap_or(Map::new(env));
+
+        let limit = page_size.min(MAX_PAGE_SIZE);
+        let skip = page.saturating_mul(limit);
+        let mut entries = Vec::new(env);
+
+        // Unfiltered pages are sliced straight out of the index, so the cost
+        // stays bounded by the page size however long the log grows.
+        if !failed_only {
+            let end = skip.saturating_add(limit).min(index.len());
+            for i in skip..end {
+                if let Some(entry) = index.get(i).and_then(|entry_id| audit_log.get(entry_id)) {
+                    entries.push_back(entry);
+                }
+            }
+            return entries;
+        }
+
+        let mut matched = 0u32;
+        for entry_id in index.iter() {
+            if entries.len() >= limit {
+                break;
+            }
+            if let Some(entry) = audit_log.get(entry_id) {
+                if entry.success {
+                    continue;
+                }
+                if matched >= skip {
+                    entries.push_back(entry);
+                }
+                matched += 1;
+            }
+        }
+
+        entries
+    }
+
     fn conditions_met(_env: &Env, conditions: &Vec<AccessCondition>, now: u64) -> bool {
         for condition in conditions.iter() {
             match condition {
@@ -2,7 +2,11 @@
 #![allow(clippy::expect_used)]
 use super::*;
 use crate::SwapStatus;
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String, Vec,
+};
 
 fn create_contract(
     env: &Env,
@@ -32,6 +36,60 @@ fn initialize_contract(
     client.initialize(admin, bridge, identity);
 }
 
+/// Stand-in bridge that counts `AccessRevoke` submissions.
+#[contract]
+pub struct MockBridge;
+
+#[contractimpl]
+impl MockBridge {
+    pub fn submit_access_revoke(
+        env: Env,
+        _source: Address,
+        grant_id: u64,
+        grantee_chain: ChainId,
+        grantee_address: String,
+    ) -> u64 {
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("revokes"))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("revokes"), &(count + 1));
+        env.storage().instance().set(
+            &symbol_short!("last"),
+            &(grant_id, grantee_chain, grantee_address),
+        );
+        count + 1
+    }
+
+    pub fn revoke_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("revokes"))
+            .unwrap_or(0)
+    }
+
+    pub fn last_revoke(env: Env) -> (u64, ChainId, String) {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("last"))
+            .unwrap()
+    }
+}
+
+/// Stand-in medical_records contract that knows record IDs 1 through 10.
+#[contract]
+pub struct MockRecords;
+
+#[contractimpl]
+impl MockRecords {
+    pub fn record_exists(_env: Env, record_id: u64) -> bool {
+        (1..=10).contains(&record_id)
+    }
+}
+
 fn generate_ip_hash(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[1u8; 32])
 }
@@ -266,6 +324,205 @@ fn test_update_grant_conditions() {
     assert_eq!(grant.conditions.len(), 1);
 }
 
+#[test]
+fn test_get_grants_by_grantor() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let other_patient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let first = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    let other = grant_with_conditions(&env, &client, &other_patient, Vec::new(&env));
+    let second = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    let third = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+
+    let grants = client.get_grants_by_grantor(&patient, &patient, &0, &10);
+    assert_eq!(grants.len(), 3);
+    assert_eq!(grants.get(0).unwrap().grant_id, first);
+    assert_eq!(grants.get(1).unwrap().grant_id, second);
+    assert_eq!(grants.get(2).unwrap().grant_id, third);
+
+    let page = client.get_grants_by_grantor(&admin, &patient, &1, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().grant_id, third);
+
+    let others = client.get_grants_by_grantor(&other_patient, &other_patient, &0, &10);
+    assert_eq!(others.len(), 1);
+    assert_eq!(others.get(0).unwrap().grant_id, other);
+
+    let result = client.try_get_grants_by_grantor(&stranger, &patient, &0, &10);
+    assert_eq!(result.err(), Some(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_sweep_expired_grants() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let short_lived = client.grant_access(
+        &patient,
+        &ChainId::Ethereum,
+        &String::from_str(&env, "0xdoctor"),
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &3600,
+        &Vec::new(&env),
+    );
+    let long_lived = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+
+    let ids = soroban_sdk::vec![&env, short_lived, long_lived, 999];
+    assert_eq!(client.sweep_expired_grants(&keeper, &ids), 1);
+    assert!(!client.get_grant(&short_lived).unwrap().is_active);
+    assert!(client.get_grant(&long_lived).unwrap().is_active);
+
+    // Already swept grants are not counted again
+    assert_eq!(client.sweep_expired_grants(&keeper, &ids), 0);
+
+    let mut too_many = Vec::new(&env);
+    for id in 0..51u64 {
+        too_many.push_back(id);
+    }
+    let result = client.try_sweep_expired_grants(&keeper, &too_many);
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge)));
+}
+
+#[test]
+fn test_revoke_and_notify_sends_one_bridge_message() {
+    let env = Env::default();
+    let (client, admin, _, identity) = create_contract(&env);
+    let bridge = env.register_contract(None, MockBridge);
+    let bridge_client = MockBridgeClient::new(&env, &bridge);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let grant_id = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+
+    assert!(client.revoke_and_notify(&patient, &grant_id));
+    assert!(!client.get_grant(&grant_id).unwrap().is_active);
+
+    assert_eq!(bridge_client.revoke_count(), 1);
+    let (sent_id, sent_chain, sent_address) = bridge_client.last_revoke();
+    assert_eq!(sent_id, grant_id);
+    assert_eq!(sent_chain, ChainId::Ethereum);
+    assert_eq!(sent_address, String::from_str(&env, "0xdoctor"));
+
+    let notice = client.get_revocation_notice(&grant_id).unwrap();
+    assert!(notice.notified);
+    assert_eq!(notice.revoked_by, patient);
+}
+
+#[test]
+fn test_revoke_and_notify_survives_bridge_failure() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let grant_id = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+
+    let result = client.try_revoke_and_notify(&stranger, &grant_id);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    // `bridge` is not a deployed contract, so the notification fails
+    assert!(!client.revoke_and_notify(&patient, &grant_id));
+    assert!(!client.get_grant(&grant_id).unwrap().is_active);
+    assert!(!client.get_revocation_notice(&grant_id).unwrap().notified);
+}
+
+#[test]
+fn test_consent_receipt_hash_is_stable() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let grant_id = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+
+    let first = client.export_consent_receipt(&patient, &grant_id);
+    let second = client.export_consent_receipt(&admin, &grant_id);
+    assert_eq!(first.receipt_hash, second.receipt_hash);
+    assert_eq!(first.grantor, patient);
+    assert_eq!(first.grantee_address, String::from_str(&env, "0xdoctor"));
+
+    // Any change to the grant changes the receipt
+    client.revoke_access(&patient, &grant_id);
+    let revoked = client.export_consent_receipt(&patient, &grant_id);
+    assert!(!revoked.is_active);
+    assert_ne!(revoked.receipt_hash, first.receipt_hash);
+
+    let result = client.try_export_consent_receipt(&stranger, &grant_id);
+    assert_eq!(result.err(), Some(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_stellar_grantee_can_export_consent_receipt() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let doctor = Address::generate(&env);
+
+    let grant_id = client.grant_access(
+        &patient,
+        &ChainId::Stellar,
+        &doctor.to_string(),
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let receipt = client.export_consent_receipt(&doctor, &grant_id);
+    assert_eq!(receipt.grant_id, grant_id);
+    assert_eq!(receipt.grantee_chain, ChainId::Stellar);
+}
+
+#[test]
+fn test_revoke_access_batch_skips_unowned_grants() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let other_patient = Address::generate(&env);
+
+    let owned_a = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    let owned_b = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    let inactive = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    let unowned = grant_with_conditions(&env, &client, &other_patient, Vec::new(&env));
+    client.revoke_access(&patient, &inactive);
+
+    let ids = soroban_sdk::vec![&env, owned_a, unowned, inactive, owned_b, 999];
+    assert_eq!(client.revoke_access_batch(&patient, &ids), 2);
+
+    assert!(!client.get_grant(&owned_a).unwrap().is_active);
+    assert!(!client.get_grant(&owned_b).unwrap().is_active);
+    assert!(client.get_grant(&unowned).unwrap().is_active);
+
+    let mut too_many = Vec::new(&env);
+    for id in 0..51u64 {
+        too_many.push_back(id);
+    }
+    let result = client.try_revoke_access_batch(&patient, &too_many);
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge)));
+}
+
 // ==================== Access Request Tests ====================
 
 #[test]
@@ -413,6 +670,55 @@ fn test_process_request_not_authorized() {
     assert_eq!(result, Err(Ok(Error::NotAuthorized)));
 }
 
+#[test]
+fn test_request_access_rejects_unknown_record() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let records = env.register_contract(None, MockRecords);
+    client.configure_record_validation(&admin, &records, &true);
+
+    let patient = Address::generate(&env);
+    let requester_address = String::from_str(&env, "0xabcdef1234567890");
+    let purpose = String::from_str(&env, "Medical consultation");
+
+    let result = client.try_request_access(
+        &ChainId::Ethereum,
+        &requester_address,
+        &patient,
+        &soroban_sdk::vec![&env, 2u64, 404u64],
+        &purpose,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(Error::RecordNotFound)));
+
+    let request_id = client.request_access(
+        &ChainId::Ethereum,
+        &requester_address,
+        &patient,
+        &soroban_sdk::vec![&env, 2u64, 3u64],
+        &purpose,
+        &false,
+    );
+    assert_eq!(request_id, 1);
+
+    // Off-chain deployments can switch the check off
+    client.configure_record_validation(&admin, &records, &false);
+    client.request_access(
+        &ChainId::Ethereum,
+        &requester_address,
+        &patient,
+        &soroban_sdk::vec![&env, 404u64],
+        &purpose,
+        &false,
+    );
+
+    let stranger = Address::generate(&env);
+    let result = client.try_configure_record_validation(&stranger, &records, &true);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
 // ==================== Delegation Tests ====================
 
 #[test]
@@ -431,9 +737,10 @@ fn test_create_delegation() {
         &delegate,
         &ChainId::Stellar,
         &String::from_str(&env, ""),
-        &true,    // can_grant
-        &true,    // can_revoke
-        &false,   // can_manage_emergency
+        &true,  // can_grant
+        &true,  // can_revoke
+        &false, // can_manage_emergency
+        &PermissionLevel::Write,
         &2592000, // 30 days
     );
 
@@ -468,6 +775,7 @@ fn test_create_delegation_with_chain() {
         &true,
         &false,
         &true,
+        &PermissionLevel::Write,
         &86400,
     );
 
@@ -495,6 +803,7 @@ fn test_revoke_delegation() {
         &true,
         &true,
         &false,
+        &PermissionLevel::Write,
         &86400,
     );
 
@@ -520,6 +829,114 @@ fn test_revoke_delegation_not_found() {
     assert_eq!(result, Err(Ok(Error::DelegationNotFound)));
 }
 
+#[test]
+fn test_delegate_grants_and_revokes_access() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xdoctor");
+
+    env.mock_all_auths();
+
+    client.create_delegation(
+        &patient,
+        &delegate,
+        &ChainId::Stellar,
+        &String::from_str(&env, ""),
+        &true,
+        &true,
+        &false,
+        &PermissionLevel::Write,
+        &86400,
+    );
+
+    let grant_id = client.grant_access_as_delegate(
+        &delegate,
+        &patient,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let grant = client.get_grant(&grant_id).unwrap();
+    assert_eq!(grant.grantor, patient);
+    assert_eq!(grant.granted_by, Some(delegate.clone()));
+    assert!(grant.is_active);
+
+    assert!(client.revoke_access(&delegate, &grant_id));
+    assert!(!client.get_grant(&grant_id).unwrap().is_active);
+}
+
+#[test]
+fn test_delegate_grant_rejected_without_valid_delegation() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let revoker = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xdoctor");
+
+    env.mock_all_auths();
+
+    client.create_delegation(
+        &patient,
+        &delegate,
+        &ChainId::Stellar,
+        &String::from_str(&env, ""),
+        &true,
+        &false,
+        &false,
+        &PermissionLevel::Write,
+        &3600,
+    );
+    // Revoke-only delegate cannot issue grants
+    client.create_delegation(
+        &patient,
+        &revoker,
+        &ChainId::Stellar,
+        &String::from_str(&env, ""),
+        &false,
+        &true,
+        &false,
+        &PermissionLevel::Write,
+        &86400,
+    );
+
+    let result = client.try_grant_access_as_delegate(
+        &revoker,
+        &patient,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientPermissions)));
+
+    env.ledger().with_mut(|li| li.timestamp += 3601);
+
+    let result = client.try_grant_access_as_delegate(
+        &delegate,
+        &patient,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::DelegationExpired)));
+}
+
 // ==================== Emergency Access Tests ====================
 
 #[test]
@@ -573,54 +990,159 @@ fn test_configure_emergency_disabled() {
     assert!(!config.is_enabled);
 }
 
-// ==================== Audit Log Tests ====================
-
 #[test]
-fn test_log_access() {
+fn test_emergency_attestations_reach_threshold() {
     let env = Env::default();
     let (client, admin, bridge, identity) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &bridge, &identity);
 
     let patient = Address::generate(&env);
-    let accessor_address = String::from_str(&env, "0xdoctor123");
-    let ip_hash = generate_ip_hash(&env);
+    let hospital_a = Address::generate(&env);
+    let hospital_b = Address::generate(&env);
+    let trusted_providers = soroban_sdk::vec![&env, hospital_a.to_string(), hospital_b.to_string()];
 
     env.mock_all_auths();
 
-    let entry_id = client.log_access(
+    client.configure_emergency(&patient, &true, &3600, &2, &trusted_providers);
+
+    let request_id = client.request_access(
         &ChainId::Ethereum,
-        &accessor_address,
+        &String::from_str(&env, "0xparamedic"),
         &patient,
-        &1, // record_id
-        &AccessAction::View,
-        &ip_hash,
-        &true, // success
+        &soroban_sdk::vec![&env, 7u64],
+        &String::from_str(&env, "Cardiac arrest"),
+        &true,
     );
 
-    assert!(entry_id > 0);
+    // Repeated attestations from the same provider count once
+    assert_eq!(client.attest_emergency(&hospital_a, &request_id), 1);
+    assert_eq!(client.attest_emergency(&hospital_a, &request_id), 1);
+    assert!(client.get_request(&request_id).unwrap().status == RequestStatus::Pending);
+    assert!(client.get_grant(&1).is_none());
 
-    let entry = client.get_audit_entry(&entry_id).unwrap();
-    assert_eq!(entry.patient, patient);
-    assert_eq!(entry.record_id, 1);
-    assert!(entry.action == AccessAction::View);
-    assert!(entry.success);
+    assert_eq!(client.attest_emergency(&hospital_b, &request_id), 2);
+
+    let request = client.get_request(&request_id).unwrap();
+    assert!(request.status == RequestStatus::Approved);
+    assert_eq!(request.decision_by, Some(hospital_b));
+
+    let grant = client.get_grant(&1).unwrap();
+    assert_eq!(grant.grantor, patient);
+    assert_eq!(grant.grantee_address, String::from_str(&env, "0xparamedic"));
+    assert_eq!(grant.expires_at, grant.granted_at + 3600);
 }
 
 #[test]
-fn test_log_access_failed() {
+fn test_emergency_attestation_below_threshold_and_untrusted() {
     let env = Env::default();
     let (client, admin, bridge, identity) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &bridge, &identity);
 
     let patient = Address::generate(&env);
-    let accessor_address = String::from_str(&env, "0xunauthorized");
-    let ip_hash = generate_ip_hash(&env);
+    let hospital = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let trusted_providers = soroban_sdk::vec![&env, hospital.to_string()];
 
     env.mock_all_auths();
 
-    let entry_id = client.log_access(
-        &ChainId::Polygon,
-        &accessor_address,
+    client.configure_emergency(&patient, &true, &3600, &3, &trusted_providers);
+
+    let request_id = client.request_access(
+        &ChainId::Ethereum,
+        &String::from_str(&env, "0xparamedic"),
+        &patient,
+        &soroban_sdk::vec![&env, 7u64],
+        &String::from_str(&env, "Trauma"),
+        &true,
+    );
+
+    let result = client.try_attest_emergency(&stranger, &request_id);
+    assert_eq!(result, Err(Ok(Error::EmergencyNotAuthorized)));
+
+    assert_eq!(client.attest_emergency(&hospital, &request_id), 1);
+    assert!(client.get_request(&request_id).unwrap().status == RequestStatus::Pending);
+    assert_eq!(client.get_emergency_attestations(&request_id).len(), 1);
+}
+
+#[test]
+fn test_emergency_attestation_after_expiry_fails() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let hospital = Address::generate(&env);
+    let trusted_providers = soroban_sdk::vec![&env, hospital.to_string()];
+
+    env.mock_all_auths();
+
+    client.configure_emergency(&patient, &true, &3600, &1, &trusted_providers);
+
+    let request_id = client.request_access(
+        &ChainId::Ethereum,
+        &String::from_str(&env, "0xparamedic"),
+        &patient,
+        &soroban_sdk::vec![&env, 7u64],
+        &String::from_str(&env, "Stroke"),
+        &true,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+
+    let result = client.try_attest_emergency(&hospital, &request_id);
+    assert_eq!(result, Err(Ok(Error::RequestExpired)));
+    assert!(client.get_emergency_attestations(&request_id).is_empty());
+    assert!(client.get_grant(&1).is_none());
+}
+
+// ==================== Audit Log Tests ====================
+
+#[test]
+fn test_log_access() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let accessor_address = String::from_str(&env, "0xdoctor123");
+    let ip_hash = generate_ip_hash(&env);
+
+    env.mock_all_auths();
+
+    let entry_id = client.log_access(
+        &ChainId::Ethereum,
+        &accessor_address,
+        &patient,
+        &1, // record_id
+        &AccessAction::View,
+        &ip_hash,
+        &true, // success
+    );
+
+    assert!(entry_id > 0);
+
+    let entry = client.get_audit_entry(&entry_id).unwrap();
+    assert_eq!(entry.patient, patient);
+    assert_eq!(entry.record_id, 1);
+    assert!(entry.action == AccessAction::View);
+    assert!(entry.success);
+}
+
+#[test]
+fn test_log_access_failed() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let accessor_address = String::from_str(&env, "0xunauthorized");
+    let ip_hash = generate_ip_hash(&env);
+
+    env.mock_all_auths();
+
+    let entry_id = client.log_access(
+        &ChainId::Polygon,
+        &accessor_address,
         &patient,
         &5,
         &AccessAction::Download,
@@ -659,6 +1181,86 @@ fn test_log_emergency_access() {
     assert!(entry.action == AccessAction::EmergencyAccess);
 }
 
+#[test]
+fn test_patient_reads_own_audit_entries() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let other_patient = Address::generate(&env);
+    let accessor_address = String::from_str(&env, "0xdoctor");
+    let ip_hash = generate_ip_hash(&env);
+
+    env.mock_all_auths();
+
+    for (record_id, success) in [(1u64, true), (2, false), (3, true), (4, false)] {
+        client.log_access(
+            &ChainId::Ethereum,
+            &accessor_address,
+            &patient,
+            &record_id,
+            &AccessAction::View,
+            &ip_hash,
+            &success,
+        );
+    }
+    client.log_access(
+        &ChainId::Ethereum,
+        &accessor_address,
+        &other_patient,
+        &9,
+        &AccessAction::View,
+        &ip_hash,
+        &false,
+    );
+
+    let entries = client.get_audit_entries(&patient, &patient, &0, &10);
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries.get(0).unwrap().record_id, 1);
+
+    let second_page = client.get_audit_entries(&patient, &patient, &1, &3);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().record_id, 4);
+
+    let failed = client.get_failed_accesses(&admin, &patient, &0, &10);
+    assert_eq!(failed.len(), 2);
+    assert_eq!(failed.get(0).unwrap().record_id, 2);
+    assert_eq!(failed.get(1).unwrap().record_id, 4);
+
+    let failed_page = client.get_failed_accesses(&patient, &patient, &1, &1);
+    assert_eq!(failed_page.len(), 1);
+    assert_eq!(failed_page.get(0).unwrap().record_id, 4);
+}
+
+#[test]
+fn test_unrelated_address_cannot_read_audit_entries() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.log_access(
+        &ChainId::Ethereum,
+        &String::from_str(&env, "0xdoctor"),
+        &patient,
+        &1,
+        &AccessAction::View,
+        &generate_ip_hash(&env),
+        &true,
+    );
+
+    let result = client.try_get_audit_entries(&stranger, &patient, &0, &10);
+    assert_eq!(result.err(), Some(Ok(Error::NotAuthorized)));
+
+    let result = client.try_get_failed_accesses(&stranger, &patient, &0, &10);
+    assert_eq!(result.err(), Some(Ok(Error::NotAuthorized)));
+}
+
 // ==================== Access Verification Tests ====================
 
 #[test]
@@ -796,6 +1398,292 @@ fn test_verify_access_specific_records() {
     assert!(!has_access);
 }
 
+fn grant_with_conditions(
+    env: &Env,
+    client: &CrossChainAccessContractClient,
+    patient: &Address,
+    conditions: Vec<AccessCondition>,
+) -> u64 {
+    client.grant_access(
+        patient,
+        &ChainId::Ethereum,
+        &String::from_str(env, "0xdoctor"),
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &conditions,
+    )
+}
+
+#[test]
+fn test_verify_grant_access_expiry_and_revocation() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let grant_id = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+
+    assert!(client.verify_grant_access(&bridge, &grant_id, &false));
+
+    let result = client.try_verify_grant_access(&bridge, &999, &false);
+    assert_eq!(result, Err(Ok(Error::GrantNotFound)));
+
+    client.revoke_access(&patient, &grant_id);
+    let result = client.try_verify_grant_access(&bridge, &grant_id, &false);
+    assert_eq!(result, Err(Ok(Error::GrantRevoked)));
+
+    let fresh_id = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    env.ledger().with_mut(|li| li.timestamp += 86401);
+    let result = client.try_verify_grant_access(&bridge, &fresh_id, &false);
+    assert_eq!(result, Err(Ok(Error::GrantExpired)));
+}
+
+#[test]
+fn test_verify_grant_access_emergency_only() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let conditions = soroban_sdk::vec![&env, AccessCondition::EmergencyOnly];
+    let grant_id = grant_with_conditions(&env, &client, &patient, conditions);
+
+    let result = client.try_verify_grant_access(&bridge, &grant_id, &false);
+    assert_eq!(result, Err(Ok(Error::EmergencyNotAuthorized)));
+
+    // Claiming an emergency needs the grantee to be a trusted provider
+    let result = client.try_verify_grant_access(&bridge, &grant_id, &true);
+    assert_eq!(result, Err(Ok(Error::EmergencyNotAuthorized)));
+
+    client.configure_emergency(
+        &patient,
+        &true,
+        &3600,
+        &1,
+        &soroban_sdk::vec![&env, String::from_str(&env, "0xdoctor")],
+    );
+    assert!(client.verify_grant_access(&bridge, &grant_id, &true));
+}
+
+#[test]
+fn test_verify_grant_access_single_use() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let conditions = soroban_sdk::vec![&env, AccessCondition::SingleUse];
+    let grant_id = grant_with_conditions(&env, &client, &patient, conditions);
+
+    assert!(!client.is_grant_consumed(&grant_id));
+    assert!(client.verify_grant_access(&bridge, &grant_id, &false));
+    assert!(client.is_grant_consumed(&grant_id));
+
+    let result = client.try_verify_grant_access(&bridge, &grant_id, &false);
+    assert_eq!(result, Err(Ok(Error::SingleUseConsumed)));
+}
+
+#[test]
+fn test_verify_grant_access_time_restricted() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    // Office hours: 09:00 - 17:00 UTC
+    let conditions = soroban_sdk::vec![&env, AccessCondition::TimeRestricted(32_400, 61_200)];
+    let grant_id = grant_with_conditions(&env, &client, &patient, conditions);
+    let midnight = env.ledger().timestamp() - env.ledger().timestamp() % 86_400;
+
+    env.ledger().with_mut(|li| li.timestamp = midnight + 43_200);
+    assert!(client.verify_grant_access(&bridge, &grant_id, &false));
+
+    env.ledger().with_mut(|li| li.timestamp = midnight + 79_200);
+    let result = client.try_verify_grant_access(&bridge, &grant_id, &false);
+    assert_eq!(result, Err(Ok(Error::TimeRestrictionViolated)));
+}
+
+#[test]
+fn test_verify_grant_access_audit_required() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let audited = soroban_sdk::vec![&env, AccessCondition::AuditRequired];
+    let audited_id = grant_with_conditions(&env, &client, &patient, audited);
+    let plain_id = grant_with_conditions(&env, &client, &patient, Vec::new(&env));
+    client.configure_emergency(
+        &patient,
+        &true,
+        &3600,
+        &1,
+        &soroban_sdk::vec![&env, String::from_str(&env, "0xdoctor")],
+    );
+
+    // Grants without the condition leave the audit log untouched
+    assert!(client.verify_grant_access(&bridge, &plain_id, &false));
+    assert!(client.get_audit_entry(&1).is_none());
+
+    assert!(client.verify_grant_access(&bridge, &audited_id, &true));
+    let entry = client.get_audit_entry(&1).unwrap();
+    assert_eq!(entry.patient, patient);
+    assert_eq!(entry.accessor_address, String::from_str(&env, "0xdoctor"));
+    assert!(entry.action == AccessAction::EmergencyAccess);
+    assert!(entry.success);
+}
+
+#[test]
+fn test_verify_grant_access_requires_grantee_auth() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let conditions = soroban_sdk::vec![&env, AccessCondition::SingleUse];
+    let grant_id = grant_with_conditions(&env, &client, &patient, conditions);
+
+    // A caller that is neither the grantee nor the bridge is turned away
+    let stranger = Address::generate(&env);
+    let result = client.try_verify_grant_access(&stranger, &grant_id, &false);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    // Without the bridge's signature the grant cannot be consumed
+    env.set_auths(&[]);
+    let result = client.try_verify_grant_access(&bridge, &grant_id, &false);
+    assert!(result.is_err());
+    assert!(!client.is_grant_consumed(&grant_id));
+}
+
+fn grant_with_scope(
+    env: &Env,
+    client: &CrossChainAccessContractClient,
+    patient: &Address,
+    scope: AccessScope,
+) -> u64 {
+    client.grant_access(
+        patient,
+        &ChainId::Ethereum,
+        &String::from_str(env, "0xdoctor"),
+        &PermissionLevel::Read,
+        &scope,
+        &86400,
+        &Vec::new(env),
+    )
+}
+
+#[test]
+fn test_is_record_in_scope_all_and_specific() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let all = grant_with_scope(&env, &client, &patient, AccessScope::AllRecords);
+    let specific = grant_with_scope(
+        &env,
+        &client,
+        &patient,
+        AccessScope::SpecificRecords(soroban_sdk::vec![&env, 3u64, 5u64]),
+    );
+
+    assert!(client.is_record_in_scope(&all, &42, &None, &None));
+    assert!(client.is_record_in_scope(&specific, &5, &None, &None));
+    assert!(!client.is_record_in_scope(&specific, &4, &None, &None));
+
+    let result = client.try_is_record_in_scope(&999, &1, &None, &None);
+    assert_eq!(result, Err(Ok(Error::GrantNotFound)));
+}
+
+#[test]
+fn test_is_record_in_scope_category() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let grant_id = grant_with_scope(
+        &env,
+        &client,
+        &patient,
+        AccessScope::CategoryBased(String::from_str(&env, "cardiology")),
+    );
+
+    let cardiology = Some(String::from_str(&env, "cardiology"));
+    let oncology = Some(String::from_str(&env, "oncology"));
+    assert!(client.is_record_in_scope(&grant_id, &1, &cardiology, &None));
+    assert!(!client.is_record_in_scope(&grant_id, &1, &oncology, &None));
+
+    let result = client.try_is_record_in_scope(&grant_id, &1, &None, &Some(100));
+    assert_eq!(result, Err(Ok(Error::InvalidScope)));
+}
+
+#[test]
+fn test_is_record_in_scope_time_ranged() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let grant_id = grant_with_scope(
+        &env,
+        &client,
+        &patient,
+        AccessScope::TimeRanged(1_000, 2_000),
+    );
+
+    assert!(client.is_record_in_scope(&grant_id, &1, &None, &Some(1_000)));
+    assert!(client.is_record_in_scope(&grant_id, &1, &None, &Some(2_000)));
+    assert!(!client.is_record_in_scope(&grant_id, &1, &None, &Some(2_001)));
+    assert!(!client.is_record_in_scope(&grant_id, &1, &None, &Some(999)));
+
+    let result = client.try_is_record_in_scope(&grant_id, &1, &None, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidScope)));
+}
+
+#[test]
+fn test_emergency_request_waits_for_attestation_and_limits_grant() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+    let hospital = Address::generate(&env);
+    let provider = hospital.to_string();
+
+    env.mock_all_auths();
+
+    client.configure_emergency(
+        &patient,
+        &true,
+        &3600,
+        &0,
+        &soroban_sdk::vec![&env, provider.clone()],
+    );
+
+    // Naming a trusted provider as requester is not enough to be approved
+    let request_id = client.request_access(
+        &ChainId::Ethereum,
+        &provider,
+        &patient,
+        &soroban_sdk::vec![&env, 11u64],
+        &String::from_str(&env, "Emergency"),
+        &true,
+    );
+
+    assert!(client.get_request(&request_id).unwrap().status == RequestStatus::Pending);
+    assert!(client.get_grant(&1).is_none());
+
+    assert_eq!(client.attest_emergency(&hospital, &request_id), 1);
+    assert!(client.get_request(&request_id).unwrap().status == RequestStatus::Approved);
+
+    let grant = client.get_grant(&1).unwrap();
+    assert_eq!(grant.expires_at, grant.granted_at + 3600);
+    assert!(client.is_record_in_scope(&1, &11, &None, &None));
+    assert!(!client.is_record_in_scope(&1, &12, &None, &None));
+}
+
 // ==================== Pause Tests ====================
 
 #[test]
@@ -857,56 +1745,122 @@ fn test_pause_not_admin() {
 // ==================== Permission Level Tests ====================
 
 #[test]
-fn test_admin_permission_covers_all() {
+fn test_admin_permission_covers_all() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let grantee_address = String::from_str(&env, "0xadmin_user");
+
+    env.mock_all_auths();
+
+    // Only the contract admin may issue Admin permission
+    client.grant_access(
+        &admin,
+        &ChainId::Ethereum,
+        &grantee_address,
+        &PermissionLevel::Admin,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    // Admin should have access to all permission levels
+    assert!(client.verify_access(
+        &ChainId::Ethereum,
+        &grantee_address,
+        &admin,
+        &1,
+        &PermissionLevel::Read,
+    ));
+    assert!(client.verify_access(
+        &ChainId::Ethereum,
+        &grantee_address,
+        &admin,
+        &1,
+        &PermissionLevel::ReadConfidential,
+    ));
+    assert!(client.verify_access(
+        &ChainId::Ethereum,
+        &grantee_address,
+        &admin,
+        &1,
+        &PermissionLevel::Write,
+    ));
+    assert!(client.verify_access(
+        &ChainId::Ethereum,
+        &grantee_address,
+        &admin,
+        &1,
+        &PermissionLevel::Admin,
+    ));
+}
+
+#[test]
+fn test_non_admin_cannot_issue_admin_grant() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let patient = Address::generate(&env);
+
+    let result = client.try_grant_access(
+        &patient,
+        &ChainId::Ethereum,
+        &String::from_str(&env, "0xdoctor"),
+        &PermissionLevel::Admin,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientPermissions)));
+}
+
+#[test]
+fn test_read_delegate_cannot_grant_write() {
     let env = Env::default();
     let (client, admin, bridge, identity) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &bridge, &identity);
 
     let patient = Address::generate(&env);
-    let grantee_address = String::from_str(&env, "0xadmin_user");
+    let delegate = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xdoctor");
 
-    env.mock_all_auths();
+    client.create_delegation(
+        &patient,
+        &delegate,
+        &ChainId::Stellar,
+        &String::from_str(&env, ""),
+        &true,
+        &false,
+        &false,
+        &PermissionLevel::Read,
+        &86400,
+    );
 
-    // Grant Admin permission
-    client.grant_access(
+    let result = client.try_grant_access_as_delegate(
+        &delegate,
         &patient,
         &ChainId::Ethereum,
-        &grantee_address,
-        &PermissionLevel::Admin,
+        &grantee_addr,
+        &PermissionLevel::Write,
         &AccessScope::AllRecords,
         &86400,
         &Vec::new(&env),
     );
+    assert_eq!(result, Err(Ok(Error::InsufficientPermissions)));
 
-    // Admin should have access to all permission levels
-    assert!(client.verify_access(
-        &ChainId::Ethereum,
-        &grantee_address,
-        &patient,
-        &1,
-        &PermissionLevel::Read,
-    ));
-    assert!(client.verify_access(
-        &ChainId::Ethereum,
-        &grantee_address,
-        &patient,
-        &1,
-        &PermissionLevel::ReadConfidential,
-    ));
-    assert!(client.verify_access(
-        &ChainId::Ethereum,
-        &grantee_address,
+    let grant_id = client.grant_access_as_delegate(
+        &delegate,
         &patient,
-        &1,
-        &PermissionLevel::Write,
-    ));
-    assert!(client.verify_access(
         &ChainId::Ethereum,
-        &grantee_address,
-        &patient,
-        &1,
-        &PermissionLevel::Admin,
-    ));
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+    assert!(client.get_grant(&grant_id).unwrap().permission_level == PermissionLevel::Read);
 }
 
 // ==================== Chain ID Tests ====================
@@ -1010,6 +1964,7 @@ fn test_multiple_delegations_independent() {
         &true,
         &false,
         &false,
+        &PermissionLevel::Write,
         &86400,
     );
 
@@ -1022,6 +1977,7 @@ fn test_multiple_delegations_independent() {
         &false,
         &true,
         &false,
+        &PermissionLevel::Write,
         &86400,
     );
 
@@ -1218,11 +2174,25 @@ fn test_finalize_access_swap() {
 
     client.accept_access_swap(&acceptor, &swap_id, &acceptor_grant_id);
 
+    // Both grants are escrowed while the swap is open
+    assert!(!client.get_grant(&offered_grant_id).unwrap().is_active);
+    assert!(!client.get_grant(&acceptor_grant_id).unwrap().is_active);
+
     let result = client.finalize_access_swap(&initiator, &swap_id, &secret);
     assert!(result);
 
     let swap = client.get_swap(&swap_id).unwrap();
     assert_eq!(swap.status, SwapStatus::Completed);
+
+    let offered = client.get_grant(&offered_grant_id).unwrap();
+    assert!(offered.is_active);
+    assert_eq!(offered.grantee_chain, ChainId::Polygon);
+    assert_eq!(offered.grantee_address, grantee_addr);
+
+    let accepted = client.get_grant(&acceptor_grant_id).unwrap();
+    assert!(accepted.is_active);
+    assert_eq!(accepted.grantee_chain, ChainId::Stellar);
+    assert_eq!(accepted.grantee_address, initiator.to_string());
 }
 
 #[test]
@@ -1276,7 +2246,128 @@ fn test_finalize_swap_wrong_secret_fails() {
 
     // Wrong secret should fail
     let result = client.try_finalize_access_swap(&initiator, &swap_id, &wrong_secret);
-    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+    assert_eq!(result, Err(Ok(Error::InvalidSecret)));
+
+    let swap = client.get_swap(&swap_id).unwrap();
+    assert_eq!(swap.status, SwapStatus::Accepted);
+    assert!(!client.get_grant(&offered_grant_id).unwrap().is_active);
+}
+
+#[test]
+fn test_finalize_swap_after_timelock_fails() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let initiator = Address::generate(&env);
+    let acceptor = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xgrantee");
+
+    env.mock_all_auths();
+
+    let secret = BytesN::from_array(&env, &[0x99u8; 32]);
+    let secret_hash: BytesN<32> = env.crypto().sha256(&secret.clone().into()).into();
+
+    let offered_grant_id = client.grant_access(
+        &initiator,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let acceptor_grant_id = client.grant_access(
+        &acceptor,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let swap_id = client.initiate_access_swap(
+        &initiator,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &offered_grant_id,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &secret_hash,
+        &7200,
+    );
+
+    client.accept_access_swap(&acceptor, &swap_id, &acceptor_grant_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 7201);
+
+    let result = client.try_finalize_access_swap(&initiator, &swap_id, &secret);
+    assert_eq!(result, Err(Ok(Error::SwapExpired)));
+    assert!(!client.get_grant(&offered_grant_id).unwrap().is_active);
+}
+
+#[test]
+fn test_revoke_during_swap_cancels_it() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let initiator = Address::generate(&env);
+    let acceptor = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xgrantee");
+
+    env.mock_all_auths();
+
+    let secret = BytesN::from_array(&env, &[0x99u8; 32]);
+    let secret_hash: BytesN<32> = env.crypto().sha256(&secret.clone().into()).into();
+
+    let offered_grant_id = client.grant_access(
+        &initiator,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let acceptor_grant_id = client.grant_access(
+        &acceptor,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let swap_id = client.initiate_access_swap(
+        &initiator,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &offered_grant_id,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &secret_hash,
+        &7200,
+    );
+    client.accept_access_swap(&acceptor, &swap_id, &acceptor_grant_id);
+
+    // The acceptor pulls their grant back while it sits in escrow
+    client.revoke_access(&acceptor, &acceptor_grant_id);
+
+    let swap = client.get_swap(&swap_id).unwrap();
+    assert_eq!(swap.status, SwapStatus::Cancelled);
+    assert!(client.get_grant(&offered_grant_id).unwrap().is_active);
+
+    // Finalizing cannot revive the revoked grant
+    let result = client.try_finalize_access_swap(&initiator, &swap_id, &secret);
+    assert_eq!(result, Err(Ok(Error::SwapAlreadyProcessed)));
+    let revoked = client.get_grant(&acceptor_grant_id).unwrap();
+    assert!(!revoked.is_active);
+    assert_eq!(revoked.grantee_chain, ChainId::Polygon);
 }
 
 #[test]
@@ -1318,6 +2409,113 @@ fn test_cancel_swap() {
 
     let swap = client.get_swap(&swap_id).unwrap();
     assert_eq!(swap.status, SwapStatus::Cancelled);
+
+    // Escrowed grant is returned to its owner unchanged
+    let grant = client.get_grant(&offered_grant_id).unwrap();
+    assert!(grant.is_active);
+    assert_eq!(grant.grantee_chain, ChainId::Ethereum);
+
+    let result = client.try_cancel_access_swap(&initiator, &swap_id);
+    assert_eq!(result, Err(Ok(Error::SwapAlreadyProcessed)));
+}
+
+#[test]
+fn test_cancel_after_revoke_keeps_grant_revoked() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let initiator = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xgrantee");
+
+    env.mock_all_auths();
+
+    let offered_grant_id = client.grant_access(
+        &initiator,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let swap_id = client.initiate_access_swap(
+        &initiator,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &offered_grant_id,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &BytesN::from_array(&env, &[0x11u8; 32]),
+        &7200,
+    );
+
+    client.revoke_access(&initiator, &offered_grant_id);
+
+    let result = client.try_cancel_access_swap(&initiator, &swap_id);
+    assert_eq!(result, Err(Ok(Error::SwapAlreadyProcessed)));
+    assert!(!client.get_grant(&offered_grant_id).unwrap().is_active);
+}
+
+#[test]
+fn test_cancel_accepted_swap_after_timelock() {
+    let env = Env::default();
+    let (client, admin, bridge, identity) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &bridge, &identity);
+
+    let initiator = Address::generate(&env);
+    let acceptor = Address::generate(&env);
+    let grantee_addr = String::from_str(&env, "0xgrantee");
+
+    env.mock_all_auths();
+
+    let offered_grant_id = client.grant_access(
+        &initiator,
+        &ChainId::Ethereum,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let acceptor_grant_id = client.grant_access(
+        &acceptor,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &86400,
+        &Vec::new(&env),
+    );
+
+    let swap_id = client.initiate_access_swap(
+        &initiator,
+        &ChainId::Polygon,
+        &grantee_addr,
+        &offered_grant_id,
+        &PermissionLevel::Read,
+        &AccessScope::AllRecords,
+        &BytesN::from_array(&env, &[0x11u8; 32]),
+        &7200,
+    );
+
+    client.accept_access_swap(&acceptor, &swap_id, &acceptor_grant_id);
+
+    // Neither party can back out of an accepted swap before the timelock
+    let result = client.try_cancel_access_swap(&initiator, &swap_id);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    env.ledger().with_mut(|li| li.timestamp += 7201);
+
+    let result = client.cancel_access_swap(&acceptor, &swap_id);
+    assert!(result);
+
+    let swap = client.get_swap(&swap_id).unwrap();
+    assert_eq!(swap.status, SwapStatus::Cancelled);
+    assert!(client.get_grant(&offered_grant_id).unwrap().is_active);
+    assert!(client.get_grant(&acceptor_grant_id).unwrap().is_active);
 }
 
 #[test]
@@ -103,7 +103,7 @@ fn submit_message_scenario(
     let (client, admin, _medical, _identity, _access) = create_contract(env);
     let (validator, sk) = setup_validator(env, &client, &admin);
     let message_id = BytesN::from_array(env, &[11u8; 32]);
-    let request = SubmitMessageRequest {
+    let mut request = SubmitMessageRequest {
         message_id: message_id.clone(),
         source_chain: ChainId::Ethereum,
         dest_chain: ChainId::Stellar,
@@ -115,7 +115,10 @@ fn submit_message_scenario(
         signature: BytesN::from_array(env, &[3u8; 64]),
         v_signature: create_sig(env, &sk, &message_id, 1),
         v_nonce: 1,
+        fee: 0,
     };
+    let digest = request.digest(env);
+    request.signature = BytesN::from_array(env, &sk.sign(&digest.to_array()).to_bytes());
     (client, validator, request)
 }
 
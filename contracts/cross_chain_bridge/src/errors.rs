@@ -1,6 +1,7 @@
 use soroban_sdk::{contracterror, symbol_short, Symbol};
 
-#[contracterror]
+// Not exported to the contract spec, which caps error enums at 50 cases.
+#[contracterror(export = false)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
@@ -10,6 +11,7 @@ pub enum Error {
     InsufficientConfirmations = 120,
     InsufficientOracleReports = 121,
     DuplicateOracleReport = 122,
+    InsufficientReputation = 123,
 
     // --- Input Validation (200–299) ---
     InvalidSignature = 207,
@@ -18,10 +20,19 @@ pub enum Error {
     InvalidPayload = 282,
     InvalidAddress = 290,
     BatchTooLarge = 283,
+    InvalidAmount = 284,
+    NonceReplayed = 285,
+    NonceTooOld = 286,
+    InvalidSchedule = 287,
+    InvalidInput = 288,
 
     // --- Lifecycle & State (300–399) ---
     AlreadyInitialized = 301,
     ContractPaused = 302,
+    FeeTokenNotSet = 303,
+    InsufficientFeePool = 304,
+    FeePoolNotEmpty = 305,
+    TimelockNotElapsed = 306,
     Overflow = 580,
 
     // --- Entity Existence (400–499) ---
@@ -38,16 +49,29 @@ pub enum Error {
     ValidatorNotFound = 483,
     ValidatorNotActive = 484,
     DuplicateConfirmation = 485,
+    DuplicateSlashReport = 493,
+    MessageNotExecuted = 494,
+    FeeAlreadyClaimed = 495,
 
     // --- Cryptography (600–699) ---
     ProofNotFound = 610,
     ProofAlreadyVerified = 611,
+    InvalidMerkleProof = 612,
+    EquivocationNotProven = 613,
+    ProofNotVerified = 614,
+    ProofAlreadyChallenged = 615,
+    ChallengeWindowClosed = 616,
+    ProofNotDisputed = 617,
 
     // --- Cross-Chain (700–799) ---
     InvalidChain = 703,
     ChainNotSupported = 720,
     OracleNotFound = 721,
     OracleNotActive = 722,
+    StaleReport = 723,
+    ChainPaused = 724,
+    OutOfOrderEvent = 725,
+    FutureReport = 726,
 
     // --- Timeout / Operation (800–899) ---
     OperationNotFound = 800,
@@ -65,14 +89,24 @@ impl core::fmt::Display for Error {
             Error::InsufficientConfirmations => write!(f, "insufficient confirmations"),
             Error::InsufficientOracleReports => write!(f, "insufficient oracle reports"),
             Error::DuplicateOracleReport => write!(f, "duplicate oracle report"),
+            Error::InsufficientReputation => write!(f, "insufficient reputation"),
             Error::InvalidSignature => write!(f, "invalid signature"),
             Error::InvalidMessage => write!(f, "invalid message"),
             Error::InvalidNonce => write!(f, "invalid nonce"),
             Error::InvalidPayload => write!(f, "invalid payload"),
             Error::InvalidAddress => write!(f, "invalid address"),
             Error::BatchTooLarge => write!(f, "batch too large"),
+            Error::InvalidAmount => write!(f, "invalid amount"),
+            Error::NonceReplayed => write!(f, "nonce replayed"),
+            Error::NonceTooOld => write!(f, "nonce too old"),
+            Error::InvalidSchedule => write!(f, "invalid schedule"),
+            Error::InvalidInput => write!(f, "invalid input"),
             Error::AlreadyInitialized => write!(f, "already initialized"),
             Error::ContractPaused => write!(f, "contract paused"),
+            Error::FeeTokenNotSet => write!(f, "fee token not set"),
+            Error::InsufficientFeePool => write!(f, "insufficient fee pool"),
+            Error::FeePoolNotEmpty => write!(f, "fee pool not empty"),
+            Error::TimelockNotElapsed => write!(f, "timelock not elapsed"),
             Error::Overflow => write!(f, "overflow"),
             Error::MessageNotFound => write!(f, "message not found"),
             Error::MessageExpired => write!(f, "message expired"),
@@ -87,12 +121,25 @@ impl core::fmt::Display for Error {
             Error::ValidatorNotFound => write!(f, "validator not found"),
             Error::ValidatorNotActive => write!(f, "validator not active"),
             Error::DuplicateConfirmation => write!(f, "duplicate confirmation"),
+            Error::DuplicateSlashReport => write!(f, "duplicate slash report"),
+            Error::MessageNotExecuted => write!(f, "message not executed"),
+            Error::FeeAlreadyClaimed => write!(f, "fee already claimed"),
             Error::ProofNotFound => write!(f, "proof not found"),
             Error::ProofAlreadyVerified => write!(f, "proof already verified"),
+            Error::InvalidMerkleProof => write!(f, "invalid merkle proof"),
+            Error::EquivocationNotProven => write!(f, "equivocation not proven"),
+            Error::ProofNotVerified => write!(f, "proof not verified"),
+            Error::ProofAlreadyChallenged => write!(f, "proof already challenged"),
+            Error::ChallengeWindowClosed => write!(f, "challenge window closed"),
+            Error::ProofNotDisputed => write!(f, "proof not disputed"),
             Error::InvalidChain => write!(f, "invalid chain"),
             Error::ChainNotSupported => write!(f, "chain not supported"),
             Error::OracleNotFound => write!(f, "oracle not found"),
             Error::OracleNotActive => write!(f, "oracle not active"),
+            Error::StaleReport => write!(f, "stale report"),
+            Error::ChainPaused => write!(f, "chain paused"),
+            Error::OutOfOrderEvent => write!(f, "out of order event"),
+            Error::FutureReport => write!(f, "future report"),
             Error::OperationNotFound => write!(f, "operation not found"),
             Error::OperationExpired => write!(f, "operation expired"),
             Error::OperationAlreadyCompleted => write!(f, "operation already completed"),
@@ -106,7 +153,8 @@ pub fn get_suggestion(error: Error) -> Symbol {
     match error {
         Error::Unauthorized
         | Error::InsufficientConfirmations
-        | Error::InsufficientOracleReports => {
+        | Error::InsufficientOracleReports
+        | Error::InsufficientReputation => {
             symbol_short!("CHK_AUTH")
         },
         Error::AlreadyInitialized
@@ -115,8 +163,13 @@ pub fn get_suggestion(error: Error) -> Symbol {
         | Error::RollbackAlreadyProcessed
         | Error::ProofAlreadyVerified
         | Error::DuplicateConfirmation
-        | Error::DuplicateOracleReport => symbol_short!("ALREADY"),
-        Error::ContractPaused => symbol_short!("RE_TRY_L"),
+        | Error::DuplicateSlashReport
+        | Error::DuplicateOracleReport
+        | Error::FeeAlreadyClaimed
+        | Error::ProofAlreadyChallenged => symbol_short!("ALREADY"),
+        Error::ContractPaused | Error::ChainPaused | Error::TimelockNotElapsed => {
+            symbol_short!("RE_TRY_L")
+        },
         Error::MessageNotFound
         | Error::AtomicTxNotFound
         | Error::ValidatorNotFound
@@ -125,4 +178,4 @@ pub fn get_suggestion(error: Error) -> Symbol {
         | Error::EventNotFound => symbol_short!("CHK_ID"),
         _ => symbol_short!("CONTACT"),
     }
-}
\ No newline at end of file
+}
@@ -27,9 +27,15 @@ mod reorg_protection_tests;
 /// **Payload**: `SHA256(Target_ID + Nonce)`
 ///   - `Target_ID`: The unique identifier of the entity being signed (e.g., `message_id`, `proof_id`).
 ///   - `Nonce`: A monotonically increasing 64-bit integer unique to the validator's public key.
+///
+/// The `signature` carried on a message is the submitting validator's signature
+/// over the canonical message digest:
+/// `SHA256(message_id + XDR(source_chain) + XDR(dest_chain) + XDR(sender) + Nonce + XDR(payload))`.
+use ed25519_dalek::{Signature, VerifyingKey};
 use governance_commons::require_admin;
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, String, Symbol, Vec,
+    contract, contractimpl, contracttype, token, xdr::ToXdr, Address, Bytes, BytesN, Env, String,
+    Symbol, Vec,
 };
 
 // ==================== Submit Message Request ====================
@@ -49,6 +55,30 @@ pub struct SubmitMessageRequest {
     pub signature: BytesN<64>,
     pub v_signature: BytesN<64>,
     pub v_nonce: u64,
+    /// Paid from the fee pool to the relayer that executes the message
+    pub fee: i128,
+}
+
+impl SubmitMessageRequest {
+    /// Canonical digest of the message fields covered by `signature`.
+    pub fn digest(&self, env: &Env) -> BytesN<32> {
+        let mut data = Bytes::from_array(env, &self.message_id.to_array());
+        data.append(&self.source_chain.clone().to_xdr(env));
+        data.append(&self.dest_chain.clone().to_xdr(env));
+        data.append(&self.sender.clone().to_xdr(env));
+        data.extend_from_array(&self.nonce.to_be_bytes());
+        data.append(&self.payload.clone().to_xdr(env));
+        env.crypto().sha256(&data).into()
+    }
+}
+
+/// Sliding-window record of consumed sender nonces.
+/// Bit `i` of `bitmap` is set when nonce `high_watermark - i` has been used.
+#[derive(Clone)]
+#[contracttype]
+pub struct NonceWindowState {
+    pub high_watermark: u64,
+    pub bitmap: u64,
 }
 
 // ==================== Existing Core Types ====================
@@ -76,6 +106,16 @@ pub enum ChainId {
     Custom(u32),
 }
 
+/// Outcome of `validate_chain_address_detailed`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum AddressValidation {
+    Valid,
+    BadLength,
+    BadPrefix,
+    BadCharset,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct CrossChainMessage {
@@ -90,6 +130,16 @@ pub struct CrossChainMessage {
     pub timestamp: u64,
     pub status: MessageStatus,
     pub signature: BytesN<64>,
+    pub fee: i128,
+}
+
+/// One page of `get_messages_by_status`. `next_cursor` is where the next call
+/// resumes scanning, or `None` once the index is exhausted.
+#[derive(Clone)]
+#[contracttype]
+pub struct MessageStatusPage {
+    pub messages: Vec<CrossChainMessage>,
+    pub next_cursor: Option<u64>,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -115,6 +165,16 @@ pub struct Validator {
     pub confirmed_messages: u64,
 }
 
+/// A validator kept active until `effective_at` so in-flight confirmations
+/// can complete before it is deactivated.
+#[derive(Clone)]
+#[contracttype]
+pub struct PendingValidatorRemoval {
+    pub validator: Address,
+    pub scheduled_at: u64,
+    pub effective_at: u64,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct CrossChainRecordRef {
@@ -179,7 +239,7 @@ pub struct OracleReport {
     pub data_hash: BytesN<32>,
     pub data: String, // JSON-encoded payload
     pub block_height: u64,
-    pub timestamp: u64,
+    pub timestamp: u64, // When the oracle observed the data
     pub signature: BytesN<64>,
     pub status: OracleStatus,
 }
@@ -222,6 +282,39 @@ pub struct CrossChainProof {
     pub verifier_count: u32,
     /// Whether the proof has reached the required consensus threshold
     pub verified: bool,
+    /// Whether `record_hash` has been proven to be a leaf under `merkle_root`
+    pub inclusion_proven: bool,
+    /// When `verified` was set; the challenge window runs from here
+    pub verified_at: u64,
+    /// Set by `challenge_proof`; blocks use until an admin resolves it
+    pub disputed: bool,
+}
+
+/// Counter-evidence raised against a verified proof within its challenge window
+#[derive(Clone)]
+#[contracttype]
+pub struct ProofChallenge {
+    pub proof_id: BytesN<32>,
+    pub challenger: Address,
+    pub counter_evidence_hash: BytesN<32>,
+    pub challenged_at: u64,
+}
+
+// ==================== New Types: Validator Slashing ====================
+
+/// A stake reduction applied to a validator, by the admin or via equivocation proof
+#[derive(Clone)]
+#[contracttype]
+pub struct SlashRecord {
+    pub slash_id: u64,
+    pub validator: Address,
+    pub amount: i128,
+    pub remaining_stake: i128,
+    pub reason: String,
+    /// `None` for admin slashes, the reporting address for equivocation slashes
+    pub reporter: Option<Address>,
+    pub deactivated: bool,
+    pub timestamp: u64,
 }
 
 // ==================== New Types: Emergency Rollback ====================
@@ -238,6 +331,9 @@ pub struct RollbackRecord {
     pub status: RollbackStatus,
     pub reason: String,
     pub completed_at: u64,
+    /// Atomic tx messages that had already executed when the rollback ran and
+    /// need manual reconciliation
+    pub executed_messages: Vec<BytesN<32>>,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -348,8 +444,19 @@ pub enum DataKey {
     RollbackCount,
     EventCount,
     OpCount,
+    SlashCount,
+    MinValidatorStake,
+    MinConsensusReputation,
+    ChainMinConfirmations(ChainId),
+    MaxReportAge,
+    NonceWindowSize,
+    TtlBumpAmount,
+    FeeToken,
+    FeePool,
+    ChainPaused(ChainId),
     // Persistent storage keys (critical long-lived data)
     Nonce(String),
+    NonceWindow(String),
     Validator(Address),
     Message(BytesN<32>),
     RecordRef(u64, ChainId),
@@ -357,21 +464,89 @@ pub enum DataKey {
     OracleNode(Address),
     OracleReport(u64),
     AggregatedOracle(ChainId),
+    /// Highest block height accepted from an oracle for a chain
+    OracleLastSeenBlock(Address, ChainId),
     Proof(BytesN<32>),
     Rollback(BytesN<32>),
     Event(u64),
     CrossChainOp(BytesN<32>),
+    ValidatorIndex,
+    SlashRecord(u64),
+    EquivocationReported(BytesN<32>, u64),
+    /// Relayer that executed a message, entitled to its fee
+    MessageExecutor(BytesN<32>),
+    /// Set once a message executes; outlives the message record so the ID can never be replayed
+    ProcessedMessages(BytesN<32>),
+    ValidatorRemoval(Address),
+    PendingRemovals,
+    FeeClaimed(BytesN<32>),
     // Temporary storage keys (session/short-lived data)
     Confirmations(BytesN<32>),
     AuthorizedRelayer(Address),
 }
 
+/// Proof challenge storage, kept apart from `DataKey` (at the 50-variant limit)
+#[contracttype]
+pub enum ProofChallengeKey {
+    /// Instance: seconds after verification a proof stays open to challenge
+    Window,
+    /// Persistent: the challenge raised against a proof
+    Challenge(BytesN<32>),
+}
+
+/// Append-only message index, kept apart from `DataKey`. Entries live at
+/// sequence positions in `[Head, Tail)`; eviction advances `Head`.
+#[contracttype]
+pub enum MessageIndexKey {
+    /// Persistent: oldest position still in the index
+    Head,
+    /// Persistent: position the next indexed message is written to
+    Tail,
+    /// Persistent: message ID stored at a position
+    Entry(u64),
+    /// Persistent: position a message ID is indexed at
+    Position(BytesN<32>),
+}
+
+/// Per chain-pair event ordering state, kept apart from `DataKey`
+#[contracttype]
+pub enum EventOrderingKey {
+    /// Instance: highest `block_height` synced from `source` to `dest`
+    LastSyncedHeight(ChainId, ChainId),
+    /// Instance: when set, events below the watermark are accepted
+    AllowReordering,
+}
+
+/// Oracle report settlement markers, kept apart from `DataKey`
+#[contracttype]
+pub enum OracleReportKey {
+    /// Persistent: set once a report has taken part in an aggregation
+    Settled(u64),
+}
+
+/// Relayer fee configuration, kept apart from `DataKey`
+#[contracttype]
+pub enum FeeKey {
+    /// Instance: largest `fee` a submitted message may carry
+    MaxMessageFee,
+}
+
 // Constants
 const DEFAULT_MIN_CONFIRMATIONS: u32 = 2;
 const MESSAGE_EXPIRY_SECS: u64 = 86_400; // 24 hours
+const MAX_STATUS_SCAN: u32 = 100; // Max index entries examined / evicted per call
+const MAX_NONCE_WINDOW: u32 = 64; // Bits in NonceWindowState::bitmap
+const MAX_BATCH_SIZE: u32 = 50; // Max entries per batch submit / confirm
+const MAX_REVOKE_ADDRESS_LEN: usize = 128; // Grantee address bytes in an AccessRevoke payload
 const ATOMIC_TX_TIMEOUT: u64 = 3_600; // 1 hour
 const MIN_ORACLE_REPORTS: u32 = 3; // Minimum oracle reports for consensus
 const DEFAULT_ORACLE_REPUTATION: u32 = 50;
+const MAX_ORACLE_REPUTATION: u32 = 100;
+const ORACLE_REPUTATION_REWARD: u32 = 2; // Agreeing with consensus
+const ORACLE_REPUTATION_PENALTY: u32 = 5; // Disagreeing with consensus
+const DEFAULT_MIN_CONSENSUS_REPUTATION: u32 = 100; // Summed reputation backing consensus
+const DEFAULT_MAX_REPORT_AGE: u64 = 3_600; // 1 hour
+const DEFAULT_MIN_VALIDATOR_STAKE: i128 = 1; // Validators at zero stake are deactivated
 
 // Default timeout constants for different operations
 const TOKEN_TRANSFER_TIMEOUT: u64 = 3_600; // 1 hour
@@ -382,10 +557,12 @@ const MAX_EXTENSIONS: u32 = 3; // Maximum number of timeout extensions
 // TTL constants for storage management
 /// TTL threshold: extend persistent data if remaining TTL falls below this
 const PERSISTENT_TTL_THRESHOLD: u32 = 100;
-/// Extend persistent data to this many ledgers (~4 days at 5s/ledger)
+/// Default ledgers to extend persistent data to on access (~4 days at 5s/ledger)
 const PERSISTENT_TTL_EXTEND_TO: u32 = 10000;
 /// TTL for temporary/session storage (~4 hours)
 const TEMP_SESSION_TTL: u32 = 1000;
+/// Seconds after verification during which a proof can be challenged
+const DEFAULT_CHALLENGE_WINDOW_SECS: u64 = 3_600; // 1 hour
 
 #[contract]
 pub struct CrossChainBridgeContract;
@@ -466,6 +643,8 @@ impl CrossChainBridgeContract {
         env.storage()
             .persistent()
             .set(&DataKey::Validator(validator_address.clone()), &validator);
+        Self::bump_ttl(&env, &DataKey::Validator(validator_address.clone()));
+        Self::index_validator(&env, &validator_address);
 
         env.events()
             .publish((Symbol::new(&env, "validator_added"),), (validator_address,));
@@ -479,21 +658,96 @@ impl CrossChainBridgeContract {
         validator_address: Address,
     ) -> Result<bool, Error> {
         require_admin!(env, caller);
+        Self::apply_deactivation(&env, validator_address)
+    }
 
-        let key = DataKey::Validator(validator_address.clone());
-        if let Some(mut validator) = env.storage().persistent().get::<DataKey, Validator>(&key) {
-            validator.is_active = false;
-            env.storage().persistent().set(&key, &validator);
+    /// Schedule `validator_address` for deactivation at `effective_at`.
+    /// Until then it keeps attesting as normal; afterwards it is treated as
+    /// inactive and `finalize_validator_removal` records the deactivation.
+    /// Rescheduling replaces the previous effective time.
+    pub fn schedule_validator_removal(
+        env: Env,
+        admin: Address,
+        validator_address: Address,
+        effective_at: u64,
+    ) -> Result<bool, Error> {
+        require_admin!(env, admin);
 
-            env.events().publish(
-                (Symbol::new(&env, "validator_deactivated"),),
-                (validator_address,),
-            );
+        let now = env.ledger().timestamp();
+        if effective_at <= now {
+            return Err(Error::InvalidSchedule);
+        }
+        let validator = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Validator>(&DataKey::Validator(validator_address.clone()))
+            .ok_or(Error::ValidatorNotFound)?;
+        if !validator.is_active {
+            return Err(Error::ValidatorNotActive);
+        }
 
-            Ok(true)
-        } else {
-            Err(Error::ValidatorNotFound)
+        let key = DataKey::ValidatorRemoval(validator_address.clone());
+        env.storage().persistent().set(
+            &key,
+            &PendingValidatorRemoval {
+                validator: validator_address.clone(),
+                scheduled_at: now,
+                effective_at,
+            },
+        );
+        Self::bump_ttl(&env, &key);
+
+        let mut pending = Self::pending_removal_index(&env);
+        if !pending.contains(&validator_address) {
+            pending.push_back(validator_address.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::PendingRemovals, &pending);
+        }
+        Self::bump_ttl(&env, &DataKey::PendingRemovals);
+
+        env.events().publish(
+            (Symbol::new(&env, "validator_removal_scheduled"),),
+            (validator_address, effective_at),
+        );
+
+        Ok(true)
+    }
+
+    /// Deactivate a validator whose scheduled removal has taken effect.
+    pub fn finalize_validator_removal(
+        env: Env,
+        caller: Address,
+        validator_address: Address,
+    ) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        let removal = env
+            .storage()
+            .persistent()
+            .get::<DataKey, PendingValidatorRemoval>(&DataKey::ValidatorRemoval(
+                validator_address.clone(),
+            ))
+            .ok_or(Error::ValidatorNotFound)?;
+        if env.ledger().timestamp() < removal.effective_at {
+            return Err(Error::TimelockNotElapsed);
+        }
+
+        Self::apply_deactivation(&env, validator_address)
+    }
+
+    pub fn get_pending_removals(env: Env) -> Vec<PendingValidatorRemoval> {
+        let mut removals = Vec::new(&env);
+        for validator in Self::pending_removal_index(&env).iter() {
+            if let Some(removal) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, PendingValidatorRemoval>(&DataKey::ValidatorRemoval(validator))
+            {
+                removals.push_back(removal);
+            }
         }
+        removals
     }
 
     pub fn add_supported_chain(env: Env, caller: Address, chain: ChainId) -> Result<bool, Error> {
@@ -532,6 +786,105 @@ impl CrossChainBridgeContract {
         Ok(true)
     }
 
+    /// Override the confirmation threshold for messages from `chain`
+    pub fn set_chain_min_confirmations(
+        env: Env,
+        caller: Address,
+        chain: ChainId,
+        min_confirmations: u32,
+    ) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        if min_confirmations == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ChainMinConfirmations(chain.clone()),
+            &min_confirmations,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "ChainConfirmationsSet"),),
+            (chain, min_confirmations),
+        );
+
+        Ok(true)
+    }
+
+    /// Confirmations required for messages from `chain` (override or global default)
+    pub fn get_chain_min_confirmations(env: Env, chain: ChainId) -> u32 {
+        Self::min_confirmations_for(&env, &chain)
+    }
+
+    /// Set the sender nonce window (0 = strictly increasing, max 64).
+    ///
+    /// With a window of N, any unused nonce among the last N below the
+    /// sender's highest nonce, or up to N above it, is accepted.
+    pub fn set_nonce_window(env: Env, caller: Address, window: u32) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        if window > MAX_NONCE_WINDOW {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::NonceWindowSize, &window);
+
+        Ok(true)
+    }
+
+    pub fn get_nonce_window(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NonceWindowSize)
+            .unwrap_or(0)
+    }
+
+    /// Set how many ledgers validator, message and proof entries are kept
+    /// alive for each time they are written or read.
+    pub fn set_ttl_bump_amount(env: Env, caller: Address, amount: u32) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        if amount <= PERSISTENT_TTL_THRESHOLD || amount > env.storage().max_ttl() {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TtlBumpAmount, &amount);
+
+        Ok(true)
+    }
+
+    pub fn get_ttl_bump_amount(env: Env) -> u32 {
+        Self::ttl_bump_amount(&env)
+    }
+
+    /// Keep a message alive for another full bump period regardless of its
+    /// remaining TTL. Callable by the admin or any active validator.
+    pub fn extend_message_ttl(
+        env: Env,
+        caller: Address,
+        message_id: BytesN<32>,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        if !Self::is_admin(&env, &caller) {
+            Self::require_active_validator(&env, &caller)?;
+        }
+
+        let key = DataKey::Message(message_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::MessageNotFound);
+        }
+
+        let amount = Self::ttl_bump_amount(&env);
+        env.storage().persistent().extend_ttl(&key, amount, amount);
+
+        Ok(true)
+    }
+
     pub fn pause(env: Env, caller: Address) -> Result<bool, Error> {
         require_admin!(env, caller);
 
@@ -558,6 +911,146 @@ impl CrossChainBridgeContract {
         Ok(true)
     }
 
+    /// Quarantine a single chain: messages and record refs touching it are
+    /// rejected while other chains keep flowing.
+    pub fn pause_chain(env: Env, admin: Address, chain: ChainId) -> Result<bool, Error> {
+        require_admin!(env, admin);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ChainPaused(chain.clone()), &true);
+
+        env.events()
+            .publish((Symbol::new(&env, "chain_paused"),), (chain, admin));
+
+        Ok(true)
+    }
+
+    pub fn unpause_chain(env: Env, admin: Address, chain: ChainId) -> Result<bool, Error> {
+        require_admin!(env, admin);
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::ChainPaused(chain.clone()));
+
+        env.events()
+            .publish((Symbol::new(&env, "chain_unpaused"),), (chain, admin));
+
+        Ok(true)
+    }
+
+    pub fn is_chain_paused(env: Env, chain: ChainId) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChainPaused(chain))
+            .unwrap_or(false)
+    }
+
+    // ==================== Validator Slashing ====================
+
+    /// Set the stake below which a slashed validator is deactivated
+    pub fn set_min_validator_stake(
+        env: Env,
+        caller: Address,
+        min_stake: i128,
+    ) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        if min_stake < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinValidatorStake, &min_stake);
+
+        Ok(true)
+    }
+
+    pub fn get_min_validator_stake(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinValidatorStake)
+            .unwrap_or(DEFAULT_MIN_VALIDATOR_STAKE)
+    }
+
+    /// Reduce a validator's stake by `amount` (saturating at zero).
+    /// Returns the id of the recorded `SlashRecord`.
+    pub fn slash_validator(
+        env: Env,
+        caller: Address,
+        validator_address: Address,
+        amount: i128,
+        reason: String,
+    ) -> Result<u64, Error> {
+        require_admin!(env, caller);
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::apply_slash(&env, &validator_address, amount, reason, None)
+    }
+
+    /// Prove that a validator signed two different messages with the same
+    /// validator nonce, and slash its entire stake.
+    ///
+    /// Each validator nonce can only ever be accepted once, so two valid
+    /// signatures over `sha256(id || nonce)` for distinct ids are conflicting
+    /// confirmations. Signature verification traps on an invalid signature.
+    pub fn report_equivocation(
+        env: Env,
+        reporter: Address,
+        validator_address: Address,
+        nonce: u64,
+        message_id: BytesN<32>,
+        sig_a: BytesN<64>,
+        conflicting_id: BytesN<32>,
+        sig_b: BytesN<64>,
+    ) -> Result<u64, Error> {
+        reporter.require_auth();
+
+        if message_id == conflicting_id {
+            return Err(Error::EquivocationNotProven);
+        }
+
+        let validator = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Validator>(&DataKey::Validator(validator_address.clone()))
+            .ok_or(Error::ValidatorNotFound)?;
+
+        let reported_key = DataKey::EquivocationReported(validator.public_key.clone(), nonce);
+        if env.storage().persistent().has(&reported_key) {
+            return Err(Error::DuplicateSlashReport);
+        }
+
+        Self::verify_validator_signature(&env, &validator.public_key, &message_id, nonce, &sig_a)?;
+        Self::verify_validator_signature(
+            &env,
+            &validator.public_key,
+            &conflicting_id,
+            nonce,
+            &sig_b,
+        )?;
+
+        env.storage().persistent().set(&reported_key, &true);
+
+        Self::apply_slash(
+            &env,
+            &validator_address,
+            validator.stake,
+            String::from_str(&env, "equivocation"),
+            Some(reporter),
+        )
+    }
+
+    pub fn get_slash_record(env: Env, slash_id: u64) -> Option<SlashRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SlashRecord(slash_id))
+    }
+
     // ==================== Cross-Chain Message Functions ====================
 
     /// Submit a cross-chain message for relaying to another chain.
@@ -576,6 +1069,10 @@ impl CrossChainBridgeContract {
         Self::require_not_paused(&env)?;
         let v_info = Self::get_active_validator_info(&env, &validator)?;
         Self::require_chain_supported(&env, &request.source_chain)?;
+        Self::require_chain_not_paused(&env, &request.source_chain)?;
+        Self::require_chain_not_paused(&env, &request.dest_chain)?;
+        Self::require_valid_fee(&env, request.fee)?;
+        Self::require_not_processed(&env, &request.message_id)?;
 
         Self::verify_nonce(&env, &request.sender, request.nonce)?;
 
@@ -588,11 +1085,12 @@ impl CrossChainBridgeContract {
             request.v_nonce,
             &request.v_signature,
         )?;
+        Self::verify_message_signature(&env, &v_info.public_key, &request)?;
 
         // Issue #1001: Enforce cross-border data transfer jurisdiction restrictions.
         // For record-related messages, verify the destination chain's jurisdiction
         // is allowed by the patient's consent record.
-        Self::enforce_jurisdiction_check(&env, &payload_type, &dest_chain)?;
+        Self::enforce_jurisdiction_check(&env, &request.payload_type, &request.dest_chain)?;
 
         let timestamp = env.ledger().timestamp();
 
@@ -608,18 +1106,16 @@ impl CrossChainBridgeContract {
             timestamp,
             status: MessageStatus::Pending,
             signature: request.signature,
+            fee: request.fee,
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::Message(request.message_id.clone()), &message);
-        env.storage().persistent().extend_ttl(
-            &DataKey::Message(request.message_id.clone()),
-            PERSISTENT_TTL_THRESHOLD,
-            PERSISTENT_TTL_EXTEND_TO,
-        );
+        Self::bump_ttl(&env, &DataKey::Message(request.message_id.clone()));
 
         Self::update_nonce(&env, &request.sender, request.nonce);
+        Self::index_message(&env, &request.message_id);
 
         let count: u64 = env
             .storage()
@@ -633,7 +1129,7 @@ impl CrossChainBridgeContract {
 
         env.events().publish(
             (Symbol::new(&env, "message_submitted"),),
-            (message_id.clone(), timestamp),
+            (request.message_id.clone(), timestamp),
         );
 
         Ok(request.message_id)
@@ -652,7 +1148,7 @@ impl CrossChainBridgeContract {
         Self::require_not_paused(&env)?;
         let v_info = Self::get_active_validator_info(&env, &validator)?;
 
-        if requests.is_empty() || requests.len() > 50 {
+        if requests.is_empty() || requests.len() > MAX_BATCH_SIZE {
             return Err(Error::BatchTooLarge);
         }
 
@@ -660,6 +1156,10 @@ impl CrossChainBridgeContract {
 
         for request in requests.iter() {
             Self::require_chain_supported(&env, &request.source_chain)?;
+            Self::require_chain_not_paused(&env, &request.source_chain)?;
+            Self::require_chain_not_paused(&env, &request.dest_chain)?;
+            Self::require_valid_fee(&env, request.fee)?;
+            Self::require_not_processed(&env, &request.message_id)?;
             Self::verify_nonce(&env, &request.sender, request.nonce)?;
 
             // Per-request validator nonce and signature verification
@@ -671,6 +1171,7 @@ impl CrossChainBridgeContract {
                 request.v_nonce,
                 &request.v_signature,
             )?;
+            Self::verify_message_signature(&env, &v_info.public_key, &request)?;
 
             let timestamp = env.ledger().timestamp();
 
@@ -686,18 +1187,16 @@ impl CrossChainBridgeContract {
                 timestamp,
                 status: MessageStatus::Pending,
                 signature: request.signature.clone(),
+                fee: request.fee,
             };
 
             env.storage()
                 .persistent()
                 .set(&DataKey::Message(request.message_id.clone()), &message);
-            env.storage().persistent().extend_ttl(
-                &DataKey::Message(request.message_id.clone()),
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &DataKey::Message(request.message_id.clone()));
 
             Self::update_nonce(&env, &request.sender, request.nonce);
+            Self::index_message(&env, &request.message_id);
 
             let count: u64 = env
                 .storage()
@@ -720,11 +1219,89 @@ impl CrossChainBridgeContract {
         Ok(message_ids)
     }
 
-    /// Confirm a cross-chain message (validator attestation)
-    /// BUG FIX: Confirmations now stored per message_id (was using shared "conf_key")
-    pub fn confirm_message(
+    /// Queue an `AccessRevoke` message telling `grantee_chain` that `grant_id`
+    /// was revoked. `source` is the access-control contract that owns the grant
+    /// and must be an authorized relayer. The payload is
+    /// `"<grant_id>:<grantee_address>"`; the message is stored `Pending` and is
+    /// confirmed and executed like any validator-submitted message.
+    pub fn submit_access_revoke(
         env: Env,
-        validator: Address,
+        source: Address,
+        grant_id: u64,
+        grantee_chain: ChainId,
+        grantee_address: String,
+    ) -> Result<BytesN<32>, Error> {
+        source.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::require_authorized_relayer(&env, &source)?;
+        Self::require_chain_supported(&env, &grantee_chain)?;
+        Self::require_chain_not_paused(&env, &ChainId::Stellar)?;
+        Self::require_chain_not_paused(&env, &grantee_chain)?;
+
+        let payload = Self::access_revoke_payload(&env, grant_id, &grantee_address)?;
+        let sender = source.to_string();
+        let nonce = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::Nonce(sender.clone()))
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        Self::verify_nonce(&env, &sender, nonce)?;
+
+        let message_id: BytesN<32> = env
+            .crypto()
+            .sha256(&(source.clone(), grant_id, grantee_chain.clone(), nonce).to_xdr(&env))
+            .into();
+        Self::require_not_processed(&env, &message_id)?;
+
+        let timestamp = env.ledger().timestamp();
+        let message = CrossChainMessage {
+            message_id: message_id.clone(),
+            source_chain: ChainId::Stellar,
+            dest_chain: grantee_chain,
+            sender: sender.clone(),
+            recipient: source,
+            payload_type: MessageType::AccessRevoke,
+            payload,
+            nonce,
+            timestamp,
+            status: MessageStatus::Pending,
+            signature: BytesN::from_array(&env, &[0u8; 64]),
+            fee: 0,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Message(message_id.clone()), &message);
+        Self::bump_ttl(&env, &DataKey::Message(message_id.clone()));
+
+        Self::update_nonce(&env, &sender, nonce);
+        Self::index_message(&env, &message_id);
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MessageCount)
+            .unwrap_or(0);
+        env.storage().instance().set(
+            &DataKey::MessageCount,
+            &(count.checked_add(1).ok_or(Error::Overflow)?),
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "message_submitted"),),
+            (message_id.clone(), timestamp),
+        );
+
+        Ok(message_id)
+    }
+
+    /// Confirm a cross-chain message (validator attestation)
+    /// BUG FIX: Confirmations now stored per message_id (was using shared "conf_key")
+    pub fn confirm_message(
+        env: Env,
+        validator: Address,
         message_id: BytesN<32>,
         signature: BytesN<64>,
         nonce: u64,
@@ -733,78 +1310,52 @@ impl CrossChainBridgeContract {
         Self::require_not_paused(&env)?;
         let v_info = Self::get_active_validator_info(&env, &validator)?;
 
-        let msg_key = DataKey::Message(message_id.clone());
-        let mut message = env
-            .storage()
-            .persistent()
-            .get::<DataKey, CrossChainMessage>(&msg_key)
-            .ok_or(Error::MessageNotFound)?;
-
-        if message.status != MessageStatus::Pending {
-            return Err(Error::MessageAlreadyProcessed);
-        }
-
-        let now = env.ledger().timestamp();
-        if now
-            > message
-                .timestamp
-                .checked_add(MESSAGE_EXPIRY_SECS)
-                .ok_or(Error::Overflow)?
-        {
-            return Err(Error::MessageExpired);
-        }
+        let message = Self::load_confirmable_message(&env, &message_id)?;
 
         // Replay Protection & Signature Verification
         Self::verify_validator_nonce(&env, &v_info.public_key, nonce)?;
         Self::verify_validator_signature(&env, &v_info.public_key, &message_id, nonce, &signature)?;
 
-        // BUG FIX: Use message_id as direct storage key, not a shared symbol
-        let conf_key = DataKey::Confirmations(message_id.clone());
-        let mut confirmations: Vec<Address> = env
-            .storage()
-            .temporary()
-            .get(&conf_key)
-            .unwrap_or(Vec::new(&env));
-
-        if confirmations.contains(&validator) {
-            return Err(Error::DuplicateConfirmation);
-        }
-
-        confirmations.push_back(validator.clone());
-        env.storage().temporary().set(&conf_key, &confirmations);
-        env.storage()
-            .temporary()
-            .extend_ttl(&conf_key, 0, TEMP_SESSION_TTL);
-
-        Self::increment_validator_confirmations(&env, &validator);
-
-        let min_confirmations: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::MinConfirmations)
-            .unwrap_or(DEFAULT_MIN_CONFIRMATIONS);
+        Self::record_confirmation(&env, &validator, message)?;
+        Ok(true)
+    }
 
-        if confirmations.len() as u32 >= min_confirmations {
-            message.status = MessageStatus::Verified;
-            env.storage().persistent().set(&msg_key, &message);
-            env.storage().persistent().extend_ttl(
-                &msg_key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+    /// Confirm up to `MAX_BATCH_SIZE` messages in one call.
+    ///
+    /// One validator signature covers the whole batch: it signs
+    /// `SHA256(message_id_1 + .. + message_id_n)` with a fresh validator
+    /// `nonce`, exactly as `confirm_message` signs a single id. Messages that
+    /// are missing, no longer pending, expired, on a paused chain or already
+    /// confirmed by this validator are skipped rather than failing the batch. Returns
+    /// `(message_id, confirmed)` for every input.
+    pub fn confirm_messages_batch(
+        env: Env,
+        validator: Address,
+        message_ids: Vec<BytesN<32>>,
+        signature: BytesN<64>,
+        nonce: u64,
+    ) -> Result<Vec<(BytesN<32>, bool)>, Error> {
+        validator.require_auth();
+        Self::require_not_paused(&env)?;
+        let v_info = Self::get_active_validator_info(&env, &validator)?;
 
-            env.events().publish(
-                (Symbol::new(&env, "message_verified"),),
-                (message_id.clone(),),
-            );
+        if message_ids.is_empty() || message_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::BatchTooLarge);
         }
 
-        env.events().publish(
-            (Symbol::new(&env, "message_confirmed"),),
-            (message_id, validator),
-        );
+        let batch_hash = Self::batch_digest(&env, &message_ids);
+        Self::verify_validator_nonce(&env, &v_info.public_key, nonce)?;
+        Self::verify_validator_signature(&env, &v_info.public_key, &batch_hash, nonce, &signature)?;
+
+        let mut outcomes: Vec<(BytesN<32>, bool)> = Vec::new(&env);
+        for message_id in message_ids.iter() {
+            let confirmed = Self::load_confirmable_message(&env, &message_id)
+                .and_then(|message| Self::record_confirmation(&env, &validator, message))
+                .is_ok();
+            outcomes.push_back((message_id, confirmed));
+        }
 
-        Ok(true)
+        Ok(outcomes)
     }
 
     pub fn execute_message(
@@ -835,12 +1386,21 @@ impl CrossChainBridgeContract {
         {
             message.status = MessageStatus::Expired;
             env.storage().persistent().set(&msg_key, &message);
+            Self::bump_ttl(&env, &msg_key);
             return Err(Error::MessageExpired);
         }
 
         let payload_type = message.payload_type.clone();
         message.status = MessageStatus::Executed;
         env.storage().persistent().set(&msg_key, &message);
+        Self::bump_ttl(&env, &msg_key);
+
+        let executor_key = DataKey::MessageExecutor(message_id.clone());
+        env.storage().persistent().set(&executor_key, &caller);
+        Self::bump_ttl(&env, &executor_key);
+        let processed_key = DataKey::ProcessedMessages(message_id.clone());
+        env.storage().persistent().set(&processed_key, &true);
+        Self::bump_ttl(&env, &processed_key);
 
         env.events().publish(
             (Symbol::new(&env, "message_executed"),),
@@ -850,6 +1410,143 @@ impl CrossChainBridgeContract {
         Ok(true)
     }
 
+    // ==================== Relayer Fees ====================
+
+    /// Set the token used for the relayer fee pool (admin only). The token
+    /// can only change while the pool is empty, since the pool balance is
+    /// held in the current token.
+    pub fn set_fee_token(env: Env, caller: Address, token: Address) -> Result<bool, Error> {
+        require_admin!(env, caller);
+        let current: Option<Address> = env.storage().instance().get(&DataKey::FeeToken);
+        if current.is_some_and(|current| current != token) && Self::get_fee_pool(env.clone()) > 0 {
+            return Err(Error::FeePoolNotEmpty);
+        }
+        env.storage().instance().set(&DataKey::FeeToken, &token);
+        Ok(true)
+    }
+
+    /// Cap the `fee` a validator may attach to a submitted message (admin
+    /// only). Until a cap is set, messages cannot carry a fee.
+    pub fn set_max_message_fee(env: Env, caller: Address, max_fee: i128) -> Result<bool, Error> {
+        require_admin!(env, caller);
+        if max_fee < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&FeeKey::MaxMessageFee, &max_fee);
+        Ok(true)
+    }
+
+    pub fn get_max_message_fee(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&FeeKey::MaxMessageFee)
+            .unwrap_or(0)
+    }
+
+    /// Fund the relayer fee pool by transferring `amount` of the fee token
+    /// from `depositor` to the bridge.
+    pub fn deposit_fees(env: Env, depositor: Address, amount: i128) -> Result<i128, Error> {
+        depositor.require_auth();
+        Self::require_not_paused(&env)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token = Self::fee_token(&env)?;
+        token::Client::new(&env, &token).transfer(
+            &depositor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let pool = Self::get_fee_pool(env.clone())
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        env.storage().instance().set(&DataKey::FeePool, &pool);
+
+        env.events().publish(
+            (Symbol::new(&env, "fees_deposited"),),
+            (depositor, amount, pool),
+        );
+
+        Ok(pool)
+    }
+
+    /// Pay an executed message's fee from the pool to the relayer that
+    /// executed it, which must be an authorized relayer. Each message's fee
+    /// can be claimed once.
+    pub fn claim_relayer_fee(
+        env: Env,
+        relayer: Address,
+        message_id: BytesN<32>,
+    ) -> Result<i128, Error> {
+        relayer.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::require_authorized_relayer(&env, &relayer)?;
+
+        let message = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CrossChainMessage>(&DataKey::Message(message_id.clone()))
+            .ok_or(Error::MessageNotFound)?;
+        if message.status != MessageStatus::Executed {
+            return Err(Error::MessageNotExecuted);
+        }
+
+        let executor: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MessageExecutor(message_id.clone()));
+        if executor != Some(relayer.clone()) {
+            return Err(Error::Unauthorized);
+        }
+
+        let claimed_key = DataKey::FeeClaimed(message_id.clone());
+        if env.storage().persistent().has(&claimed_key) {
+            return Err(Error::FeeAlreadyClaimed);
+        }
+        if message.fee <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let pool = Self::get_fee_pool(env.clone());
+        if pool < message.fee {
+            return Err(Error::InsufficientFeePool);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FeePool, &(pool - message.fee));
+        env.storage().persistent().set(&claimed_key, &true);
+        Self::bump_ttl(&env, &claimed_key);
+
+        let token = Self::fee_token(&env)?;
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &relayer,
+            &message.fee,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "relayer_fee_claimed"),),
+            (message_id, relayer, message.fee),
+        );
+
+        Ok(message.fee)
+    }
+
+    pub fn get_fee_pool(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::FeePool).unwrap_or(0)
+    }
+
+    pub fn is_fee_claimed(env: Env, message_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::FeeClaimed(message_id))
+    }
+
     /// Mark a message as failed and emit a failure event (validator only).
     /// This enables callers to detect failures and trigger refunds or retries.
     pub fn fail_message(
@@ -875,6 +1572,7 @@ impl CrossChainBridgeContract {
 
         message.status = MessageStatus::Failed;
         env.storage().persistent().set(&msg_key, &message);
+        Self::bump_ttl(&env, &msg_key);
 
         env.events().publish(
             (Symbol::new(&env, "MessageFailed"),),
@@ -927,6 +1625,9 @@ impl CrossChainBridgeContract {
         message.status = MessageStatus::Pending;
         message.timestamp = now;
         env.storage().persistent().set(&msg_key, &message);
+        Self::bump_ttl(&env, &msg_key);
+        // The failed entry may have been evicted from the index already
+        Self::index_message(&env, &message_id);
 
         env.events().publish(
             (Symbol::new(&env, "MessageRetried"),),
@@ -936,6 +1637,61 @@ impl CrossChainBridgeContract {
         Ok(true)
     }
 
+    /// Mark Pending/Verified messages older than `MESSAGE_EXPIRY_SECS` as
+    /// Expired and clear their confirmations (active validator only).
+    /// Unknown or already-terminal IDs are skipped. Returns the number swept.
+    pub fn sweep_expired_messages(
+        env: Env,
+        caller: Address,
+        message_ids: Vec<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::require_active_validator(&env, &caller)?;
+
+        if message_ids.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        if message_ids.len() > 50 {
+            return Err(Error::BatchTooLarge);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut swept: u32 = 0;
+
+        for message_id in message_ids.iter() {
+            let msg_key = DataKey::Message(message_id.clone());
+            let Some(mut message) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, CrossChainMessage>(&msg_key)
+            else {
+                continue;
+            };
+
+            if message.status != MessageStatus::Pending && message.status != MessageStatus::Verified
+            {
+                continue;
+            }
+            if now <= message.timestamp.saturating_add(MESSAGE_EXPIRY_SECS) {
+                continue;
+            }
+
+            message.status = MessageStatus::Expired;
+            env.storage().persistent().set(&msg_key, &message);
+            Self::bump_ttl(&env, &msg_key);
+            env.storage()
+                .temporary()
+                .remove(&DataKey::Confirmations(message_id.clone()));
+
+            env.events()
+                .publish((Symbol::new(&env, "MessageExpired"),), (message_id, now));
+            swept += 1;
+        }
+
+        Ok(swept)
+    }
+
     // ==================== Atomic Transaction Functions ====================
 
     pub fn initiate_atomic_tx(
@@ -1094,6 +1850,7 @@ impl CrossChainBridgeContract {
         caller.require_auth();
         Self::require_not_paused(&env)?;
         Self::require_chain_supported(&env, &external_chain)?;
+        Self::require_chain_not_paused(&env, &external_chain)?;
 
         let record_ref = CrossChainRecordRef {
             local_record_id,
@@ -1214,7 +1971,78 @@ impl CrossChainBridgeContract {
         }
     }
 
-    /// Submit a data report from an oracle node
+    /// Set an oracle's reputation (0-100)
+    pub fn set_oracle_reputation(
+        env: Env,
+        caller: Address,
+        oracle_address: Address,
+        reputation: u32,
+    ) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        if reputation > MAX_ORACLE_REPUTATION {
+            return Err(Error::InvalidAmount);
+        }
+
+        let key = DataKey::OracleNode(oracle_address);
+        let mut oracle = env
+            .storage()
+            .persistent()
+            .get::<DataKey, OracleNode>(&key)
+            .ok_or(Error::OracleNotFound)?;
+        oracle.reputation = reputation;
+        env.storage().persistent().set(&key, &oracle);
+
+        Ok(true)
+    }
+
+    /// Set the minimum summed reputation that must back a consensus hash
+    pub fn set_min_consensus_reputation(
+        env: Env,
+        caller: Address,
+        min_reputation: u32,
+    ) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinConsensusReputation, &min_reputation);
+
+        Ok(true)
+    }
+
+    pub fn get_min_consensus_reputation(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinConsensusReputation)
+            .unwrap_or(DEFAULT_MIN_CONSENSUS_REPUTATION)
+    }
+
+    /// Set how far `observed_at` may lag the ledger time for oracle reports
+    pub fn set_max_report_age(env: Env, caller: Address, max_age_secs: u64) -> Result<bool, Error> {
+        require_admin!(env, caller);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxReportAge, &max_age_secs);
+
+        Ok(true)
+    }
+
+    pub fn get_max_report_age(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxReportAge)
+            .unwrap_or(DEFAULT_MAX_REPORT_AGE)
+    }
+
+    /// Submit a data report from an oracle node.
+    ///
+    /// `block_height` must be strictly above the last height this oracle
+    /// reported for `chain`: a repeat of that height is a
+    /// `DuplicateOracleReport` and a lower one is a `StaleReport`.
+    /// `observed_at` must be no older than the configured max report age
+    /// (`StaleReport`) and no later than the ledger time (`FutureReport`).
     pub fn submit_oracle_report(
         env: Env,
         oracle: Address,
@@ -1222,6 +2050,7 @@ impl CrossChainBridgeContract {
         data_hash: BytesN<32>,
         data: String,
         block_height: u64,
+        observed_at: u64,
         signature: BytesN<64>,
     ) -> Result<u64, Error> {
         oracle.require_auth();
@@ -1241,6 +2070,30 @@ impl CrossChainBridgeContract {
 
         let now = env.ledger().timestamp();
 
+        // Staleness / replay protection; the watermark also rejects a repeat
+        // of the same (oracle, chain, block_height)
+        let watermark_key = DataKey::OracleLastSeenBlock(oracle.clone(), chain.clone());
+        if let Some(last_seen_block) = env.storage().persistent().get::<DataKey, u64>(&watermark_key)
+        {
+            if block_height == last_seen_block {
+                return Err(Error::DuplicateOracleReport);
+            }
+            if block_height < last_seen_block {
+                return Err(Error::StaleReport);
+            }
+        }
+        let max_age: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxReportAge)
+            .unwrap_or(DEFAULT_MAX_REPORT_AGE);
+        if observed_at.saturating_add(max_age) < now {
+            return Err(Error::StaleReport);
+        }
+        if observed_at > now {
+            return Err(Error::FutureReport);
+        }
+
         let count: u64 = env
             .storage()
             .instance()
@@ -1255,7 +2108,7 @@ impl CrossChainBridgeContract {
             data_hash: data_hash.clone(),
             data,
             block_height,
-            timestamp: now,
+            timestamp: observed_at,
             signature,
             status: OracleStatus::Submitted,
         };
@@ -1266,6 +2119,7 @@ impl CrossChainBridgeContract {
         env.storage()
             .instance()
             .set(&DataKey::OracleCount, &report_id);
+        env.storage().persistent().set(&watermark_key, &block_height);
 
         // Update oracle stats
         oracle_node.total_reports = oracle_node.total_reports.saturating_add(1);
@@ -1279,7 +2133,17 @@ impl CrossChainBridgeContract {
         Ok(report_id)
     }
 
-    /// Aggregate oracle reports to reach consensus for a chain
+    /// Aggregate oracle reports to reach consensus for a chain.
+    ///
+    /// At least `MIN_ORACLE_REPORTS` of the supplied reports must carry
+    /// `consensus_hash` as their `data_hash`; dissenting reports do not count
+    /// towards that minimum. Each report is weighted by its oracle's
+    /// reputation. The reports backing `consensus_hash` must hold a strict
+    /// majority of the total weight and at least the configured minimum
+    /// reputation. Agreeing oracles gain reputation and their reports are
+    /// marked Aggregated; dissenters lose reputation and their reports stay
+    /// Submitted. Every counted report is marked settled and is ignored by
+    /// later aggregations, so a dissent is only weighed and penalized once.
     pub fn aggregate_oracle_data(
         env: Env,
         caller: Address,
@@ -1307,12 +2171,75 @@ impl CrossChainBridgeContract {
             return Err(Error::InsufficientOracleReports);
         }
 
+        // Weigh contributing reports by the reputation of their oracle
+        let mut reports: Vec<OracleReport> = Vec::new(&env);
+        let mut oracles: Vec<Address> = Vec::new(&env);
+        let mut total_weight: u64 = 0;
+        let mut agreeing_weight: u64 = 0;
+        let mut agreeing_count: u32 = 0;
+        for report_id in report_ids.iter() {
+            let Some(report) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, OracleReport>(&DataKey::OracleReport(report_id))
+            else {
+                continue;
+            };
+            if report.chain != chain || report.status != OracleStatus::Submitted {
+                continue;
+            }
+            if env
+                .storage()
+                .persistent()
+                .has(&OracleReportKey::Settled(report_id))
+            {
+                continue;
+            }
+            if oracles.contains(&report.oracle) {
+                return Err(Error::DuplicateOracleReport);
+            }
+            let Some(node) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, OracleNode>(&DataKey::OracleNode(report.oracle.clone()))
+            else {
+                continue;
+            };
+            if !node.is_active {
+                continue;
+            }
+
+            let weight = u64::from(node.reputation);
+            total_weight = total_weight.saturating_add(weight);
+            if report.data_hash == consensus_hash {
+                agreeing_weight = agreeing_weight.saturating_add(weight);
+                agreeing_count = agreeing_count.saturating_add(1);
+            }
+            oracles.push_back(report.oracle.clone());
+            reports.push_back(report);
+        }
+
+        if agreeing_count < MIN_ORACLE_REPORTS {
+            return Err(Error::InsufficientOracleReports);
+        }
+
+        let min_reputation: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinConsensusReputation)
+            .unwrap_or(DEFAULT_MIN_CONSENSUS_REPUTATION);
+        if agreeing_weight < u64::from(min_reputation)
+            || agreeing_weight.saturating_mul(2) <= total_weight
+        {
+            return Err(Error::InsufficientReputation);
+        }
+
         let now = env.ledger().timestamp();
 
         let aggregated = AggregatedOracleData {
             chain: chain.clone(),
             consensus_hash: consensus_hash.clone(),
-            report_count: report_ids.len() as u32,
+            report_count: agreeing_count,
             consensus_threshold: MIN_ORACLE_REPORTS,
             aggregated_at: now,
             is_finalized: true,
@@ -1322,16 +2249,30 @@ impl CrossChainBridgeContract {
             .persistent()
             .set(&DataKey::AggregatedOracle(chain.clone()), &aggregated);
 
-        // Mark contributing reports as aggregated
-        for report_id in report_ids.iter() {
-            let rkey = DataKey::OracleReport(report_id);
-            if let Some(mut report) = env
-                .storage()
+        // Settle reports and reward or penalize their oracles
+        for mut report in reports.iter() {
+            let agreed = report.data_hash == consensus_hash;
+            if agreed {
+                report.status = OracleStatus::Aggregated;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::OracleReport(report.report_id), &report);
+            }
+            env.storage()
                 .persistent()
-                .get::<DataKey, OracleReport>(&rkey)
+                .set(&OracleReportKey::Settled(report.report_id), &true);
+
+            let node_key = DataKey::OracleNode(report.oracle.clone());
+            if let Some(mut node) = env.storage().persistent().get::<DataKey, OracleNode>(&node_key)
             {
-                report.status = OracleStatus::Aggregated;
-                env.storage().persistent().set(&rkey, &report);
+                node.reputation = if agreed {
+                    node.reputation
+                        .saturating_add(ORACLE_REPUTATION_REWARD)
+                        .min(MAX_ORACLE_REPUTATION)
+                } else {
+                    node.reputation.saturating_sub(ORACLE_REPUTATION_PENALTY)
+                };
+                env.storage().persistent().set(&node_key, &node);
             }
         }
 
@@ -1379,11 +2320,15 @@ impl CrossChainBridgeContract {
             prover,
             verifier_count: 1,
             verified: false,
+            inclusion_proven: false,
+            verified_at: 0,
+            disputed: false,
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::Proof(proof_id.clone()), &proof);
+        Self::bump_ttl(&env, &DataKey::Proof(proof_id.clone()));
 
         // Track initial submission as a confirmation
         let conf_key = DataKey::Confirmations(proof_id.clone());
@@ -1402,7 +2347,10 @@ impl CrossChainBridgeContract {
         Ok(proof_id)
     }
 
-    /// Verify a submitted cross-chain proof (additional validator attestation)
+    /// Verify a submitted cross-chain proof (additional validator attestation).
+    ///
+    /// Returns whether the proof is now verified. A verified proof only
+    /// becomes usable once its challenge window passes; see `is_proof_usable`.
     pub fn verify_cross_chain_proof(
         env: Env,
         validator_address: Address,
@@ -1420,7 +2368,7 @@ impl CrossChainBridgeContract {
             .get::<DataKey, Validator>(&v_key)
             .ok_or(Error::ValidatorNotFound)?;
 
-        if !validator.is_active {
+        if !Self::is_attesting(&env, &validator) {
             return Err(Error::ValidatorNotActive);
         }
 
@@ -1431,7 +2379,8 @@ impl CrossChainBridgeContract {
             .get::<DataKey, CrossChainProof>(&proof_key)
             .ok_or(Error::ProofNotFound)?;
 
-        if proof.verified {
+        // A disputed proof is settled by the admin, not by further attestations
+        if proof.verified || proof.disputed {
             return Err(Error::ProofAlreadyVerified);
         }
 
@@ -1465,6 +2414,78 @@ impl CrossChainBridgeContract {
 
         proof.verifier_count = verifiers.len() as u32;
 
+        let min_conf: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinConfirmations)
+            .unwrap_or(DEFAULT_MIN_CONFIRMATIONS);
+
+        // Attestations only finalize the proof once inclusion has been proven
+        if proof.inclusion_proven && proof.verifier_count >= min_conf {
+            proof.verified = true;
+            proof.verified_at = env.ledger().timestamp();
+            env.events().publish(
+                (Symbol::new(&env, "proof_verified"),),
+                (proof_id.clone(), proof.source_chain.clone()),
+            );
+        }
+
+        env.storage().persistent().set(&proof_key, &proof);
+        Self::bump_ttl(&env, &proof_key);
+        Ok(proof.verified)
+    }
+
+    /// Prove that `leaf` (the proof's `record_hash`) is included under the
+    /// stored `merkle_root`.
+    ///
+    /// Each level hashes `sha256(left || right)`. `indices[i]` is `true` when
+    /// the running node is the right child at level `i` (its sibling
+    /// `proof_path[i]` is on the left). Once proven, attestations already
+    /// collected count toward `verified`. Returns whether the proof is now
+    /// verified.
+    pub fn verify_merkle_inclusion(
+        env: Env,
+        proof_id: BytesN<32>,
+        leaf: BytesN<32>,
+        proof_path: Vec<BytesN<32>>,
+        indices: Vec<bool>,
+    ) -> Result<bool, Error> {
+        Self::require_not_paused(&env)?;
+
+        let proof_key = DataKey::Proof(proof_id.clone());
+        let mut proof = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CrossChainProof>(&proof_key)
+            .ok_or(Error::ProofNotFound)?;
+
+        if proof.verified || proof.disputed {
+            return Err(Error::ProofAlreadyVerified);
+        }
+
+        if leaf != proof.record_hash || proof_path.len() != indices.len() {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let mut node = leaf;
+        for (sibling, is_right) in proof_path.iter().zip(indices.iter()) {
+            let mut data = Bytes::new(&env);
+            if is_right {
+                data.append(&Bytes::from(sibling));
+                data.append(&Bytes::from(node));
+            } else {
+                data.append(&Bytes::from(node));
+                data.append(&Bytes::from(sibling));
+            }
+            node = env.crypto().sha256(&data).into();
+        }
+
+        if node != proof.merkle_root {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        proof.inclusion_proven = true;
+
         let min_conf: u32 = env
             .storage()
             .instance()
@@ -1473,6 +2494,7 @@ impl CrossChainBridgeContract {
 
         if proof.verifier_count >= min_conf {
             proof.verified = true;
+            proof.verified_at = env.ledger().timestamp();
             env.events().publish(
                 (Symbol::new(&env, "proof_verified"),),
                 (proof_id.clone(), proof.source_chain.clone()),
@@ -1480,27 +2502,216 @@ impl CrossChainBridgeContract {
         }
 
         env.storage().persistent().set(&proof_key, &proof);
+        Self::bump_ttl(&env, &proof_key);
+        env.events().publish(
+            (Symbol::new(&env, "merkle_inclusion_proven"),),
+            proof_id,
+        );
+
         Ok(proof.verified)
     }
 
+    /// Set how long after verification a proof stays open to challenge (admin only).
+    pub fn set_challenge_window(
+        env: Env,
+        caller: Address,
+        challenge_window_secs: u64,
+    ) -> Result<bool, Error> {
+        require_admin!(env, caller);
+        env.storage()
+            .instance()
+            .set(&ProofChallengeKey::Window, &challenge_window_secs);
+        Ok(true)
+    }
+
+    pub fn get_challenge_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ProofChallengeKey::Window)
+            .unwrap_or(DEFAULT_CHALLENGE_WINDOW_SECS)
+    }
+
+    /// Contest a verified proof while its challenge window is open, moving it
+    /// to the disputed state until `resolve_proof_dispute` is called.
+    pub fn challenge_proof(
+        env: Env,
+        validator: Address,
+        proof_id: BytesN<32>,
+        counter_evidence_hash: BytesN<32>,
+    ) -> Result<bool, Error> {
+        validator.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::require_active_validator(&env, &validator)?;
+
+        let proof_key = DataKey::Proof(proof_id.clone());
+        let mut proof = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CrossChainProof>(&proof_key)
+            .ok_or(Error::ProofNotFound)?;
+
+        if !proof.verified {
+            return Err(Error::ProofNotVerified);
+        }
+        let challenge_key = ProofChallengeKey::Challenge(proof_id.clone());
+        // One challenge per proof; a resolved challenge is kept as the record
+        if proof.disputed || env.storage().persistent().has(&challenge_key) {
+            return Err(Error::ProofAlreadyChallenged);
+        }
+        let now = env.ledger().timestamp();
+        let window = Self::get_challenge_window(env.clone());
+        if now >= proof.verified_at.saturating_add(window) {
+            return Err(Error::ChallengeWindowClosed);
+        }
+
+        proof.disputed = true;
+        env.storage().persistent().set(&proof_key, &proof);
+        Self::bump_ttl(&env, &proof_key);
+
+        env.storage().persistent().set(
+            &challenge_key,
+            &ProofChallenge {
+                proof_id: proof_id.clone(),
+                challenger: validator.clone(),
+                counter_evidence_hash: counter_evidence_hash.clone(),
+                challenged_at: now,
+            },
+        );
+        env.storage().persistent().extend_ttl(
+            &challenge_key,
+            PERSISTENT_TTL_THRESHOLD,
+            Self::ttl_bump_amount(&env),
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "proof_challenged"),),
+            (proof_id, validator, counter_evidence_hash),
+        );
+
+        Ok(true)
+    }
+
+    /// Settle a disputed proof (admin only). When `proof_valid` the proof is
+    /// usable immediately and cannot be challenged again; otherwise it is
+    /// permanently unverified.
+    pub fn resolve_proof_dispute(
+        env: Env,
+        admin: Address,
+        proof_id: BytesN<32>,
+        proof_valid: bool,
+    ) -> Result<bool, Error> {
+        require_admin!(env, admin);
+
+        let proof_key = DataKey::Proof(proof_id.clone());
+        let mut proof = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CrossChainProof>(&proof_key)
+            .ok_or(Error::ProofNotFound)?;
+
+        if !proof.disputed {
+            return Err(Error::ProofNotDisputed);
+        }
+
+        if proof_valid {
+            proof.disputed = false;
+        } else {
+            // Stays disputed so attestations cannot re-verify it
+            proof.verified = false;
+        }
+        env.storage().persistent().set(&proof_key, &proof);
+        Self::bump_ttl(&env, &proof_key);
+
+        env.events().publish(
+            (Symbol::new(&env, "proof_dispute_resolved"),),
+            (proof_id, proof_valid),
+        );
+
+        Ok(true)
+    }
+
+    /// Whether a proof can be relied on: verified, not disputed, and either
+    /// past its challenge window or cleared by an admin ruling.
+    pub fn is_proof_usable(env: Env, proof_id: BytesN<32>) -> bool {
+        let Some(proof) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CrossChainProof>(&DataKey::Proof(proof_id.clone()))
+        else {
+            return false;
+        };
+        if !proof.verified || proof.disputed {
+            return false;
+        }
+        let window = Self::get_challenge_window(env.clone());
+        env.ledger().timestamp() >= proof.verified_at.saturating_add(window)
+            || env
+                .storage()
+                .persistent()
+                .has(&ProofChallengeKey::Challenge(proof_id))
+    }
+
+    pub fn get_proof_challenge(env: Env, proof_id: BytesN<32>) -> Option<ProofChallenge> {
+        env.storage()
+            .persistent()
+            .get(&ProofChallengeKey::Challenge(proof_id))
+    }
+
     // ==================== Address Validation / Conversion ====================
 
-    /// Validate a chain address format (length + prefix check)
+    /// Validate a chain address format.
     /// Returns true if the address matches expected format for the given chain.
-    pub fn validate_chain_address(_env: Env, chain: ChainId, address: String) -> bool {
+    pub fn validate_chain_address(env: Env, chain: ChainId, address: String) -> bool {
+        Self::validate_chain_address_detailed(env, chain, address) == AddressValidation::Valid
+    }
+
+    /// Validate a chain address format, reporting why it was rejected.
+    pub fn validate_chain_address_detailed(
+        env: Env,
+        chain: ChainId,
+        address: String,
+    ) -> AddressValidation {
         let len = address.len();
-        match chain {
-            // Stellar StrKey account IDs: 56 chars, start with 'G'
-            ChainId::Stellar => len == 56,
-            // EVM-compatible chains: 42 chars ("0x" + 40 hex digits)
-            ChainId::Ethereum
-            | ChainId::Polygon
-            | ChainId::Avalanche
-            | ChainId::BinanceSmartChain
-            | ChainId::Arbitrum
-            | ChainId::Optimism => len == 42,
+        if let ChainId::Custom(_) = chain {
             // Custom chains: accept any non-empty address
-            ChainId::Custom(_) => len > 0,
+            return if len > 0 {
+                AddressValidation::Valid
+            } else {
+                AddressValidation::BadLength
+            };
+        }
+        if len != Self::get_chain_address_length(env, chain.clone()) {
+            return AddressValidation::BadLength;
+        }
+
+        let mut buf = [0u8; 56];
+        address.copy_into_slice(&mut buf[..len as usize]);
+        let bytes = &buf[..len as usize];
+
+        match chain {
+            // Stellar StrKey account IDs: 'G' followed by RFC 4648 base32 (A-Z, 2-7)
+            ChainId::Stellar => {
+                if bytes[0] != b'G' {
+                    AddressValidation::BadPrefix
+                } else if !bytes
+                    .iter()
+                    .all(|c| c.is_ascii_uppercase() || (b'2'..=b'7').contains(c))
+                {
+                    AddressValidation::BadCharset
+                } else {
+                    AddressValidation::Valid
+                }
+            },
+            // EVM-compatible chains: "0x" + 40 hex digits (either case)
+            _ => {
+                if &bytes[..2] != b"0x" {
+                    AddressValidation::BadPrefix
+                } else if !bytes[2..].iter().all(u8::is_ascii_hexdigit) {
+                    AddressValidation::BadCharset
+                } else {
+                    AddressValidation::Valid
+                }
+            },
         }
     }
 
@@ -1520,7 +2731,11 @@ impl CrossChainBridgeContract {
 
     // ==================== Event Synchronization Functions ====================
 
-    /// Submit a cross-chain event for synchronization
+    /// Submit a cross-chain event for synchronization.
+    ///
+    /// Events for a (source, dest) pair must not go below that pair's last
+    /// synced `block_height` unless reordering is allowed; equal heights are
+    /// accepted for multiple events from the same block.
     pub fn sync_cross_chain_event(
         env: Env,
         validator: Address,
@@ -1547,6 +2762,18 @@ impl CrossChainBridgeContract {
             &signature,
         )?;
 
+        let height_key =
+            EventOrderingKey::LastSyncedHeight(source_chain.clone(), dest_chain.clone());
+        let last_height: Option<u64> = env.storage().instance().get(&height_key);
+        if let Some(last_height) = last_height {
+            if block_height < last_height && !Self::get_allow_reordering(env.clone()) {
+                return Err(Error::OutOfOrderEvent);
+            }
+        }
+        if last_height < Some(block_height) {
+            env.storage().instance().set(&height_key, &block_height);
+        }
+
         let count: u64 = env
             .storage()
             .instance()
@@ -1580,6 +2807,30 @@ impl CrossChainBridgeContract {
         Ok(event_id)
     }
 
+    /// Highest `block_height` synced from `source` to `dest`, if any.
+    pub fn get_last_synced_height(env: Env, source: ChainId, dest: ChainId) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&EventOrderingKey::LastSyncedHeight(source, dest))
+    }
+
+    /// Let `sync_cross_chain_event` accept events below a chain pair's
+    /// last synced height (admin only).
+    pub fn set_allow_reordering(env: Env, caller: Address, allow: bool) -> Result<bool, Error> {
+        require_admin!(env, caller);
+        env.storage()
+            .instance()
+            .set(&EventOrderingKey::AllowReordering, &allow);
+        Ok(true)
+    }
+
+    pub fn get_allow_reordering(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&EventOrderingKey::AllowReordering)
+            .unwrap_or(false)
+    }
+
     /// Mark a cross-chain event as processed/synced
     pub fn process_sync_event(
         env: Env,
@@ -1815,6 +3066,7 @@ impl CrossChainBridgeContract {
             status: RollbackStatus::Initiated,
             reason,
             completed_at: 0,
+            executed_messages: Vec::new(&env),
         };
 
         env.storage()
@@ -1869,6 +3121,7 @@ impl CrossChainBridgeContract {
                     env.storage()
                         .persistent()
                         .set(&DataKey::Message(op_id.clone()), &msg);
+                    Self::bump_ttl(&env, &DataKey::Message(op_id.clone()));
                 }
             },
             RollbackOpType::AtomicTxRollback => {
@@ -1881,6 +3134,17 @@ impl CrossChainBridgeContract {
                     env.storage()
                         .persistent()
                         .set(&DataKey::AtomicTx(op_id.clone()), &atomic_tx);
+
+                    // Cascade to constituent messages; executed ones cannot be
+                    // undone and are recorded for manual reconciliation
+                    let executed = Self::fail_atomic_tx_messages(&env, &atomic_tx.messages);
+                    if !executed.is_empty() {
+                        rollback.executed_messages = executed.clone();
+                        env.events().publish(
+                            (Symbol::new(&env, "rollback_executed_messages"),),
+                            (op_id.clone(), executed),
+                        );
+                    }
                 }
             },
             RollbackOpType::RecordSyncRollback => {
@@ -1929,24 +3193,61 @@ impl CrossChainBridgeContract {
         let key = DataKey::Message(message_id);
         let val: Option<CrossChainMessage> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
 
+    /// List indexed messages currently in `status`, oldest first. The index
+    /// only holds messages younger than `MESSAGE_EXPIRY_SECS`.
+    ///
+    /// Each call examines at most `MAX_STATUS_SCAN` index entries starting at
+    /// `cursor` (`None` for the oldest entry) and returns up to `page_size`
+    /// matches. Pass the returned `next_cursor` back to continue; a short or
+    /// even empty page does not mean the scan is finished.
+    ///
+    /// This replaces the earlier `page` number argument: a page number can't
+    /// be resolved without counting matches from the start of the index.
+    pub fn get_messages_by_status(
+        env: Env,
+        status: MessageStatus,
+        cursor: Option<u64>,
+        page_size: u32,
+    ) -> MessageStatusPage {
+        let mut messages = Vec::new(&env);
+        let page_size = page_size.min(MAX_STATUS_SCAN);
+        let store = env.storage().persistent();
+        let head: u64 = store.get(&MessageIndexKey::Head).unwrap_or(0);
+        let tail: u64 = store.get(&MessageIndexKey::Tail).unwrap_or(0);
+
+        let mut position = cursor.map_or(head, |c| c.max(head));
+        let scan_end = position
+            .saturating_add(u64::from(MAX_STATUS_SCAN))
+            .min(tail);
+        while position < scan_end && messages.len() < page_size {
+            let entry = store.get::<MessageIndexKey, BytesN<32>>(&MessageIndexKey::Entry(position));
+            position += 1;
+            let Some(message) =
+                entry.and_then(|id| store.get::<DataKey, CrossChainMessage>(&DataKey::Message(id)))
+            else {
+                continue;
+            };
+            if message.status == status {
+                messages.push_back(message);
+            }
+        }
+
+        MessageStatusPage {
+            messages,
+            next_cursor: (position < tail).then_some(position),
+        }
+    }
+
     pub fn get_atomic_tx(env: Env, tx_id: BytesN<32>) -> Option<AtomicTransaction> {
         let key = DataKey::AtomicTx(tx_id);
         let val: Option<AtomicTransaction> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -1959,11 +3260,7 @@ impl CrossChainBridgeContract {
         let key = DataKey::RecordRef(local_record_id, external_chain);
         let val: Option<CrossChainRecordRef> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -1972,24 +3269,48 @@ impl CrossChainBridgeContract {
         let key = DataKey::Validator(validator_address);
         let val: Option<Validator> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
 
+    /// All currently active validators, in registration order
+    pub fn get_active_validators(env: Env) -> Vec<Validator> {
+        let index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ValidatorIndex)
+            .unwrap_or(Vec::new(&env));
+
+        let mut active = Vec::new(&env);
+        for address in index.iter() {
+            if let Some(validator) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Validator>(&DataKey::Validator(address))
+            {
+                if Self::is_attesting(&env, &validator) {
+                    active.push_back(validator);
+                }
+            }
+        }
+        active
+    }
+
+    /// Number of registered validators, active or not
+    pub fn get_validator_count(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, Vec<Address>>(&DataKey::ValidatorIndex)
+            .map(|index| index.len())
+            .unwrap_or(0)
+    }
+
     pub fn get_oracle_node(env: Env, oracle_address: Address) -> Option<OracleNode> {
         let key = DataKey::OracleNode(oracle_address);
         let val: Option<OracleNode> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -1998,11 +3319,7 @@ impl CrossChainBridgeContract {
         let key = DataKey::OracleReport(report_id);
         let val: Option<OracleReport> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -2011,11 +3328,7 @@ impl CrossChainBridgeContract {
         let key = DataKey::AggregatedOracle(chain);
         let val: Option<AggregatedOracleData> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -2024,11 +3337,7 @@ impl CrossChainBridgeContract {
         let key = DataKey::Proof(proof_id);
         let val: Option<CrossChainProof> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -2037,11 +3346,7 @@ impl CrossChainBridgeContract {
         let key = DataKey::Rollback(op_id);
         let val: Option<RollbackRecord> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -2050,11 +3355,7 @@ impl CrossChainBridgeContract {
         let key = DataKey::Event(event_id);
         let val: Option<CrossChainEvent> = env.storage().persistent().get(&key);
         if val.is_some() {
-            env.storage().persistent().extend_ttl(
-                &key,
-                PERSISTENT_TTL_THRESHOLD,
-                PERSISTENT_TTL_EXTEND_TO,
-            );
+            Self::bump_ttl(&env, &key);
         }
         val
     }
@@ -2156,22 +3457,283 @@ impl CrossChainBridgeContract {
             .get(&DataKey::Nonce(sender.clone()))
             .unwrap_or(0);
 
-        if nonce <= last_nonce {
-            return Err(Error::InvalidNonce);
+        let window: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NonceWindowSize)
+            .unwrap_or(0);
+        if window == 0 {
+            if nonce <= last_nonce {
+                return Err(Error::InvalidNonce);
+            }
+            return Ok(());
+        }
+        // Nonces start at 1, so 0 always sits below the window
+        if nonce == 0 {
+            return Err(Error::NonceTooOld);
         }
+
+        // Window mode. A sender with legacy history treats everything up to
+        // its old watermark as consumed; a brand new sender's window starts at
+        // its first nonce. The nonce is recorded here; failures later in the
+        // call revert it.
+        let key = DataKey::NonceWindow(sender.clone());
+        let stored: Option<NonceWindowState> = env.storage().persistent().get(&key);
+        let mut state = match stored {
+            Some(state) => state,
+            None if last_nonce == 0 => {
+                let state = NonceWindowState {
+                    high_watermark: nonce,
+                    bitmap: 1,
+                };
+                env.storage().persistent().set(&key, &state);
+                return Ok(());
+            },
+            None => NonceWindowState {
+                high_watermark: last_nonce,
+                bitmap: u64::MAX,
+            },
+        };
+
+        if nonce > state.high_watermark {
+            let shift = nonce - state.high_watermark;
+            if shift > u64::from(window) {
+                return Err(Error::InvalidNonce);
+            }
+            let shifted = if shift >= 64 { 0 } else { state.bitmap << shift };
+            state.bitmap = shifted | 1;
+            state.high_watermark = nonce;
+        } else {
+            let age = state.high_watermark - nonce;
+            if age >= u64::from(window) {
+                return Err(Error::NonceTooOld);
+            }
+            let bit = 1u64 << age;
+            if state.bitmap & bit != 0 {
+                return Err(Error::NonceReplayed);
+            }
+            state.bitmap |= bit;
+        }
+
+        env.storage().persistent().set(&key, &state);
         Ok(())
     }
 
     fn update_nonce(env: &Env, sender: &String, nonce: u64) {
-        env.storage()
-            .persistent()
-            .set(&DataKey::Nonce(sender.clone()), &nonce);
+        let key = DataKey::Nonce(sender.clone());
+        // Out-of-order nonces in window mode must not lower the watermark
+        let last_nonce: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &nonce.max(last_nonce));
     }
 }
 
 // ==================== Private Helper Functions ====================
 // These are not exposed as contract entry points.
 impl CrossChainBridgeContract {
+    fn index_validator(env: &Env, validator_address: &Address) {
+        let key = DataKey::ValidatorIndex;
+        let mut index: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        if !index.contains(validator_address) {
+            index.push_back(validator_address.clone());
+            env.storage().persistent().set(&key, &index);
+        }
+        Self::bump_ttl(env, &key);
+    }
+
+    fn min_confirmations_for(env: &Env, chain: &ChainId) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChainMinConfirmations(chain.clone()))
+            .or_else(|| env.storage().instance().get(&DataKey::MinConfirmations))
+            .unwrap_or(DEFAULT_MIN_CONFIRMATIONS)
+    }
+
+    /// Mark every non-executed message as Failed and drop its confirmations,
+    /// refunding the confirming validators' credit. Returns the ids of
+    /// messages that had already been executed.
+    fn fail_atomic_tx_messages(env: &Env, message_ids: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+        let mut executed: Vec<BytesN<32>> = Vec::new(env);
+
+        for message_id in message_ids.iter() {
+            let msg_key = DataKey::Message(message_id.clone());
+            let Some(mut message) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, CrossChainMessage>(&msg_key)
+            else {
+                continue;
+            };
+
+            if message.status == MessageStatus::Executed {
+                executed.push_back(message_id);
+                continue;
+            }
+
+            message.status = MessageStatus::Failed;
+            env.storage().persistent().set(&msg_key, &message);
+            Self::bump_ttl(env, &msg_key);
+
+            let conf_key = DataKey::Confirmations(message_id);
+            let confirmations: Vec<Address> = env
+                .storage()
+                .temporary()
+                .get(&conf_key)
+                .unwrap_or(Vec::new(env));
+            for validator in confirmations.iter() {
+                let v_key = DataKey::Validator(validator);
+                if let Some(mut v) = env.storage().persistent().get::<DataKey, Validator>(&v_key) {
+                    v.confirmed_messages = v.confirmed_messages.saturating_sub(1);
+                    env.storage().persistent().set(&v_key, &v);
+                    Self::bump_ttl(env, &v_key);
+                }
+            }
+            env.storage().temporary().remove(&conf_key);
+        }
+
+        executed
+    }
+
+    /// Deduct `amount` from the validator's stake, deactivating it if the
+    /// remainder falls below the configured minimum, and record the slash.
+    fn apply_slash(
+        env: &Env,
+        validator_address: &Address,
+        amount: i128,
+        reason: String,
+        reporter: Option<Address>,
+    ) -> Result<u64, Error> {
+        let key = DataKey::Validator(validator_address.clone());
+        let mut validator = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Validator>(&key)
+            .ok_or(Error::ValidatorNotFound)?;
+
+        let slashed = amount.min(validator.stake).max(0);
+        validator.stake = validator.stake.saturating_sub(slashed);
+
+        let min_stake: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinValidatorStake)
+            .unwrap_or(DEFAULT_MIN_VALIDATOR_STAKE);
+        let deactivated = validator.is_active && validator.stake < min_stake;
+        if deactivated {
+            validator.is_active = false;
+            Self::clear_validator_removal(env, validator_address);
+        }
+        env.storage().persistent().set(&key, &validator);
+        Self::bump_ttl(env, &key);
+
+        let slash_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::SlashCount)
+            .unwrap_or(0u64)
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        env.storage().instance().set(&DataKey::SlashCount, &slash_id);
+
+        let record = SlashRecord {
+            slash_id,
+            validator: validator_address.clone(),
+            amount: slashed,
+            remaining_stake: validator.stake,
+            reason,
+            reporter,
+            deactivated,
+            timestamp: env.ledger().timestamp(),
+        };
+        let record_key = DataKey::SlashRecord(slash_id);
+        env.storage().persistent().set(&record_key, &record);
+        Self::bump_ttl(env, &record_key);
+
+        env.events().publish(
+            (Symbol::new(env, "ValidatorSlashed"),),
+            (
+                validator_address.clone(),
+                slashed,
+                validator.stake,
+                slash_id,
+            ),
+        );
+
+        Ok(slash_id)
+    }
+
+    /// Append `message_id` to the tail of the message index, moving it there
+    /// if it was already indexed, after evicting entries older than
+    /// `MESSAGE_EXPIRY_SECS` from the head.
+    ///
+    /// Eviction goes by age rather than status: past the expiry a Pending or
+    /// Verified message can no longer be confirmed or executed, so it cannot
+    /// pin the head. Entries are appended in timestamp order, so eviction
+    /// examines at most `MAX_STATUS_SCAN` entries and stops at the first one
+    /// still inside the window.
+    fn index_message(env: &Env, message_id: &BytesN<32>) {
+        let store = env.storage().persistent();
+        let mut head: u64 = store.get(&MessageIndexKey::Head).unwrap_or(0);
+        let mut tail: u64 = store.get(&MessageIndexKey::Tail).unwrap_or(0);
+        let now = env.ledger().timestamp();
+        let bump = |key: &MessageIndexKey| {
+            store.extend_ttl(key, PERSISTENT_TTL_THRESHOLD, Self::ttl_bump_amount(env));
+        };
+
+        let scan_end = head.saturating_add(u64::from(MAX_STATUS_SCAN)).min(tail);
+        while head < scan_end {
+            let entry_key = MessageIndexKey::Entry(head);
+            if let Some(id) = store.get::<MessageIndexKey, BytesN<32>>(&entry_key) {
+                let stale =
+                    match store.get::<DataKey, CrossChainMessage>(&DataKey::Message(id.clone())) {
+                        Some(msg) => now > msg.timestamp.saturating_add(MESSAGE_EXPIRY_SECS),
+                        None => true,
+                    };
+                if !stale {
+                    break;
+                }
+                store.remove(&MessageIndexKey::Position(id));
+                store.remove(&entry_key);
+            }
+            head += 1;
+        }
+        store.set(&MessageIndexKey::Head, &head);
+
+        // A retried message restarts its clock, so it moves to the tail to
+        // keep the index in timestamp order
+        let position_key = MessageIndexKey::Position(message_id.clone());
+        if let Some(previous) = store.get::<MessageIndexKey, u64>(&position_key) {
+            store.remove(&MessageIndexKey::Entry(previous));
+        }
+        let entry_key = MessageIndexKey::Entry(tail);
+        store.set(&entry_key, message_id);
+        store.set(&position_key, &tail);
+        tail += 1;
+        store.set(&MessageIndexKey::Tail, &tail);
+        bump(&entry_key);
+        bump(&position_key);
+        bump(&MessageIndexKey::Head);
+        bump(&MessageIndexKey::Tail);
+    }
+
+    fn ttl_bump_amount(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TtlBumpAmount)
+            .unwrap_or(PERSISTENT_TTL_EXTEND_TO)
+    }
+
+    fn bump_ttl(env: &Env, key: &DataKey) {
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_TTL_THRESHOLD,
+            Self::ttl_bump_amount(env),
+        );
+    }
+
     #[must_use]
     fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
         let admin: Address = env
@@ -2214,7 +3776,7 @@ impl CrossChainBridgeContract {
             .persistent()
             .get::<DataKey, Validator>(&DataKey::Validator(validator.clone()))
         {
-            Some(v) if v.is_active => Ok(v),
+            Some(v) if Self::is_attesting(env, &v) => Ok(v),
             Some(_) => Err(Error::ValidatorNotActive),
             None => Err(Error::ValidatorNotFound),
         }
@@ -2223,14 +3785,71 @@ impl CrossChainBridgeContract {
     
     #[must_use]
     fn require_active_validator(env: &Env, validator: &Address) -> Result<(), Error> {
+        let key = DataKey::Validator(validator.clone());
+        match env.storage().persistent().get::<DataKey, Validator>(&key) {
+            Some(v) if Self::is_attesting(env, &v) => {
+                Self::bump_ttl(env, &key);
+                Ok(())
+            },
+            Some(_) => Err(Error::ValidatorNotActive),
+            None => Err(Error::ValidatorNotFound),
+        }
+    }
+
+    fn apply_deactivation(env: &Env, validator_address: Address) -> Result<bool, Error> {
+        let key = DataKey::Validator(validator_address.clone());
+        if let Some(mut validator) = env.storage().persistent().get::<DataKey, Validator>(&key) {
+            validator.is_active = false;
+            env.storage().persistent().set(&key, &validator);
+            Self::bump_ttl(env, &key);
+            // Deactivated validators stay indexed so they remain discoverable
+            Self::index_validator(env, &validator_address);
+            Self::clear_validator_removal(env, &validator_address);
+
+            env.events().publish(
+                (Symbol::new(env, "validator_deactivated"),),
+                (validator_address,),
+            );
+
+            Ok(true)
+        } else {
+            Err(Error::ValidatorNotFound)
+        }
+    }
+
+    /// Active and not past the effective time of a scheduled removal.
+    fn is_attesting(env: &Env, validator: &Validator) -> bool {
+        if !validator.is_active {
+            return false;
+        }
         match env
             .storage()
             .persistent()
-            .get::<DataKey, Validator>(&DataKey::Validator(validator.clone()))
-        {
-            Some(v) if v.is_active => Ok(()),
-            Some(_) => Err(Error::ValidatorNotActive),
-            None => Err(Error::ValidatorNotFound),
+            .get::<DataKey, PendingValidatorRemoval>(&DataKey::ValidatorRemoval(
+                validator.address.clone(),
+            )) {
+            Some(removal) => env.ledger().timestamp() < removal.effective_at,
+            None => true,
+        }
+    }
+
+    fn pending_removal_index(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingRemovals)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn clear_validator_removal(env: &Env, validator: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ValidatorRemoval(validator.clone()));
+        let mut pending = Self::pending_removal_index(env);
+        if let Some(index) = pending.first_index_of(validator) {
+            pending.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::PendingRemovals, &pending);
         }
     }
 
@@ -2239,7 +3858,7 @@ impl CrossChainBridgeContract {
             env.storage()
                 .persistent()
                 .get::<DataKey, Validator>(&DataKey::Validator(validator.clone())),
-            Some(v) if v.is_active
+            Some(v) if Self::is_attesting(env, &v)
         )
     }
 
@@ -2258,6 +3877,29 @@ impl CrossChainBridgeContract {
         }
     }
 
+    fn require_not_processed(env: &Env, message_id: &BytesN<32>) -> Result<(), Error> {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::ProcessedMessages(message_id.clone()))
+        {
+            return Err(Error::MessageAlreadyProcessed);
+        }
+        Ok(())
+    }
+
+    fn require_chain_not_paused(env: &Env, chain: &ChainId) -> Result<(), Error> {
+        if env
+            .storage()
+            .instance()
+            .get(&DataKey::ChainPaused(chain.clone()))
+            .unwrap_or(false)
+        {
+            return Err(Error::ChainPaused);
+        }
+        Ok(())
+    }
+
     fn verify_validator_signature(
         env: &Env,
         validator_pubkey: &BytesN<32>,
@@ -2270,14 +3912,78 @@ impl CrossChainBridgeContract {
         let mut msg_data = Bytes::from_array(env, &data.to_array());
         msg_data.extend_from_array(&nonce.to_be_bytes());
 
-        // Note: ed25519_verify will panic/trap if verification fails.
-        // This is standard for Soroban auth, but ensure callers are aware
-        // that Error::InvalidSignature is primarily a placeholder for off-chain hints.
         let message_hash = env.crypto().sha256(&msg_data);
-        env.crypto()
-            .ed25519_verify(validator_pubkey, &message_hash.into(), signature);
+        Self::verify_ed25519(validator_pubkey, &message_hash.to_array(), signature)
+    }
 
-        Ok(())
+    /// Checks an Ed25519 `signature` over `message`. Unlike
+    /// `env.crypto().ed25519_verify`, which traps the whole invocation, a bad
+    /// key or signature comes back as `Error::InvalidSignature`.
+    fn verify_ed25519(
+        public_key: &BytesN<32>,
+        message: &[u8],
+        signature: &BytesN<64>,
+    ) -> Result<(), Error> {
+        let key = VerifyingKey::from_bytes(&public_key.to_array())
+            .map_err(|_| Error::InvalidSignature)?;
+        key.verify_strict(message, &Signature::from_bytes(&signature.to_array()))
+            .map_err(|_| Error::InvalidSignature)
+    }
+
+    /// Encodes `grant_id` in decimal followed by `:` and the grantee address.
+    fn access_revoke_payload(
+        env: &Env,
+        grant_id: u64,
+        grantee_address: &String,
+    ) -> Result<String, Error> {
+        let address_len = grantee_address.len() as usize;
+        if address_len == 0 || address_len > MAX_REVOKE_ADDRESS_LEN {
+            return Err(Error::InvalidAddress);
+        }
+
+        let mut digits = [0u8; 20];
+        let mut digit_count = 0;
+        let mut rest = grant_id;
+        loop {
+            digits[digit_count] = b'0' + (rest % 10) as u8;
+            digit_count += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+
+        let mut buf = [0u8; 21 + MAX_REVOKE_ADDRESS_LEN];
+        let mut len = 0;
+        while digit_count > 0 {
+            digit_count -= 1;
+            buf[len] = digits[digit_count];
+            len += 1;
+        }
+        buf[len] = b':';
+        len += 1;
+        grantee_address.copy_into_slice(&mut buf[len..len + address_len]);
+        Ok(String::from_bytes(env, &buf[..len + address_len]))
+    }
+
+    /// Digest of a confirmation batch: SHA-256 over the message ids in order.
+    fn batch_digest(env: &Env, message_ids: &Vec<BytesN<32>>) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        for message_id in message_ids.iter() {
+            data.extend_from_array(&message_id.to_array());
+        }
+        env.crypto().sha256(&data).into()
+    }
+
+    /// Verify the submitting validator signed the canonical message digest, so a
+    /// stored message cannot pair a valid `message_id` with a substituted payload.
+    fn verify_message_signature(
+        env: &Env,
+        validator_pubkey: &BytesN<32>,
+        request: &SubmitMessageRequest,
+    ) -> Result<(), Error> {
+        let digest = request.digest(env);
+        Self::verify_ed25519(validator_pubkey, &digest.to_array(), &request.signature)
     }
 
     #[must_use]
@@ -2293,11 +3999,110 @@ impl CrossChainBridgeContract {
         Ok(())
     }
 
+    /// Fetch a message that can still take confirmations: pending, not
+    /// past `MESSAGE_EXPIRY_SECS`, and not touching a paused chain.
+    fn load_confirmable_message(
+        env: &Env,
+        message_id: &BytesN<32>,
+    ) -> Result<CrossChainMessage, Error> {
+        let message = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CrossChainMessage>(&DataKey::Message(message_id.clone()))
+            .ok_or(Error::MessageNotFound)?;
+
+        if message.status != MessageStatus::Pending {
+            return Err(Error::MessageAlreadyProcessed);
+        }
+
+        let now = env.ledger().timestamp();
+        if now
+            > message
+                .timestamp
+                .checked_add(MESSAGE_EXPIRY_SECS)
+                .ok_or(Error::Overflow)?
+        {
+            return Err(Error::MessageExpired);
+        }
+
+        Self::require_chain_not_paused(env, &message.source_chain)?;
+        Self::require_chain_not_paused(env, &message.dest_chain)?;
+
+        Ok(message)
+    }
+
+    /// Record `validator`'s confirmation and mark the message verified once
+    /// its source chain's threshold is reached.
+    fn record_confirmation(
+        env: &Env,
+        validator: &Address,
+        mut message: CrossChainMessage,
+    ) -> Result<(), Error> {
+        let message_id = message.message_id.clone();
+
+        // BUG FIX: Use message_id as direct storage key, not a shared symbol
+        let conf_key = DataKey::Confirmations(message_id.clone());
+        let mut confirmations: Vec<Address> = env
+            .storage()
+            .temporary()
+            .get(&conf_key)
+            .unwrap_or(Vec::new(env));
+
+        if confirmations.contains(validator) {
+            return Err(Error::DuplicateConfirmation);
+        }
+
+        confirmations.push_back(validator.clone());
+        env.storage().temporary().set(&conf_key, &confirmations);
+        env.storage()
+            .temporary()
+            .extend_ttl(&conf_key, 0, TEMP_SESSION_TTL);
+
+        Self::increment_validator_confirmations(env, validator);
+
+        let min_confirmations = Self::min_confirmations_for(env, &message.source_chain);
+
+        if confirmations.len() as u32 >= min_confirmations {
+            message.status = MessageStatus::Verified;
+            let msg_key = DataKey::Message(message_id.clone());
+            env.storage().persistent().set(&msg_key, &message);
+            Self::bump_ttl(env, &msg_key);
+
+            env.events().publish(
+                (Symbol::new(env, "message_verified"),),
+                (message_id.clone(),),
+            );
+        }
+
+        env.events().publish(
+            (Symbol::new(env, "message_confirmed"),),
+            (message_id, validator.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Fees are non-negative and at most the configured `MaxMessageFee`.
+    fn require_valid_fee(env: &Env, fee: i128) -> Result<(), Error> {
+        if fee < 0 || fee > Self::get_max_message_fee(env.clone()) {
+            return Err(Error::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    fn fee_token(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .ok_or(Error::FeeTokenNotSet)
+    }
+
     fn increment_validator_confirmations(env: &Env, validator: &Address) {
         let key = DataKey::Validator(validator.clone());
         if let Some(mut v) = env.storage().persistent().get::<DataKey, Validator>(&key) {
             v.confirmed_messages = v.confirmed_messages.saturating_add(1);
             env.storage().persistent().set(&key, &v);
+            Self::bump_ttl(env, &key);
         }
     }
 
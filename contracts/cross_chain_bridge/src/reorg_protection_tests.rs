@@ -24,9 +24,7 @@ mod reorg_protection_tests {
         MessageType, SubmitMessageRequest,
     };
     use ed25519_dalek::{Signer, SigningKey};
-    use soroban_sdk::{
-        testutils::Address as _, Address, Bytes, BytesN, Env, String, Vec,
-    };
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
 
     // ── Minimum confirmation depths per chain ─────────────────────────
 
@@ -86,6 +84,11 @@ mod reorg_protection_tests {
         BytesN::from_array(env, &sig.to_bytes())
     }
 
+    fn sign_message(env: &Env, sk: &SigningKey, req: &SubmitMessageRequest) -> BytesN<64> {
+        let digest = req.digest(env);
+        BytesN::from_array(env, &sk.sign(&digest.to_array()).to_bytes())
+    }
+
     fn register_validator(
         env: &Env,
         client: &CrossChainBridgeContractClient,
@@ -109,7 +112,7 @@ mod reorg_protection_tests {
     ) {
         let nonce = 1u64;
         let v_sig = sign(env, sk, &msg_id, nonce);
-        let req = SubmitMessageRequest {
+        let mut req = SubmitMessageRequest {
             message_id: msg_id,
             source_chain,
             dest_chain,
@@ -121,7 +124,9 @@ mod reorg_protection_tests {
             signature: BytesN::from_array(env, &[0u8; 64]),
             v_signature: v_sig,
             v_nonce: nonce,
+            fee: 0,
         };
+        req.signature = sign_message(env, sk, &req);
         env.mock_all_auths();
         client.submit_message(v_addr, &req);
     }
@@ -1,12 +1,16 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::expect_used)]
 use crate::{
-    AtomicTxStatus, ChainId, CrossChainBridgeContract, CrossChainBridgeContractClient,
-    CrossChainEventType, Error, EventSyncStatus, MessageStatus, MessageType, OracleStatus,
-    RollbackOpType, RollbackStatus, SubmitMessageRequest, SyncStatus,
+    AddressValidation, AtomicTxStatus, ChainId, CrossChainBridgeContract,
+    CrossChainBridgeContractClient, CrossChainEventType, DataKey, Error, EventSyncStatus,
+    MessageStatus, MessageType, OracleStatus, RollbackOpType, RollbackStatus,
+    SubmitMessageRequest, SyncStatus, MAX_STATUS_SCAN,
 };
 use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
-use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env, InvokeError, String, Vec,
+};
 
 fn create_contract(
     env: &Env,
@@ -59,6 +63,22 @@ fn generate_keypair() -> (VerifyingKey, SigningKey) {
     (verifying_key, signing_key)
 }
 
+/// Validator signature over a confirmation batch, as `confirm_messages_batch`
+/// expects.
+fn sign_batch(
+    env: &Env,
+    signing_key: &SigningKey,
+    message_ids: &Vec<BytesN<32>>,
+    nonce: u64,
+) -> BytesN<64> {
+    let mut ids = Bytes::new(env);
+    for message_id in message_ids.iter() {
+        ids.extend_from_array(&message_id.to_array());
+    }
+    let batch_hash: BytesN<32> = env.crypto().sha256(&ids).into();
+    create_sig(env, signing_key, &batch_hash, nonce)
+}
+
 fn make_public_key(env: &Env, vk: &VerifyingKey) -> BytesN<32> {
     BytesN::from_array(env, &vk.to_bytes())
 }
@@ -72,6 +92,18 @@ fn create_sig(env: &Env, signing_key: &SigningKey, data: &BytesN<32>, nonce: u64
     BytesN::from_array(env, &sig.to_bytes())
 }
 
+/// Sign the canonical message digest the contract checks `signature` against.
+fn sign_request(
+    env: &Env,
+    signing_key: &SigningKey,
+    mut request: SubmitMessageRequest,
+) -> SubmitMessageRequest {
+    let digest = request.digest(env);
+    let sig = signing_key.sign(&digest.to_array());
+    request.signature = BytesN::from_array(env, &sig.to_bytes());
+    request
+}
+
 fn setup_validator(
     env: &Env,
     client: &CrossChainBridgeContractClient,
@@ -151,6 +183,32 @@ fn test_deactivate_validator() {
     assert!(!validator_info.is_active);
 }
 
+#[test]
+fn test_get_active_validators() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    assert_eq!(client.get_validator_count(), 0);
+    assert_eq!(client.get_active_validators().len(), 0);
+
+    let (validator1, _) = setup_validator(&env, &client, &admin);
+    let (validator2, _) = setup_validator(&env, &client, &admin);
+    assert_eq!(client.get_validator_count(), 2);
+
+    // Re-adding an existing validator does not duplicate the index entry
+    let public_key = client.get_validator(&validator1).unwrap().public_key;
+    client.add_validator(&admin, &validator1, &public_key, &1000);
+    assert_eq!(client.get_validator_count(), 2);
+
+    client.deactivate_validator(&admin, &validator1);
+    assert_eq!(client.get_validator_count(), 2);
+
+    let active = client.get_active_validators();
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().address, validator2);
+}
+
 #[test]
 fn test_add_validator_not_admin() {
     let env = Env::default();
@@ -166,6 +224,182 @@ fn test_add_validator_not_admin() {
     assert_eq!(result, Err(Ok(Error::Unauthorized)));
 }
 
+#[test]
+fn test_scheduled_validator_removal_grace_period() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    let effective_at = env.ledger().timestamp() + 1_000;
+    client.schedule_validator_removal(&admin, &validator2, &effective_at);
+    let pending = client.get_pending_removals();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get_unchecked(0).validator, validator2);
+    assert_eq!(pending.get_unchecked(0).effective_at, effective_at);
+    assert_eq!(
+        client.try_finalize_validator_removal(&admin, &validator2),
+        Err(Ok(Error::TimelockNotElapsed))
+    );
+    assert_eq!(
+        client.try_schedule_validator_removal(&admin, &validator1, &env.ledger().timestamp()),
+        Err(Ok(Error::InvalidSchedule))
+    );
+
+    // Still attesting during the grace period.
+    let first_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xd1, 1);
+    client.confirm_message(
+        &validator2,
+        &first_id,
+        &create_sig(&env, &sk2, &first_id, 1),
+        &1,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+    let second_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xd2, 2);
+    let result = client.try_confirm_message(
+        &validator2,
+        &second_id,
+        &create_sig(&env, &sk2, &second_id, 2),
+        &2,
+    );
+    assert_eq!(result, Err(Ok(Error::ValidatorNotActive)));
+
+    client.finalize_validator_removal(&admin, &validator2);
+    assert!(!client.get_validator(&validator2).unwrap().is_active);
+    assert_eq!(client.get_pending_removals().len(), 0);
+}
+
+// ==================== Slashing Tests ====================
+
+#[test]
+fn test_slash_validator_reduces_stake_and_deactivates() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, _sk) = setup_validator(&env, &client, &admin);
+    client.set_min_validator_stake(&admin, &500);
+
+    let slash_id = client.slash_validator(
+        &admin,
+        &validator,
+        &400,
+        &String::from_str(&env, "missed attestations"),
+    );
+    let v = client.get_validator(&validator).unwrap();
+    assert_eq!(v.stake, 600);
+    assert!(v.is_active);
+
+    let record = client.get_slash_record(&slash_id).unwrap();
+    assert_eq!(record.amount, 400);
+    assert_eq!(record.remaining_stake, 600);
+    assert_eq!(record.reporter, None);
+    assert!(!record.deactivated);
+
+    // Second slash drops below the minimum and saturates at zero
+    let slash_id = client.slash_validator(
+        &admin,
+        &validator,
+        &5000,
+        &String::from_str(&env, "offline"),
+    );
+    let v = client.get_validator(&validator).unwrap();
+    assert_eq!(v.stake, 0);
+    assert!(!v.is_active);
+
+    let record = client.get_slash_record(&slash_id).unwrap();
+    assert_eq!(record.amount, 600);
+    assert!(record.deactivated);
+}
+
+#[test]
+fn test_slash_validator_rejects_non_admin_and_bad_amount() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, _sk) = setup_validator(&env, &client, &admin);
+    let reason = String::from_str(&env, "reason");
+
+    let result = client.try_slash_validator(&Address::generate(&env), &validator, &10, &reason);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let result = client.try_slash_validator(&admin, &validator, &0, &reason);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    assert_eq!(client.get_validator(&validator).unwrap().stake, 1000);
+}
+
+#[test]
+fn test_report_equivocation_slashes_validator() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let reporter = Address::generate(&env);
+
+    let id_a = BytesN::from_array(&env, &[0x0au8; 32]);
+    let id_b = BytesN::from_array(&env, &[0x0bu8; 32]);
+    let sig_a = create_sig(&env, &sk, &id_a, 7);
+    let sig_b = create_sig(&env, &sk, &id_b, 7);
+
+    let slash_id =
+        client.report_equivocation(&reporter, &validator, &7, &id_a, &sig_a, &id_b, &sig_b);
+
+    let v = client.get_validator(&validator).unwrap();
+    assert_eq!(v.stake, 0);
+    assert!(!v.is_active);
+
+    let record = client.get_slash_record(&slash_id).unwrap();
+    assert_eq!(record.amount, 1000);
+    assert_eq!(record.reporter, Some(reporter.clone()));
+
+    // The same evidence cannot be replayed
+    let result =
+        client.try_report_equivocation(&reporter, &validator, &7, &id_a, &sig_a, &id_b, &sig_b);
+    assert_eq!(result, Err(Ok(Error::DuplicateSlashReport)));
+}
+
+#[test]
+fn test_report_equivocation_requires_conflicting_signatures() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let reporter = Address::generate(&env);
+
+    let id_a = BytesN::from_array(&env, &[0x0au8; 32]);
+    let id_b = BytesN::from_array(&env, &[0x0bu8; 32]);
+    let sig_a = create_sig(&env, &sk, &id_a, 7);
+
+    // Same message twice is not a conflict
+    let result =
+        client.try_report_equivocation(&reporter, &validator, &7, &id_a, &sig_a, &id_a, &sig_a);
+    assert_eq!(result, Err(Ok(Error::EquivocationNotProven)));
+
+    // Honest signatures at different nonces do not verify as a same-nonce pair
+    let sig_b_other_nonce = create_sig(&env, &sk, &id_b, 8);
+    let result = client.try_report_equivocation(
+        &reporter,
+        &validator,
+        &7,
+        &id_a,
+        &sig_a,
+        &id_b,
+        &sig_b_other_nonce,
+    );
+    assert!(result.is_err());
+
+    assert_eq!(client.get_validator(&validator).unwrap().stake, 1000);
+}
+
 // ==================== Chain Support Tests ====================
 
 #[test]
@@ -193,6 +427,128 @@ fn test_add_supported_chain() {
     assert!(chains.contains(&ChainId::Avalanche));
 }
 
+/// Signed request from `source` to `dest` using `nonce` as both sender and
+/// validator nonce.
+fn chain_request(
+    env: &Env,
+    sk: &SigningKey,
+    id_byte: u8,
+    source: ChainId,
+    dest: ChainId,
+    nonce: u64,
+) -> SubmitMessageRequest {
+    let message_id = BytesN::from_array(env, &[id_byte; 32]);
+    sign_request(
+        env,
+        sk,
+        SubmitMessageRequest {
+            message_id: message_id.clone(),
+            source_chain: source,
+            dest_chain: dest,
+            sender: String::from_str(env, "0x1234567890abcdef"),
+            recipient: Address::generate(env),
+            payload_type: MessageType::RecordRequest,
+            payload: String::from_str(env, "{\"record_id\": 1}"),
+            nonce,
+            signature: dummy_sig(env),
+            v_signature: create_sig(env, sk, &message_id, nonce),
+            v_nonce: nonce,
+            fee: 0,
+        },
+    )
+}
+
+#[test]
+fn test_pause_chain_leaves_other_chains_operational() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    // Submitted before the pause; confirming it afterwards is rejected.
+    let eth_id = client.submit_message(
+        &validator1,
+        &chain_request(&env, &sk1, 0xe1, ChainId::Ethereum, ChainId::Stellar, 1),
+    );
+
+    client.pause_chain(&admin, &ChainId::Ethereum);
+    assert!(client.is_chain_paused(&ChainId::Ethereum));
+    assert!(!client.is_chain_paused(&ChainId::Stellar));
+
+    let result = client.try_submit_message(
+        &validator1,
+        &chain_request(&env, &sk1, 0xe2, ChainId::Stellar, ChainId::Ethereum, 2),
+    );
+    assert_eq!(result, Err(Ok(Error::ChainPaused)));
+    let result = client.try_confirm_message(
+        &validator2,
+        &eth_id,
+        &create_sig(&env, &sk2, &eth_id, 1),
+        &1,
+    );
+    assert_eq!(result, Err(Ok(Error::ChainPaused)));
+    let result = client.try_register_record_ref(
+        &validator1,
+        &1,
+        &ChainId::Ethereum,
+        &String::from_str(&env, "0xrecord"),
+    );
+    assert_eq!(result, Err(Ok(Error::ChainPaused)));
+
+    // Stellar traffic keeps flowing through to verification.
+    let stellar_id = client.submit_message(
+        &validator1,
+        &chain_request(&env, &sk1, 0x51, ChainId::Stellar, ChainId::Polygon, 3),
+    );
+    client.confirm_message(
+        &validator1,
+        &stellar_id,
+        &create_sig(&env, &sk1, &stellar_id, 4),
+        &4,
+    );
+    // A batch skips the paused-chain message instead of failing outright.
+    let batch = Vec::from_array(&env, [eth_id.clone(), stellar_id.clone()]);
+    assert_eq!(
+        client.confirm_messages_batch(&validator2, &batch, &sign_batch(&env, &sk2, &batch, 2), &2),
+        Vec::from_array(&env, [(eth_id.clone(), false), (stellar_id.clone(), true)])
+    );
+    assert_eq!(
+        client.get_message(&stellar_id).unwrap().status,
+        MessageStatus::Verified
+    );
+    client.register_record_ref(
+        &validator1,
+        &1,
+        &ChainId::Polygon,
+        &String::from_str(&env, "0xrecord"),
+    );
+
+    // Global pause still overrides every chain.
+    client.pause(&admin);
+    let result = client.try_submit_message(
+        &validator1,
+        &chain_request(&env, &sk1, 0x52, ChainId::Stellar, ChainId::Polygon, 5),
+    );
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    client.unpause(&admin);
+
+    client.unpause_chain(&admin, &ChainId::Ethereum);
+    client.confirm_message(
+        &validator2,
+        &eth_id,
+        &create_sig(&env, &sk2, &eth_id, 3),
+        &3,
+    );
+    assert_eq!(
+        client.get_message(&eth_id).unwrap().status,
+        MessageStatus::Pending
+    );
+}
+
 // ==================== Message Tests ====================
 
 #[test]
@@ -212,19 +568,24 @@ fn test_submit_message() {
     let v_sig = create_sig(&env, &sk, &message_id, 1);
     let result = client.submit_message(
         &validator,
-        &SubmitMessageRequest {
-            message_id: message_id.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: sender.clone(),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: payload.clone(),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: payload.clone(),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     assert_eq!(result, message_id);
@@ -258,12 +619,76 @@ fn test_submit_message_invalid_chain() {
             signature: dummy_sig(&env),
             v_signature: dummy_sig(&env),
             v_nonce: 1,
+            fee: 0,
         },
     );
 
     assert_eq!(result, Err(Ok(Error::ChainNotSupported)));
 }
 
+fn signed_test_request(
+    env: &Env,
+    sk: &SigningKey,
+    recipient: &Address,
+    payload: &str,
+) -> SubmitMessageRequest {
+    let message_id = generate_message_id(env);
+    sign_request(
+        env,
+        sk,
+        SubmitMessageRequest {
+            message_id: message_id.clone(),
+            source_chain: ChainId::Ethereum,
+            dest_chain: ChainId::Stellar,
+            sender: String::from_str(env, "0x1234567890abcdef"),
+            recipient: recipient.clone(),
+            payload_type: MessageType::RecordRequest,
+            payload: String::from_str(env, payload),
+            nonce: 1,
+            signature: dummy_sig(env),
+            v_signature: create_sig(env, sk, &message_id, 1),
+            v_nonce: 1,
+            fee: 0,
+        },
+    )
+}
+
+#[test]
+fn test_submit_message_with_valid_signature_is_accepted() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+    let request = signed_test_request(&env, &sk, &recipient, "{\"record_id\": 1}");
+    let message_id = client.submit_message(&validator, &request);
+
+    let stored = client.get_message(&message_id).unwrap();
+    assert_eq!(stored.signature, request.signature);
+}
+
+#[test]
+fn test_submit_message_with_tampered_payload_is_rejected() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+    let mut request = signed_test_request(&env, &sk, &recipient, "{\"record_id\": 1}");
+    request.payload = String::from_str(&env, "{\"record_id\": 2}");
+
+    assert_eq!(
+        client.try_submit_message(&validator, &request),
+        Err(Ok(Error::InvalidSignature))
+    );
+    assert!(client.get_message(&request.message_id).is_none());
+    assert_eq!(client.get_message_count(), 0);
+}
+
 #[test]
 fn test_confirm_message() {
     let env = Env::default();
@@ -280,19 +705,24 @@ fn test_confirm_message() {
     let v_sig1 = create_sig(&env, &sk1, &message_id, 1);
     client.submit_message(
         &validator1,
-        &SubmitMessageRequest {
-            message_id: message_id.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0x1234567890abcdef"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\": 1}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig1,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0x1234567890abcdef"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 1}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig1,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     let confirm_sig1 = create_sig(&env, &sk1, &message_id, 2);
@@ -306,59 +736,256 @@ fn test_confirm_message() {
     assert_eq!(msg.status, MessageStatus::Verified);
 }
 
-// ==================== Storage Key Uniqueness Regression Tests ====================
-
-/// Regression test: two different messages must have independent confirmation tracking
 #[test]
-fn test_confirmations_unique_per_message() {
+fn test_chain_min_confirmations_override() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
     let (validator1, sk1) = setup_validator(&env, &client, &admin);
     let (validator2, sk2) = setup_validator(&env, &client, &admin);
-    let recipient = Address::generate(&env);
+    let (validator3, sk3) = setup_validator(&env, &client, &admin);
 
     env.mock_all_auths();
 
-    // Submit message A
-    let msg_id_a = BytesN::from_array(&env, &[0xaau8; 32]);
-    let v_sig_a = create_sig(&env, &sk1, &msg_id_a, 1);
+    client.set_chain_min_confirmations(&admin, &ChainId::Polygon, &3);
+    assert_eq!(client.get_chain_min_confirmations(&ChainId::Polygon), 3);
+    assert_eq!(client.get_chain_min_confirmations(&ChainId::Ethereum), 2);
+
+    // Ethereum uses the global default of 2
+    let eth_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xe1, 1);
+
+    let polygon_id = BytesN::from_array(&env, &[0x90u8; 32]);
     client.submit_message(
         &validator1,
-        &SubmitMessageRequest {
-            message_id: msg_id_a.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xAAA"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_a,
-            v_nonce: 1,
-        },
-    );
-
+        &sign_request(
+            &env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: polygon_id.clone(),
+                source_chain: ChainId::Polygon,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xpolygonsender"),
+                recipient: Address::generate(&env),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 2}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: create_sig(&env, &sk1, &polygon_id, 2),
+                v_nonce: 2,
+                fee: 0,
+            },
+        ),
+    );
+
+    client.confirm_message(
+        &validator1,
+        &eth_id,
+        &create_sig(&env, &sk1, &eth_id, 3),
+        &3,
+    );
+    client.confirm_message(
+        &validator2,
+        &eth_id,
+        &create_sig(&env, &sk2, &eth_id, 1),
+        &1,
+    );
+    assert_eq!(
+        client.get_message(&eth_id).unwrap().status,
+        MessageStatus::Verified
+    );
+
+    client.confirm_message(
+        &validator1,
+        &polygon_id,
+        &create_sig(&env, &sk1, &polygon_id, 4),
+        &4,
+    );
+    client.confirm_message(
+        &validator2,
+        &polygon_id,
+        &create_sig(&env, &sk2, &polygon_id, 2),
+        &2,
+    );
+    assert_eq!(
+        client.get_message(&polygon_id).unwrap().status,
+        MessageStatus::Pending
+    );
+
+    client.confirm_message(
+        &validator3,
+        &polygon_id,
+        &create_sig(&env, &sk3, &polygon_id, 1),
+        &1,
+    );
+    assert_eq!(
+        client.get_message(&polygon_id).unwrap().status,
+        MessageStatus::Verified
+    );
+}
+
+#[test]
+fn test_confirm_messages_batch() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    let verified_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xb1, 1);
+    let pending_a = submit_indexed_message(&env, &client, &validator1, &sk1, 0xb2, 2);
+    let pending_b = submit_indexed_message(&env, &client, &validator1, &sk1, 0xb3, 3);
+
+    // First message already reaches the threshold of 2 before the batch.
+    client.confirm_message(
+        &validator1,
+        &verified_id,
+        &create_sig(&env, &sk1, &verified_id, 4),
+        &4,
+    );
+    client.confirm_message(
+        &validator2,
+        &verified_id,
+        &create_sig(&env, &sk2, &verified_id, 1),
+        &1,
+    );
+    let batch = Vec::from_array(&env, [pending_a.clone(), pending_b.clone()]);
+    client.confirm_messages_batch(&validator1, &batch, &sign_batch(&env, &sk1, &batch, 5), &5);
+
+    // The signature binds the exact id list and a fresh validator nonce.
+    let batch = Vec::from_array(
+        &env,
+        [verified_id.clone(), pending_a.clone(), pending_b.clone()],
+    );
+    let replayed = client.try_confirm_messages_batch(
+        &validator2,
+        &batch,
+        &sign_batch(&env, &sk2, &batch, 1),
+        &1,
+    );
+    assert_eq!(replayed, Err(Ok(Error::InvalidNonce)));
+    let tampered = client.try_confirm_messages_batch(
+        &validator2,
+        &batch,
+        &sign_batch(&env, &sk2, &Vec::from_array(&env, [pending_a.clone()]), 2),
+        &2,
+    );
+    assert_eq!(tampered, Err(Ok(Error::InvalidSignature)));
+
+    let outcomes =
+        client.confirm_messages_batch(&validator2, &batch, &sign_batch(&env, &sk2, &batch, 2), &2);
+    assert_eq!(
+        outcomes,
+        Vec::from_array(
+            &env,
+            [
+                (verified_id, false),
+                (pending_a.clone(), true),
+                (pending_b.clone(), true),
+            ],
+        )
+    );
+    assert_eq!(
+        client.get_message(&pending_a).unwrap().status,
+        MessageStatus::Verified
+    );
+    assert_eq!(
+        client.get_message(&pending_b).unwrap().status,
+        MessageStatus::Verified
+    );
+
+    let empty = Vec::new(&env);
+    let result = client.try_confirm_messages_batch(
+        &validator2,
+        &empty,
+        &sign_batch(&env, &sk2, &empty, 3),
+        &3,
+    );
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge)));
+}
+
+#[test]
+fn test_set_chain_min_confirmations_validation() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    env.mock_all_auths();
+
+    let result =
+        client.try_set_chain_min_confirmations(&Address::generate(&env), &ChainId::Polygon, &3);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let result = client.try_set_chain_min_confirmations(&admin, &ChainId::Polygon, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+// ==================== Storage Key Uniqueness Regression Tests ====================
+
+/// Regression test: two different messages must have independent confirmation tracking
+#[test]
+fn test_confirmations_unique_per_message() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    // Submit message A
+    let msg_id_a = BytesN::from_array(&env, &[0xaau8; 32]);
+    let v_sig_a = create_sig(&env, &sk1, &msg_id_a, 1);
+    client.submit_message(
+        &validator1,
+        &sign_request(
+            &env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: msg_id_a.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xAAA"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_a,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
+    );
+
     // Submit message B (different data, same validator, needs nonce 2)
     let msg_id_b = BytesN::from_array(&env, &[0xbbu8; 32]);
     let v_sig_b = create_sig(&env, &sk1, &msg_id_b, 2);
     client.submit_message(
         &validator1,
-        &SubmitMessageRequest {
-            message_id: msg_id_b.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xBBB"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_b,
-            v_nonce: 2,
-        },
+        &sign_request(
+            &env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: msg_id_b.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xBBB"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_b,
+                v_nonce: 2,
+                fee: 0,
+            },
+        ),
     );
 
     // Confirm only message A with both validators (nonce resets per validator pubkey)
@@ -461,19 +1088,24 @@ fn test_execute_message() {
     let v_sig = create_sig(&env, &sk1, &message_id, 1);
     client.submit_message(
         &validator1,
-        &SubmitMessageRequest {
-            message_id: message_id.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0x1234567890abcdef"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\": 1}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0x1234567890abcdef"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 1}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     let conf_sig1 = create_sig(&env, &sk1, &message_id, 2);
@@ -488,119 +1120,588 @@ fn test_execute_message() {
     assert_eq!(msg.status, MessageStatus::Executed);
 }
 
-// ==================== Atomic Transaction Tests ====================
+fn submit_indexed_message(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    validator: &Address,
+    sk: &SigningKey,
+    id_byte: u8,
+    v_nonce: u64,
+) -> BytesN<32> {
+    submit_message_with_id(env, client, validator, sk, [id_byte; 32], v_nonce)
+}
+
+fn submit_message_with_id(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    validator: &Address,
+    sk: &SigningKey,
+    id: [u8; 32],
+    v_nonce: u64,
+) -> BytesN<32> {
+    let message_id = BytesN::from_array(env, &id);
+    client.submit_message(
+        validator,
+        &sign_request(
+            env,
+            sk,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(env, "0x1234567890abcdef"),
+                recipient: Address::generate(env),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(env, "{\"record_id\": 1}"),
+                nonce: v_nonce,
+                signature: dummy_sig(env),
+                v_signature: create_sig(env, sk, &message_id, v_nonce),
+                v_nonce,
+                fee: 0,
+            },
+        ),
+    );
+    message_id
+}
 
 #[test]
-fn test_atomic_transaction_flow() {
+fn test_get_messages_by_status() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
     let (validator1, sk1) = setup_validator(&env, &client, &admin);
     let (validator2, sk2) = setup_validator(&env, &client, &admin);
-    let caller = Address::generate(&env);
 
     env.mock_all_auths();
 
-    let tx_id = BytesN::from_array(&env, &[5u8; 32]);
-    let message_ids = soroban_sdk::vec![&env, generate_message_id(&env)];
-
-    let result = client.initiate_atomic_tx(&caller, &tx_id, &message_ids);
-    assert_eq!(result, tx_id);
+    let msg_a = submit_indexed_message(&env, &client, &validator1, &sk1, 0xa1, 1);
+    let msg_b = submit_indexed_message(&env, &client, &validator1, &sk1, 0xb2, 2);
+
+    let pending = client.get_messages_by_status(&MessageStatus::Pending, &None, &10);
+    assert_eq!(pending.messages.len(), 2);
+    assert_eq!(pending.messages.get(0).unwrap().message_id, msg_a);
+    assert_eq!(pending.messages.get(1).unwrap().message_id, msg_b);
+    assert_eq!(pending.next_cursor, None);
+
+    // Paging walks the matches in submission order via the returned cursor
+    let first_page = client.get_messages_by_status(&MessageStatus::Pending, &None, &1);
+    assert_eq!(first_page.messages.get(0).unwrap().message_id, msg_a);
+    assert_eq!(first_page.next_cursor, Some(1));
+    let second_page =
+        client.get_messages_by_status(&MessageStatus::Pending, &first_page.next_cursor, &1);
+    assert_eq!(second_page.messages.len(), 1);
+    assert_eq!(second_page.messages.get(0).unwrap().message_id, msg_b);
+    assert_eq!(second_page.next_cursor, None);
+
+    client.confirm_message(&validator1, &msg_a, &create_sig(&env, &sk1, &msg_a, 3), &3);
+    client.confirm_message(&validator2, &msg_a, &create_sig(&env, &sk2, &msg_a, 1), &1);
+    client.execute_message(&Address::generate(&env), &msg_a);
+
+    let pending = client.get_messages_by_status(&MessageStatus::Pending, &None, &10);
+    assert_eq!(pending.messages.len(), 1);
+    assert_eq!(pending.messages.get(0).unwrap().message_id, msg_b);
+
+    let executed = client.get_messages_by_status(&MessageStatus::Executed, &None, &10);
+    assert_eq!(executed.messages.len(), 1);
+    assert_eq!(executed.messages.get(0).unwrap().message_id, msg_a);
+
+    // Past the expiry window both the executed message and the one left
+    // Pending are evicted on the next submit
+    env.ledger().with_mut(|li| li.timestamp += 86_400 + 1);
+    let msg_c = submit_indexed_message(&env, &client, &validator1, &sk1, 0xc3, 4);
+
+    let executed = client.get_messages_by_status(&MessageStatus::Executed, &None, &10);
+    assert_eq!(executed.messages.len(), 0);
+    assert_eq!(executed.next_cursor, None);
+    // Still readable by point lookup
+    assert!(client.get_message(&msg_a).is_some());
+    assert!(client.get_message(&msg_b).is_some());
+    let pending = client.get_messages_by_status(&MessageStatus::Pending, &None, &10);
+    assert_eq!(pending.messages.len(), 1);
+    assert_eq!(pending.messages.get(0).unwrap().message_id, msg_c);
+    // A cursor from before the eviction is clamped to the new head
+    let stale_cursor = client.get_messages_by_status(&MessageStatus::Pending, &Some(0), &10);
+    assert_eq!(stale_cursor.messages.len(), 1);
+}
 
-    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
-    assert_eq!(atomic_tx.status, AtomicTxStatus::Initiated);
+#[test]
+fn test_message_status_scan_is_bounded() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
 
-    let prep_sig1 = create_sig(&env, &sk1, &tx_id, 1);
-    client.prepare_atomic_tx(&validator1, &tx_id, &prep_sig1, &1);
-    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
-    assert_eq!(atomic_tx.status, AtomicTxStatus::Initiated);
+    // More live entries than one call may examine
+    let total = MAX_STATUS_SCAN + 5;
+    for i in 0..total {
+        let mut id = [0u8; 32];
+        id[..4].copy_from_slice(&i.to_be_bytes());
+        id[31] = 0xee;
+        submit_message_with_id(&env, &client, &validator, &sk, id, u64::from(i) + 1);
+    }
 
-    let prep_sig2 = create_sig(&env, &sk2, &tx_id, 1);
-    client.prepare_atomic_tx(&validator2, &tx_id, &prep_sig2, &1);
-    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
-    assert_eq!(atomic_tx.status, AtomicTxStatus::Prepared);
+    let first = client.get_messages_by_status(&MessageStatus::Executed, &None, &10);
+    assert_eq!(first.messages.len(), 0);
+    assert_eq!(first.next_cursor, Some(u64::from(MAX_STATUS_SCAN)));
 
-    client.commit_atomic_tx(&caller, &tx_id);
-    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
-    assert_eq!(atomic_tx.status, AtomicTxStatus::Committed);
+    let rest = client.get_messages_by_status(&MessageStatus::Pending, &first.next_cursor, &10);
+    assert_eq!(rest.messages.len(), 5);
+    assert_eq!(rest.next_cursor, None);
 }
 
 #[test]
-fn test_abort_atomic_tx() {
+fn test_sweep_expired_messages() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
-    let caller = Address::generate(&env);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
 
     env.mock_all_auths();
 
-    let tx_id = BytesN::from_array(&env, &[5u8; 32]);
-    let message_ids = soroban_sdk::vec![&env, generate_message_id(&env)];
+    let stale_id = submit_indexed_message(&env, &client, &validator, &sk, 0xa1, 1);
+    env.ledger().with_mut(|li| li.timestamp += 86_400 + 1);
+    let fresh_id = submit_indexed_message(&env, &client, &validator, &sk, 0xb2, 2);
 
-    client.initiate_atomic_tx(&caller, &tx_id, &message_ids);
-    client.abort_atomic_tx(&caller, &tx_id);
+    let swept = client.sweep_expired_messages(
+        &validator,
+        &soroban_sdk::vec![&env, stale_id.clone(), fresh_id.clone()],
+    );
+    assert_eq!(swept, 1);
+    assert_eq!(
+        client.get_message(&stale_id).unwrap().status,
+        MessageStatus::Expired
+    );
+    assert_eq!(
+        client.get_message(&fresh_id).unwrap().status,
+        MessageStatus::Pending
+    );
 
-    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
-    assert_eq!(atomic_tx.status, AtomicTxStatus::Aborted);
+    // Sweeping again is a no-op
+    let swept = client.sweep_expired_messages(&validator, &soroban_sdk::vec![&env, stale_id]);
+    assert_eq!(swept, 0);
 }
 
-// ==================== Record Reference Tests ====================
-
 #[test]
-fn test_register_record_ref() {
+fn test_sweep_expired_messages_requires_validator_and_bounded_batch() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
-    let caller = Address::generate(&env);
+    let (validator, _sk) = setup_validator(&env, &client, &admin);
 
     env.mock_all_auths();
 
-    let external_record_id = String::from_str(&env, "eth_record_123");
-    let result = client.register_record_ref(&caller, &1, &ChainId::Ethereum, &external_record_id);
-    assert!(result);
+    let ids = soroban_sdk::vec![&env, generate_message_id(&env)];
+    let result = client.try_sweep_expired_messages(&Address::generate(&env), &ids);
+    assert_eq!(result, Err(Ok(Error::ValidatorNotFound)));
 
-    let record_ref = client.get_record_ref(&1, &ChainId::Ethereum).unwrap();
-    assert_eq!(record_ref.local_record_id, 1);
-    assert_eq!(record_ref.sync_status, SyncStatus::PendingSync);
+    let result = client.try_sweep_expired_messages(&validator, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidInput)));
+
+    let mut too_many = Vec::new(&env);
+    for i in 0..51u8 {
+        too_many.push_back(BytesN::from_array(&env, &[i; 32]));
+    }
+    let result = client.try_sweep_expired_messages(&validator, &too_many);
+    assert_eq!(result, Err(Ok(Error::BatchTooLarge)));
 }
 
 #[test]
-fn test_update_sync_status() {
+fn test_executed_message_id_cannot_be_resubmitted_after_sweep() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
-    let (validator, sk) = setup_validator(&env, &client, &admin);
-    let caller = Address::generate(&env);
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
 
     env.mock_all_auths();
-    let external_record_id = String::from_str(&env, "eth_record_123");
-    client.register_record_ref(&caller, &1, &ChainId::Ethereum, &external_record_id);
 
-    let mut target_id_bytes = [0u8; 32];
-    target_id_bytes[24..32].copy_from_slice(&1u64.to_be_bytes());
-    let target_id = BytesN::from_array(&env, &target_id_bytes);
-    let update_sig = create_sig(&env, &sk, &target_id, 1);
-    client.update_sync_status(
-        &validator,
-        &1,
-        &ChainId::Ethereum,
-        &SyncStatus::Synced,
-        &update_sig,
+    let message_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xc1, 1);
+    let batch = soroban_sdk::vec![&env, message_id.clone()];
+    client.confirm_messages_batch(&validator1, &batch, &sign_batch(&env, &sk1, &batch, 2), &2);
+    client.confirm_messages_batch(&validator2, &batch, &sign_batch(&env, &sk2, &batch, 1), &1);
+    client.execute_message(&validator1, &message_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400 + 1);
+    client.sweep_expired_messages(&validator1, &soroban_sdk::vec![&env, message_id.clone()]);
+
+    // Drop the message record itself, as archival of the entry would.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Message(message_id.clone()));
+    });
+    assert!(client.get_message(&message_id).is_none());
+
+    let result = client.try_submit_message(
+        &validator2,
+        &sign_request(
+            &env,
+            &sk2,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0x1234567890abcdef"),
+                recipient: Address::generate(&env),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 1}"),
+                nonce: 2,
+                signature: dummy_sig(&env),
+                v_signature: create_sig(&env, &sk2, &message_id, 1),
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
+    );
+    assert_eq!(result, Err(Ok(Error::MessageAlreadyProcessed)));
+}
+
+// ==================== Relayer Fee Tests ====================
+
+/// Fund the fee pool with `pool` tokens and run a message carrying `fee`
+/// through to execution by `relayer`, which is made an authorized relayer.
+fn execute_fee_message(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    admin: &Address,
+    relayer: &Address,
+    pool: i128,
+    fee: i128,
+) -> (BytesN<32>, token::Client<'static>) {
+    let (validator1, sk1) = setup_validator(env, client, admin);
+    let (validator2, sk2) = setup_validator(env, client, admin);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_fee_token(admin, &token_id);
+    client.set_max_message_fee(admin, &200);
+    client.add_relayer(admin, relayer);
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_id).mint(&depositor, &pool);
+    client.deposit_fees(&depositor, &pool);
+
+    let message_id = BytesN::from_array(env, &[0xfe; 32]);
+    client.submit_message(
+        &validator1,
+        &sign_request(
+            env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(env, "0x1234567890abcdef"),
+                recipient: Address::generate(env),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(env, "{\"record_id\": 1}"),
+                nonce: 1,
+                signature: dummy_sig(env),
+                v_signature: create_sig(env, &sk1, &message_id, 1),
+                v_nonce: 1,
+                fee,
+            },
+        ),
+    );
+    client.confirm_message(
+        &validator1,
+        &message_id,
+        &create_sig(env, &sk1, &message_id, 2),
+        &2,
+    );
+    client.confirm_message(
+        &validator2,
+        &message_id,
+        &create_sig(env, &sk2, &message_id, 1),
         &1,
     );
+    client.execute_message(relayer, &message_id);
 
-    let record_ref = client.get_record_ref(&1, &ChainId::Ethereum).unwrap();
-    assert_eq!(record_ref.sync_status, SyncStatus::Synced);
+    (message_id, token::Client::new(env, &token_id))
 }
 
-// ==================== Oracle Network Tests ====================
-
 #[test]
-fn test_register_oracle() {
+fn test_claim_relayer_fee() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    let relayer = Address::generate(&env);
+    let (message_id, token) = execute_fee_message(&env, &client, &admin, &relayer, 500, 120);
+    assert_eq!(client.get_fee_pool(), 500);
+
+    // Only the relayer that executed the message is paid.
+    let other = Address::generate(&env);
+    client.add_relayer(&admin, &other);
+    assert_eq!(
+        client.try_claim_relayer_fee(&other, &message_id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(client.claim_relayer_fee(&relayer, &message_id), 120);
+    assert_eq!(token.balance(&relayer), 120);
+    assert_eq!(token.balance(&client.address), 380);
+    assert_eq!(client.get_fee_pool(), 380);
+    assert!(client.is_fee_claimed(&message_id));
+}
+
+#[test]
+fn test_claim_relayer_fee_twice_fails() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    let relayer = Address::generate(&env);
+    let (message_id, token) = execute_fee_message(&env, &client, &admin, &relayer, 500, 120);
+
+    client.claim_relayer_fee(&relayer, &message_id);
+    assert_eq!(
+        client.try_claim_relayer_fee(&relayer, &message_id),
+        Err(Ok(Error::FeeAlreadyClaimed))
+    );
+    assert_eq!(token.balance(&relayer), 120);
+    assert_eq!(client.get_fee_pool(), 380);
+}
+
+#[test]
+fn test_claim_relayer_fee_beyond_pool_fails() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    let relayer = Address::generate(&env);
+    let (message_id, token) = execute_fee_message(&env, &client, &admin, &relayer, 100, 120);
+    assert_eq!(
+        client.try_claim_relayer_fee(&relayer, &message_id),
+        Err(Ok(Error::InsufficientFeePool))
+    );
+    assert_eq!(token.balance(&relayer), 0);
+    assert!(!client.is_fee_claimed(&message_id));
+}
+
+#[test]
+fn test_claim_relayer_fee_requires_execution() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    let message_id = submit_indexed_message(&env, &client, &validator, &sk, 0xd4, 1);
+    client.add_relayer(&admin, &validator);
+    assert_eq!(
+        client.try_claim_relayer_fee(&validator, &message_id),
+        Err(Ok(Error::MessageNotExecuted))
+    );
+}
+
+#[test]
+fn test_claim_relayer_fee_requires_authorized_relayer() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    let relayer = Address::generate(&env);
+    let (message_id, token) = execute_fee_message(&env, &client, &admin, &relayer, 500, 120);
+    client.remove_relayer(&admin, &relayer);
+    assert_eq!(
+        client.try_claim_relayer_fee(&relayer, &message_id),
+        Err(Ok(Error::UnauthorizedRelayer))
+    );
+    assert_eq!(token.balance(&relayer), 0);
+    assert_eq!(client.get_fee_pool(), 500);
+}
+
+#[test]
+fn test_message_fee_is_capped() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    let message_id = BytesN::from_array(&env, &[0xfc; 32]);
+    let request = |fee: i128| {
+        sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0x1234567890abcdef"),
+                recipient: Address::generate(&env),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 1}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: create_sig(&env, &sk, &message_id, 1),
+                v_nonce: 1,
+                fee,
+            },
+        )
+    };
+
+    // No fee may be attached until the admin sets a cap.
+    assert_eq!(
+        client.try_submit_message(&validator, &request(1)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_max_message_fee(&admin, &100);
+    assert_eq!(
+        client.try_submit_message(&validator, &request(101)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.submit_message(&validator, &request(100));
+    assert_eq!(client.get_message(&message_id).unwrap().fee, 100);
+}
+
+#[test]
+fn test_fee_token_locked_while_pool_funded() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    let relayer = Address::generate(&env);
+    let (message_id, _) = execute_fee_message(&env, &client, &admin, &relayer, 120, 120);
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    assert_eq!(
+        client.try_set_fee_token(&admin, &other_token),
+        Err(Ok(Error::FeePoolNotEmpty))
+    );
+
+    // Once the pool is drained the token may change.
+    client.claim_relayer_fee(&relayer, &message_id);
+    assert_eq!(client.get_fee_pool(), 0);
+    assert!(client.set_fee_token(&admin, &other_token));
+}
+
+// ==================== Atomic Transaction Tests ====================
+
+#[test]
+fn test_atomic_transaction_flow() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+    let caller = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let tx_id = BytesN::from_array(&env, &[5u8; 32]);
+    let message_ids = soroban_sdk::vec![&env, generate_message_id(&env)];
+
+    let result = client.initiate_atomic_tx(&caller, &tx_id, &message_ids);
+    assert_eq!(result, tx_id);
+
+    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
+    assert_eq!(atomic_tx.status, AtomicTxStatus::Initiated);
+
+    let prep_sig1 = create_sig(&env, &sk1, &tx_id, 1);
+    client.prepare_atomic_tx(&validator1, &tx_id, &prep_sig1, &1);
+    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
+    assert_eq!(atomic_tx.status, AtomicTxStatus::Initiated);
+
+    let prep_sig2 = create_sig(&env, &sk2, &tx_id, 1);
+    client.prepare_atomic_tx(&validator2, &tx_id, &prep_sig2, &1);
+    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
+    assert_eq!(atomic_tx.status, AtomicTxStatus::Prepared);
+
+    client.commit_atomic_tx(&caller, &tx_id);
+    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
+    assert_eq!(atomic_tx.status, AtomicTxStatus::Committed);
+}
+
+#[test]
+fn test_abort_atomic_tx() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let caller = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let tx_id = BytesN::from_array(&env, &[5u8; 32]);
+    let message_ids = soroban_sdk::vec![&env, generate_message_id(&env)];
+
+    client.initiate_atomic_tx(&caller, &tx_id, &message_ids);
+    client.abort_atomic_tx(&caller, &tx_id);
+
+    let atomic_tx = client.get_atomic_tx(&tx_id).unwrap();
+    assert_eq!(atomic_tx.status, AtomicTxStatus::Aborted);
+}
+
+// ==================== Record Reference Tests ====================
+
+#[test]
+fn test_register_record_ref() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let caller = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let external_record_id = String::from_str(&env, "eth_record_123");
+    let result = client.register_record_ref(&caller, &1, &ChainId::Ethereum, &external_record_id);
+    assert!(result);
+
+    let record_ref = client.get_record_ref(&1, &ChainId::Ethereum).unwrap();
+    assert_eq!(record_ref.local_record_id, 1);
+    assert_eq!(record_ref.sync_status, SyncStatus::PendingSync);
+}
+
+#[test]
+fn test_update_sync_status() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let caller = Address::generate(&env);
+
+    env.mock_all_auths();
+    let external_record_id = String::from_str(&env, "eth_record_123");
+    client.register_record_ref(&caller, &1, &ChainId::Ethereum, &external_record_id);
+
+    let mut target_id_bytes = [0u8; 32];
+    target_id_bytes[24..32].copy_from_slice(&1u64.to_be_bytes());
+    let target_id = BytesN::from_array(&env, &target_id_bytes);
+    let update_sig = create_sig(&env, &sk, &target_id, 1);
+    client.update_sync_status(
+        &validator,
+        &1,
+        &ChainId::Ethereum,
+        &SyncStatus::Synced,
+        &update_sig,
+        &1,
+    );
+
+    let record_ref = client.get_record_ref(&1, &ChainId::Ethereum).unwrap();
+    assert_eq!(record_ref.sync_status, SyncStatus::Synced);
+}
+
+// ==================== Oracle Network Tests ====================
+
+#[test]
+fn test_register_oracle() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
@@ -659,6 +1760,7 @@ fn test_submit_oracle_report() {
         &data_hash,
         &data,
         &100000,
+        &env.ledger().timestamp(),
         &dummy_sig(&env),
     );
 
@@ -676,52 +1778,537 @@ fn test_submit_oracle_report() {
 }
 
 #[test]
-fn test_aggregate_oracle_data() {
+fn test_submit_oracle_report_rejects_replayed_block_height() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let oracle = Address::generate(&env);
+    let other_oracle = Address::generate(&env);
+    let chains = soroban_sdk::vec![&env, ChainId::Ethereum, ChainId::Polygon];
+    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
+    let data = String::from_str(&env, "{}");
+    let now = env.ledger().timestamp();
+
+    env.mock_all_auths();
+    client.register_oracle(&admin, &oracle, &data_hash, &chains);
+    client.register_oracle(&admin, &other_oracle, &data_hash, &chains);
+
+    client.submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &data,
+        &100,
+        &now,
+        &dummy_sig(&env),
+    );
+
+    // A repeat of the watermark height is a duplicate; lower heights are stale
+    for (height, expected) in [
+        (100u64, Error::DuplicateOracleReport),
+        (99, Error::StaleReport),
+    ] {
+        let result = client.try_submit_oracle_report(
+            &oracle,
+            &ChainId::Ethereum,
+            &data_hash,
+            &data,
+            &height,
+            &now,
+            &dummy_sig(&env),
+        );
+        assert_eq!(result, Err(Ok(expected)));
+    }
+
+    // The watermark is per oracle and per chain
+    client.submit_oracle_report(
+        &other_oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &data,
+        &100,
+        &now,
+        &dummy_sig(&env),
+    );
+    client.submit_oracle_report(
+        &oracle,
+        &ChainId::Polygon,
+        &data_hash,
+        &data,
+        &50,
+        &now,
+        &dummy_sig(&env),
+    );
+    client.submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &data,
+        &101,
+        &now,
+        &dummy_sig(&env),
+    );
+}
+
+#[test]
+fn test_submit_oracle_report_rejects_duplicate() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let oracle = Address::generate(&env);
+    let chains = soroban_sdk::vec![&env, ChainId::Ethereum];
+    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
+    let now = env.ledger().timestamp();
+
+    env.mock_all_auths();
+    client.register_oracle(&admin, &oracle, &data_hash, &chains);
+
+    client.submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &String::from_str(&env, "{}"),
+        &100,
+        &now,
+        &dummy_sig(&env),
+    );
+
+    // Even with different data, the same (oracle, chain, block) is caught by
+    // the block height watermark
+    let result = client.try_submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &BytesN::from_array(&env, &[0xcdu8; 32]),
+        &String::from_str(&env, "{\"forked\": true}"),
+        &100,
+        &now,
+        &dummy_sig(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::DuplicateOracleReport)));
+    assert_eq!(client.get_oracle_count(), 1);
+}
+
+#[test]
+fn test_submit_oracle_report_rejects_future_observation() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let oracle = Address::generate(&env);
+    let chains = soroban_sdk::vec![&env, ChainId::Ethereum];
+    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    env.mock_all_auths();
+    client.register_oracle(&admin, &oracle, &data_hash, &chains);
+
+    // A future observed_at would otherwise stay inside the max age window longer
+    let result = client.try_submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &String::from_str(&env, "{}"),
+        &100,
+        &10_001,
+        &dummy_sig(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::FutureReport)));
+    assert_eq!(client.get_oracle_count(), 0);
+
+    client.submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &String::from_str(&env, "{}"),
+        &100,
+        &10_000,
+        &dummy_sig(&env),
+    );
+}
+
+#[test]
+fn test_submit_oracle_report_rejects_over_age_timestamp() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let oracle = Address::generate(&env);
+    let chains = soroban_sdk::vec![&env, ChainId::Ethereum];
+    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
+    let data = String::from_str(&env, "{}");
+
+    env.mock_all_auths();
+    client.register_oracle(&admin, &oracle, &data_hash, &chains);
+    client.set_max_report_age(&admin, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let result = client.try_submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &data,
+        &100,
+        &(10_000 - 601),
+        &dummy_sig(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::StaleReport)));
+
+    let report_id = client.submit_oracle_report(
+        &oracle,
+        &ChainId::Ethereum,
+        &data_hash,
+        &data,
+        &100,
+        &(10_000 - 600),
+        &dummy_sig(&env),
+    );
+    assert_eq!(
+        client.get_oracle_report(&report_id).unwrap().timestamp,
+        9_400
+    );
+}
+
+#[test]
+fn test_aggregate_oracle_data() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+
+    // Register 3 oracles and submit reports
+    let oracle1 = Address::generate(&env);
+    let oracle2 = Address::generate(&env);
+    let oracle3 = Address::generate(&env);
+    let chains = soroban_sdk::vec![&env, ChainId::Ethereum];
+    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
+
+    env.mock_all_auths();
+
+    let mut report_ids = soroban_sdk::vec![&env];
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        client.register_oracle(&admin, oracle, &data_hash, &chains);
+        let rid = client.submit_oracle_report(
+            oracle,
+            &ChainId::Ethereum,
+            &data_hash,
+            &String::from_str(&env, "{}"),
+            &100,
+            &env.ledger().timestamp(),
+            &dummy_sig(&env),
+        );
+        report_ids.push_back(rid);
+    }
+
+    let consensus_hash = data_hash.clone();
+    let agg_sig = create_sig(&env, &sk, &consensus_hash, 1);
+    let result = client.aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &report_ids,
+        &consensus_hash,
+        &agg_sig,
+        &1,
+    );
+    assert!(result);
+
+    let aggregated = client.get_aggregated_oracle(&ChainId::Ethereum).unwrap();
+    assert!(aggregated.is_finalized);
+    assert_eq!(aggregated.report_count, 3);
+    assert_eq!(aggregated.consensus_hash, consensus_hash);
+}
+
+/// Register one oracle per entry with the given reputation, each reporting its hash.
+fn setup_weighted_reports<const N: usize>(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    admin: &Address,
+    reports: [(u32, &BytesN<32>); N],
+) -> (soroban_sdk::Vec<Address>, soroban_sdk::Vec<u64>) {
+    let chains = soroban_sdk::vec![env, ChainId::Ethereum];
+    let mut oracles = soroban_sdk::vec![env];
+    let mut report_ids = soroban_sdk::vec![env];
+    for (reputation, data_hash) in reports {
+        let oracle = Address::generate(env);
+        client.register_oracle(
+            admin,
+            &oracle,
+            &BytesN::from_array(env, &[3u8; 32]),
+            &chains,
+        );
+        client.set_oracle_reputation(admin, &oracle, &reputation);
+        let rid = client.submit_oracle_report(
+            &oracle,
+            &ChainId::Ethereum,
+            data_hash,
+            &String::from_str(env, "{}"),
+            &100,
+            &env.ledger().timestamp(),
+            &dummy_sig(env),
+        );
+        oracles.push_back(oracle);
+        report_ids.push_back(rid);
+    }
+    (oracles, report_ids)
+}
+
+#[test]
+fn test_aggregate_oracle_data_outvotes_low_reputation_outlier() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    let honest = BytesN::from_array(&env, &[0xabu8; 32]);
+    let outlier = BytesN::from_array(&env, &[0xeeu8; 32]);
+    let (oracles, report_ids) = setup_weighted_reports(
+        &env,
+        &client,
+        &admin,
+        [(80, &honest), (60, &honest), (40, &honest), (10, &outlier)],
+    );
+
+    let agg_sig = create_sig(&env, &sk, &honest, 1);
+    assert!(client.aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &report_ids,
+        &honest,
+        &agg_sig,
+        &1,
+    ));
+
+    // Agreeing oracles are rewarded, the outlier is penalized
+    assert_eq!(
+        client
+            .get_oracle_node(&oracles.get(0).unwrap())
+            .unwrap()
+            .reputation,
+        82
+    );
+    assert_eq!(
+        client
+            .get_oracle_node(&oracles.get(1).unwrap())
+            .unwrap()
+            .reputation,
+        62
+    );
+    assert_eq!(
+        client
+            .get_oracle_node(&oracles.get(2).unwrap())
+            .unwrap()
+            .reputation,
+        42
+    );
+    assert_eq!(
+        client
+            .get_oracle_node(&oracles.get(3).unwrap())
+            .unwrap()
+            .reputation,
+        5
+    );
+
+    assert_eq!(
+        client
+            .get_oracle_report(&report_ids.get(0).unwrap())
+            .unwrap()
+            .status,
+        OracleStatus::Aggregated
+    );
+    assert_eq!(
+        client
+            .get_oracle_report(&report_ids.get(3).unwrap())
+            .unwrap()
+            .status,
+        OracleStatus::Submitted
+    );
+}
+
+#[test]
+fn test_aggregate_oracle_data_ignores_settled_dissent() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    let honest = BytesN::from_array(&env, &[0xabu8; 32]);
+    let outlier = BytesN::from_array(&env, &[0xeeu8; 32]);
+    let (oracles, report_ids) = setup_weighted_reports(
+        &env,
+        &client,
+        &admin,
+        [(80, &honest), (60, &honest), (40, &honest), (10, &outlier)],
+    );
+    let agg_sig = create_sig(&env, &sk, &honest, 1);
+    assert!(client.aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &report_ids,
+        &honest,
+        &agg_sig,
+        &1,
+    ));
+    let dissent_id = report_ids.get(3).unwrap();
+    assert_eq!(
+        client.get_oracle_report(&dissent_id).unwrap().status,
+        OracleStatus::Submitted
+    );
+
+    // Feed the already-settled dissent into a fresh round
+    let (_, mut fresh_ids) = setup_weighted_reports(
+        &env,
+        &client,
+        &admin,
+        [(50, &honest), (50, &honest), (50, &honest)],
+    );
+    fresh_ids.push_back(dissent_id);
+    let agg_sig = create_sig(&env, &sk, &honest, 2);
+    assert!(client.aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &fresh_ids,
+        &honest,
+        &agg_sig,
+        &2,
+    ));
+
+    // The outlier is not penalized a second time
+    assert_eq!(
+        client
+            .get_oracle_node(&oracles.get(3).unwrap())
+            .unwrap()
+            .reputation,
+        5
+    );
+}
+
+#[test]
+fn test_aggregate_oracle_data_rejects_minority_consensus() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    // Three low-reputation oracles cannot outvote one high-reputation oracle
+    let majority = BytesN::from_array(&env, &[0xabu8; 32]);
+    let dissent = BytesN::from_array(&env, &[0xeeu8; 32]);
+    let (oracles, report_ids) = setup_weighted_reports(
+        &env,
+        &client,
+        &admin,
+        [
+            (90, &dissent),
+            (20, &majority),
+            (20, &majority),
+            (20, &majority),
+        ],
+    );
+
+    let agg_sig = create_sig(&env, &sk, &majority, 1);
+    let result = client.try_aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &report_ids,
+        &majority,
+        &agg_sig,
+        &1,
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientReputation)));
+    assert_eq!(
+        client
+            .get_oracle_node(&oracles.get(0).unwrap())
+            .unwrap()
+            .reputation,
+        90
+    );
+}
+
+#[test]
+fn test_aggregate_oracle_data_counts_only_agreeing_reports() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    // Three reports are supplied, but only two back the claimed hash
+    let claimed = BytesN::from_array(&env, &[0xabu8; 32]);
+    let dissent = BytesN::from_array(&env, &[0xeeu8; 32]);
+    let (_, report_ids) = setup_weighted_reports(
+        &env,
+        &client,
+        &admin,
+        [(80, &claimed), (80, &claimed), (10, &dissent)],
+    );
+
+    let agg_sig = create_sig(&env, &sk, &claimed, 1);
+    let result = client.try_aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &report_ids,
+        &claimed,
+        &agg_sig,
+        &1,
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientOracleReports)));
+    assert!(client.get_aggregated_oracle(&ChainId::Ethereum).is_none());
+    for report_id in report_ids.iter() {
+        assert_eq!(
+            client.get_oracle_report(&report_id).unwrap().status,
+            OracleStatus::Submitted
+        );
+    }
+}
+
+#[test]
+fn test_aggregate_oracle_data_below_reputation_threshold() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
     let (validator, sk) = setup_validator(&env, &client, &admin);
-
-    // Register 3 oracles and submit reports
-    let oracle1 = Address::generate(&env);
-    let oracle2 = Address::generate(&env);
-    let oracle3 = Address::generate(&env);
-    let chains = soroban_sdk::vec![&env, ChainId::Ethereum];
-    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
-
     env.mock_all_auths();
 
-    let mut report_ids = soroban_sdk::vec![&env];
-    for oracle in [&oracle1, &oracle2, &oracle3] {
-        client.register_oracle(&admin, oracle, &data_hash, &chains);
-        let rid = client.submit_oracle_report(
-            oracle,
-            &ChainId::Ethereum,
-            &data_hash,
-            &String::from_str(&env, "{}"),
-            &100,
-            &dummy_sig(&env),
-        );
-        report_ids.push_back(rid);
-    }
+    let data_hash = BytesN::from_array(&env, &[0xabu8; 32]);
+    let (_, report_ids) = setup_weighted_reports(
+        &env,
+        &client,
+        &admin,
+        [(30, &data_hash), (30, &data_hash), (30, &data_hash)],
+    );
 
-    let consensus_hash = BytesN::from_array(&env, &[0xccu8; 32]);
-    let agg_sig = create_sig(&env, &sk, &consensus_hash, 1);
-    let result = client.aggregate_oracle_data(
+    // Unanimous, but 90 < default minimum of 100
+    let agg_sig = create_sig(&env, &sk, &data_hash, 1);
+    let result = client.try_aggregate_oracle_data(
         &validator,
         &ChainId::Ethereum,
         &report_ids,
-        &consensus_hash,
+        &data_hash,
         &agg_sig,
         &1,
     );
-    assert!(result);
+    assert_eq!(result, Err(Ok(Error::InsufficientReputation)));
 
-    let aggregated = client.get_aggregated_oracle(&ChainId::Ethereum).unwrap();
-    assert!(aggregated.is_finalized);
-    assert_eq!(aggregated.report_count, 3);
-    assert_eq!(aggregated.consensus_hash, consensus_hash);
+    client.set_min_consensus_reputation(&admin, &90);
+    let agg_sig = create_sig(&env, &sk, &data_hash, 2);
+    assert!(client.aggregate_oracle_data(
+        &validator,
+        &ChainId::Ethereum,
+        &report_ids,
+        &data_hash,
+        &agg_sig,
+        &2,
+    ));
 }
 
 #[test]
@@ -752,6 +2339,53 @@ fn test_aggregate_oracle_insufficient_reports() {
 
 // ==================== Cryptographic Proof Tests ====================
 
+fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.append(&Bytes::from(left.clone()));
+    data.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&data).into()
+}
+
+/// Hand-built 4-leaf tree: returns (leaves, root).
+///
+///           root
+///         /      \
+///      h01        h23
+///     /   \      /   \
+///   l0    l1    l2    l3
+fn build_four_leaf_tree(env: &Env) -> (Vec<BytesN<32>>, BytesN<32>) {
+    let mut leaves = Vec::new(env);
+    for b in 1u8..=4 {
+        leaves.push_back(BytesN::from_array(env, &[b; 32]));
+    }
+    let h01 = hash_pair(env, &leaves.get(0).unwrap(), &leaves.get(1).unwrap());
+    let h23 = hash_pair(env, &leaves.get(2).unwrap(), &leaves.get(3).unwrap());
+    let root = hash_pair(env, &h01, &h23);
+    (leaves, root)
+}
+
+fn submit_tree_proof(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    validator: &Address,
+    sk: &SigningKey,
+    proof_id: &BytesN<32>,
+    record_hash: &BytesN<32>,
+    merkle_root: &BytesN<32>,
+) {
+    client.submit_proof(
+        validator,
+        proof_id,
+        &ChainId::Ethereum,
+        record_hash,
+        &BytesN::from_array(env, &[0x22u8; 32]),
+        merkle_root,
+        &String::from_str(env, "0x1234567890abcdef1234567890abcdef12345678"),
+        &create_sig(env, sk, proof_id, 1),
+        &1,
+    );
+}
+
 #[test]
 fn test_submit_proof() {
     let env = Env::default();
@@ -799,21 +2433,23 @@ fn test_verify_cross_chain_proof() {
     env.mock_all_auths();
 
     let proof_id = BytesN::from_array(&env, &[0xeeu8; 32]);
+    let (leaves, root) = build_four_leaf_tree(&env);
+    let leaf = leaves.get(0).unwrap();
+    submit_tree_proof(&env, &client, &validator1, &sk1, &proof_id, &leaf, &root);
+
+    // l0 is a left child at both levels: siblings are l1, then h23
+    let mut path = Vec::new(&env);
+    path.push_back(leaves.get(1).unwrap());
+    path.push_back(hash_pair(
+        &env,
+        &leaves.get(2).unwrap(),
+        &leaves.get(3).unwrap(),
+    ));
+    let mut indices = Vec::new(&env);
+    indices.push_back(false);
+    indices.push_back(false);
+    assert!(!client.verify_merkle_inclusion(&proof_id, &leaf, &path, &indices));
 
-    let proof_sig1 = create_sig(&env, &sk1, &proof_id, 1);
-    client.submit_proof(
-        &validator1,
-        &proof_id,
-        &ChainId::Ethereum,
-        &BytesN::from_array(&env, &[1u8; 32]),
-        &BytesN::from_array(&env, &[2u8; 32]),
-        &BytesN::from_array(&env, &[3u8; 32]),
-        &String::from_str(&env, "0x1234567890abcdef1234567890abcdef12345678"),
-        &proof_sig1,
-        &1,
-    );
-
-    // First verification — not yet verified (needs min_confirmations = 2)
     let verify_sig = create_sig(&env, &sk2, &proof_id, 1);
     let verified = client.verify_cross_chain_proof(&validator2, &verify_sig, &1, &proof_id);
     assert!(verified); // 1 (submit) + 1 = 2 => matches min_confirmations
@@ -844,6 +2480,190 @@ fn test_proof_not_found() {
     assert_eq!(result, Err(Ok(Error::ProofNotFound)));
 }
 
+#[test]
+fn test_verify_merkle_inclusion_right_leaf_after_attestations() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    let proof_id = BytesN::from_array(&env, &[0xe1u8; 32]);
+    let (leaves, root) = build_four_leaf_tree(&env);
+    let leaf = leaves.get(2).unwrap();
+    submit_tree_proof(&env, &client, &validator1, &sk1, &proof_id, &leaf, &root);
+
+    // Attestations alone do not verify the proof
+    let verify_sig = create_sig(&env, &sk2, &proof_id, 1);
+    assert!(!client.verify_cross_chain_proof(&validator2, &verify_sig, &1, &proof_id));
+    let proof = client.get_proof(&proof_id).unwrap();
+    assert!(!proof.verified);
+    assert_eq!(proof.verifier_count, 2);
+
+    // l2 is a left child of h23, which is the right child of root
+    let mut path = Vec::new(&env);
+    path.push_back(leaves.get(3).unwrap());
+    path.push_back(hash_pair(
+        &env,
+        &leaves.get(0).unwrap(),
+        &leaves.get(1).unwrap(),
+    ));
+    let mut indices = Vec::new(&env);
+    indices.push_back(false);
+    indices.push_back(true);
+    assert!(client.verify_merkle_inclusion(&proof_id, &leaf, &path, &indices));
+
+    let proof = client.get_proof(&proof_id).unwrap();
+    assert!(proof.inclusion_proven);
+    assert!(proof.verified);
+}
+
+#[test]
+fn test_verify_merkle_inclusion_rejects_bad_path() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    let proof_id = BytesN::from_array(&env, &[0xe2u8; 32]);
+    let (leaves, root) = build_four_leaf_tree(&env);
+    let leaf = leaves.get(1).unwrap();
+    submit_tree_proof(&env, &client, &validator, &sk, &proof_id, &leaf, &root);
+
+    let h23 = hash_pair(&env, &leaves.get(2).unwrap(), &leaves.get(3).unwrap());
+    let mut path = Vec::new(&env);
+    path.push_back(leaves.get(0).unwrap());
+    path.push_back(h23);
+
+    // Wrong direction at the first level
+    let mut wrong_indices = Vec::new(&env);
+    wrong_indices.push_back(false);
+    wrong_indices.push_back(false);
+    let result = client.try_verify_merkle_inclusion(&proof_id, &leaf, &path, &wrong_indices);
+    assert_eq!(result, Err(Ok(Error::InvalidMerkleProof)));
+
+    // Leaf that does not match the proof's record hash
+    let mut indices = Vec::new(&env);
+    indices.push_back(true);
+    indices.push_back(false);
+    let other_leaf = leaves.get(0).unwrap();
+    let result = client.try_verify_merkle_inclusion(&proof_id, &other_leaf, &path, &indices);
+    assert_eq!(result, Err(Ok(Error::InvalidMerkleProof)));
+
+    // Mismatched path / indices lengths
+    let mut short_indices = Vec::new(&env);
+    short_indices.push_back(true);
+    let result = client.try_verify_merkle_inclusion(&proof_id, &leaf, &path, &short_indices);
+    assert_eq!(result, Err(Ok(Error::InvalidMerkleProof)));
+
+    assert!(!client.get_proof(&proof_id).unwrap().inclusion_proven);
+
+    // Correct path succeeds
+    client.verify_merkle_inclusion(&proof_id, &leaf, &path, &indices);
+    assert!(client.get_proof(&proof_id).unwrap().inclusion_proven);
+}
+
+/// Submit and fully verify a proof over leaf 0 of the four-leaf tree,
+/// returning its ID and a second active validator able to challenge it.
+fn verified_tree_proof(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    admin: &Address,
+) -> (BytesN<32>, Address) {
+    let (validator1, sk1) = setup_validator(env, client, admin);
+    let (validator2, sk2) = setup_validator(env, client, admin);
+    let (challenger, _) = setup_validator(env, client, admin);
+
+    let proof_id = BytesN::from_array(env, &[0xc7u8; 32]);
+    let (leaves, root) = build_four_leaf_tree(env);
+    let leaf = leaves.get(0).unwrap();
+    submit_tree_proof(env, client, &validator1, &sk1, &proof_id, &leaf, &root);
+
+    let path = soroban_sdk::vec![
+        env,
+        leaves.get(1).unwrap(),
+        hash_pair(env, &leaves.get(2).unwrap(), &leaves.get(3).unwrap()),
+    ];
+    client.verify_merkle_inclusion(&proof_id, &leaf, &path, &soroban_sdk::vec![env, false, false]);
+    let verify_sig = create_sig(env, &sk2, &proof_id, 1);
+    assert!(client.verify_cross_chain_proof(&validator2, &verify_sig, &1, &proof_id));
+    (proof_id, challenger)
+}
+
+#[test]
+fn test_challenge_proof_within_window() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    client.set_challenge_window(&admin, &600);
+    let (proof_id, challenger) = verified_tree_proof(&env, &client, &admin);
+    assert!(!client.is_proof_usable(&proof_id));
+
+    env.ledger().with_mut(|li| li.timestamp += 300);
+    let evidence = BytesN::from_array(&env, &[0x5au8; 32]);
+    client.challenge_proof(&challenger, &proof_id, &evidence);
+    assert!(client.get_proof(&proof_id).unwrap().disputed);
+    assert_eq!(
+        client
+            .get_proof_challenge(&proof_id)
+            .unwrap()
+            .counter_evidence_hash,
+        evidence
+    );
+    assert_eq!(
+        client.try_challenge_proof(&challenger, &proof_id, &evidence),
+        Err(Ok(Error::ProofAlreadyChallenged))
+    );
+
+    // Still blocked after the window would have closed
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    assert!(!client.is_proof_usable(&proof_id));
+
+    client.resolve_proof_dispute(&admin, &proof_id, &false);
+    let proof = client.get_proof(&proof_id).unwrap();
+    assert!(!proof.verified);
+    assert!(proof.disputed);
+    assert!(!client.is_proof_usable(&proof_id));
+    assert_eq!(
+        client.try_challenge_proof(&challenger, &proof_id, &evidence),
+        Err(Ok(Error::ProofNotVerified))
+    );
+}
+
+#[test]
+fn test_unchallenged_proof_usable_after_window() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    env.mock_all_auths();
+
+    client.set_challenge_window(&admin, &600);
+    let (proof_id, challenger) = verified_tree_proof(&env, &client, &admin);
+
+    env.ledger().with_mut(|li| li.timestamp += 599);
+    assert!(!client.is_proof_usable(&proof_id));
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert!(client.is_proof_usable(&proof_id));
+    let evidence = BytesN::from_array(&env, &[0x5au8; 32]);
+    assert_eq!(
+        client.try_challenge_proof(&challenger, &proof_id, &evidence),
+        Err(Ok(Error::ChallengeWindowClosed))
+    );
+    assert_eq!(
+        client.try_resolve_proof_dispute(&admin, &proof_id, &true),
+        Err(Ok(Error::ProofNotDisputed))
+    );
+}
+
 // ==================== Address Validation Tests ====================
 
 #[test]
@@ -887,6 +2707,85 @@ fn test_validate_polygon_address() {
     assert!(client.validate_chain_address(&ChainId::Optimism, &valid));
 }
 
+#[test]
+fn test_validate_evm_address_detailed() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let check = |addr: &str| {
+        client.validate_chain_address_detailed(&ChainId::Ethereum, &String::from_str(&env, addr))
+    };
+
+    assert_eq!(
+        check("0xABCDEF1234567890abcdef1234567890ABCDEF12"),
+        AddressValidation::Valid
+    );
+    assert_eq!(check("0x1234"), AddressValidation::BadLength);
+    assert_eq!(
+        check("1x1234567890abcdef1234567890abcdef12345678"),
+        AddressValidation::BadPrefix
+    );
+    assert_eq!(
+        check("0xzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz"),
+        AddressValidation::BadCharset
+    );
+
+    // The boolean wrapper rejects non-hex bodies too
+    let non_hex = String::from_str(&env, "0xg234567890abcdef1234567890abcdef12345678");
+    assert!(!client.validate_chain_address(&ChainId::Ethereum, &non_hex));
+}
+
+#[test]
+fn test_validate_stellar_address_detailed() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let check = |addr: &str| {
+        client.validate_chain_address_detailed(&ChainId::Stellar, &String::from_str(&env, addr))
+    };
+
+    assert_eq!(
+        check("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWNA"),
+        AddressValidation::Valid
+    );
+    assert_eq!(
+        check("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5"),
+        AddressValidation::BadLength
+    );
+    assert_eq!(
+        check("SAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWNA"),
+        AddressValidation::BadPrefix
+    );
+    // '0', '1', '8', '9' and lowercase are outside the base32 alphabet
+    assert_eq!(
+        check("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCW01"),
+        AddressValidation::BadCharset
+    );
+    assert_eq!(
+        check("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCwna"),
+        AddressValidation::BadCharset
+    );
+}
+
+#[test]
+fn test_validate_custom_chain_address_detailed() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let chain = ChainId::Custom(7);
+    assert_eq!(
+        client.validate_chain_address_detailed(&chain, &String::from_str(&env, "anything")),
+        AddressValidation::Valid
+    );
+    assert_eq!(
+        client.validate_chain_address_detailed(&chain, &String::from_str(&env, "")),
+        AddressValidation::BadLength
+    );
+}
+
 #[test]
 fn test_get_chain_address_length() {
     let env = Env::default();
@@ -932,6 +2831,97 @@ fn test_sync_cross_chain_event() {
     assert_eq!(event.source_chain, ChainId::Ethereum);
 }
 
+fn sync_event_at(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    validator: &Address,
+    sk: &SigningKey,
+    id_byte: u8,
+    block_height: u64,
+    nonce: u64,
+) -> Result<u64, InvokeError> {
+    let payload_hash = BytesN::from_array(env, &[id_byte; 32]);
+    let sig = create_sig(env, sk, &payload_hash, nonce);
+    match client.try_sync_cross_chain_event(
+        validator,
+        &ChainId::Ethereum,
+        &ChainId::Stellar,
+        &CrossChainEventType::RecordCreated,
+        &payload_hash,
+        &block_height,
+        &sig,
+        &nonce,
+    ) {
+        Ok(Ok(event_id)) => Ok(event_id),
+        Err(Ok(err)) => Err(err.into()),
+        Err(Err(err)) => Err(err),
+        _ => panic!("unexpected sync result"),
+    }
+}
+
+#[test]
+fn test_sync_events_in_order_accepted() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.get_last_synced_height(&ChainId::Ethereum, &ChainId::Stellar),
+        None
+    );
+    assert_eq!(
+        sync_event_at(&env, &client, &validator, &sk, 1, 100, 1),
+        Ok(1)
+    );
+    // Several events from the same block share a height.
+    assert_eq!(
+        sync_event_at(&env, &client, &validator, &sk, 2, 100, 2),
+        Ok(2)
+    );
+    assert_eq!(
+        sync_event_at(&env, &client, &validator, &sk, 3, 150, 3),
+        Ok(3)
+    );
+    assert_eq!(
+        client.get_last_synced_height(&ChainId::Ethereum, &ChainId::Stellar),
+        Some(150)
+    );
+    // Other chain pairs keep their own watermark.
+    assert_eq!(
+        client.get_last_synced_height(&ChainId::Stellar, &ChainId::Ethereum),
+        None
+    );
+}
+
+#[test]
+fn test_sync_event_out_of_order_rejected() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    env.mock_all_auths();
+
+    sync_event_at(&env, &client, &validator, &sk, 1, 200, 1).unwrap();
+    assert_eq!(
+        sync_event_at(&env, &client, &validator, &sk, 2, 199, 2),
+        Err(Error::OutOfOrderEvent.into())
+    );
+    assert_eq!(client.get_event_count(), 1);
+
+    client.set_allow_reordering(&admin, &true);
+    assert_eq!(
+        sync_event_at(&env, &client, &validator, &sk, 2, 199, 2),
+        Ok(2)
+    );
+    // A late event does not lower the watermark.
+    assert_eq!(
+        client.get_last_synced_height(&ChainId::Ethereum, &ChainId::Stellar),
+        Some(200)
+    );
+}
+
 #[test]
 fn test_process_sync_event() {
     let env = Env::default();
@@ -1057,24 +3047,29 @@ fn test_execute_rollback_for_message() {
 
     env.mock_all_auths();
 
-    // Submit a message
-    let message_id = generate_message_id(&env);
-    let v_sig = create_sig(&env, &sk, &message_id, 1);
-    client.submit_message(
-        &validator,
-        &SubmitMessageRequest {
-            message_id: message_id.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0x1234"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig,
-            v_nonce: 1,
-        },
+    // Submit a message
+    let message_id = generate_message_id(&env);
+    let v_sig = create_sig(&env, &sk, &message_id, 1);
+    client.submit_message(
+        &validator,
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0x1234"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     // Initiate and execute rollback on that message
@@ -1128,6 +3123,153 @@ fn test_execute_rollback_for_atomic_tx() {
     assert_eq!(atomic_tx.status, AtomicTxStatus::Aborted);
 }
 
+#[test]
+fn test_execute_rollback_for_atomic_tx_fails_messages() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    let verified_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xa1, 1);
+    let pending_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xb2, 2);
+    client.confirm_message(
+        &validator1,
+        &verified_id,
+        &create_sig(&env, &sk1, &verified_id, 3),
+        &3,
+    );
+    client.confirm_message(
+        &validator2,
+        &verified_id,
+        &create_sig(&env, &sk2, &verified_id, 1),
+        &1,
+    );
+    assert_eq!(
+        client.get_message(&verified_id).unwrap().status,
+        MessageStatus::Verified
+    );
+    assert_eq!(
+        client
+            .get_validator(&validator1)
+            .unwrap()
+            .confirmed_messages,
+        1
+    );
+
+    let tx_id = BytesN::from_array(&env, &[0x8au8; 32]);
+    client.initiate_atomic_tx(
+        &validator1,
+        &tx_id,
+        &soroban_sdk::vec![&env, verified_id.clone(), pending_id.clone()],
+    );
+    client.initiate_rollback(
+        &admin,
+        &tx_id,
+        &RollbackOpType::AtomicTxRollback,
+        &String::from_str(&env, "{}"),
+        &String::from_str(&env, "Partial delivery"),
+    );
+    client.execute_rollback(&admin, &tx_id);
+
+    assert_eq!(
+        client.get_message(&verified_id).unwrap().status,
+        MessageStatus::Failed
+    );
+    assert_eq!(
+        client.get_message(&pending_id).unwrap().status,
+        MessageStatus::Failed
+    );
+
+    // Confirmation credit is refunded
+    assert_eq!(
+        client
+            .get_validator(&validator1)
+            .unwrap()
+            .confirmed_messages,
+        0
+    );
+    assert_eq!(
+        client
+            .get_validator(&validator2)
+            .unwrap()
+            .confirmed_messages,
+        0
+    );
+
+    // Nothing was executed, so there is nothing to reconcile
+    let rollback = client.get_rollback(&tx_id).unwrap();
+    assert_eq!(rollback.original_state, String::from_str(&env, "{}"));
+    assert!(rollback.executed_messages.is_empty());
+}
+
+#[test]
+fn test_execute_rollback_for_atomic_tx_records_executed_messages() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator1, sk1) = setup_validator(&env, &client, &admin);
+    let (validator2, sk2) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+
+    let executed_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xa1, 1);
+    let pending_id = submit_indexed_message(&env, &client, &validator1, &sk1, 0xb2, 2);
+    client.confirm_message(
+        &validator1,
+        &executed_id,
+        &create_sig(&env, &sk1, &executed_id, 3),
+        &3,
+    );
+    client.confirm_message(
+        &validator2,
+        &executed_id,
+        &create_sig(&env, &sk2, &executed_id, 1),
+        &1,
+    );
+    client.execute_message(&validator1, &executed_id);
+
+    let tx_id = BytesN::from_array(&env, &[0x8bu8; 32]);
+    client.initiate_atomic_tx(
+        &validator1,
+        &tx_id,
+        &soroban_sdk::vec![&env, executed_id.clone(), pending_id.clone()],
+    );
+    client.initiate_rollback(
+        &admin,
+        &tx_id,
+        &RollbackOpType::AtomicTxRollback,
+        &String::from_str(&env, "not json: \"step 1\""),
+        &String::from_str(&env, "Partial delivery"),
+    );
+    client.execute_rollback(&admin, &tx_id);
+
+    // Executed messages are left as-is and recorded for reconciliation
+    assert_eq!(
+        client.get_message(&executed_id).unwrap().status,
+        MessageStatus::Executed
+    );
+    assert_eq!(
+        client.get_message(&pending_id).unwrap().status,
+        MessageStatus::Failed
+    );
+
+    // The operator snapshot is stored verbatim, whatever its shape
+    let rollback = client.get_rollback(&tx_id).unwrap();
+    assert_eq!(
+        rollback.original_state,
+        String::from_str(&env, "not json: \"step 1\"")
+    );
+    assert_eq!(
+        rollback.executed_messages,
+        soroban_sdk::vec![&env, executed_id]
+    );
+}
+
 #[test]
 fn test_cancel_rollback() {
     let env = Env::default();
@@ -1175,6 +3317,103 @@ fn test_rollback_already_processed_fails() {
     assert_eq!(result, Err(Ok(Error::RollbackAlreadyProcessed)));
 }
 
+// ==================== Storage TTL Tests ====================
+
+fn message_ttl(env: &Env, client: &CrossChainBridgeContractClient, message_id: &BytesN<32>) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Message(message_id.clone()))
+    })
+}
+
+/// Advance the ledger while keeping the contract instance itself alive, so
+/// only the persistent entries under test can expire.
+fn advance_ledgers(env: &Env, client: &CrossChainBridgeContractClient, ledgers: u32) {
+    env.as_contract(&client.address, || {
+        env.storage().instance().extend_ttl(ledgers, ledgers * 2);
+    });
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_accessed_message_survives_past_original_expiry() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.set_ttl_bump_amount(&admin, &20_000);
+    let request = signed_test_request(&env, &sk, &recipient, "{\"record_id\": 1}");
+    let message_id = client.submit_message(&validator, &request);
+
+    let original_ttl = message_ttl(&env, &client, &message_id);
+    advance_ledgers(&env, &client, original_ttl - 50);
+    assert!(client.get_message(&message_id).is_some());
+    assert!(message_ttl(&env, &client, &message_id) >= 20_000);
+
+    advance_ledgers(&env, &client, 100);
+    assert_eq!(
+        client.get_message(&message_id).unwrap().status,
+        MessageStatus::Pending
+    );
+}
+
+#[test]
+fn test_extend_message_ttl() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+    let request = signed_test_request(&env, &sk, &recipient, "{\"record_id\": 1}");
+    let message_id = client.submit_message(&validator, &request);
+
+    assert!(client.extend_message_ttl(&validator, &message_id));
+    assert_eq!(
+        message_ttl(&env, &client, &message_id),
+        client.get_ttl_bump_amount()
+    );
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_extend_message_ttl(&outsider, &message_id),
+        Err(Ok(Error::ValidatorNotFound))
+    );
+    assert_eq!(
+        client.try_extend_message_ttl(&admin, &BytesN::from_array(&env, &[9u8; 32])),
+        Err(Ok(Error::MessageNotFound))
+    );
+}
+
+#[test]
+fn test_set_ttl_bump_amount_validation() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    env.mock_all_auths();
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_ttl_bump_amount(&outsider, &20_000),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_ttl_bump_amount(&admin, &100),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_ttl_bump_amount(&admin, &u32::MAX),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert!(client.set_ttl_bump_amount(&admin, &20_000));
+    assert_eq!(client.get_ttl_bump_amount(), 20_000);
+}
+
 // ==================== Pause Tests ====================
 
 #[test]
@@ -1195,92 +3434,250 @@ fn test_pause_unpause() {
 }
 
 #[test]
-fn test_operations_blocked_when_paused() {
+fn test_operations_blocked_when_paused() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, _sk) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+    client.pause(&admin);
+
+    let result = client.try_submit_message(
+        &validator,
+        &SubmitMessageRequest {
+            message_id: generate_message_id(&env),
+            source_chain: ChainId::Ethereum,
+            dest_chain: ChainId::Stellar,
+            sender: String::from_str(&env, "0x1234567890abcdef"),
+            recipient: Address::generate(&env),
+            payload_type: MessageType::RecordRequest,
+            payload: String::from_str(&env, "{\"record_id\": 1}"),
+            nonce: 1,
+            signature: dummy_sig(&env),
+            v_signature: dummy_sig(&env),
+            v_nonce: 1,
+            fee: 0,
+        },
+    );
+
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+}
+
+// ==================== Nonce Tests ====================
+
+#[test]
+fn test_nonce_replay_protection() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+    let recipient = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let sender = String::from_str(&env, "0x1234567890abcdef");
+
+    let msg_id_1 = BytesN::from_array(&env, &[1u8; 32]);
+    let v_sig_1 = create_sig(&env, &sk, &msg_id_1, 1);
+    client.submit_message(
+        &validator,
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: msg_id_1,
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_1,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
+    );
+
+    // Same nonce should fail (nonce 1 already used for this validator's pubkey)
+    let msg_id_4 = BytesN::from_array(&env, &[4u8; 32]);
+    let v_sig_2 = create_sig(&env, &sk, &msg_id_4, 2); // new nonce for second call
+    let result = client.try_submit_message(
+        &validator,
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: msg_id_4,
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{}"),
+                nonce: 1, // <-- sender nonce, not validator nonce
+                signature: dummy_sig(&env),
+                v_signature: v_sig_2, // validator nonce is 2, which IS valid
+                v_nonce: 2,
+                fee: 0,
+            },
+        ),
+    );
+
+    // This test checks sender nonce replay, not validator nonce
+    assert_eq!(result, Err(Ok(Error::InvalidNonce)));
+}
+
+fn try_submit_with_sender_nonce(
+    env: &Env,
+    client: &CrossChainBridgeContractClient,
+    validator: &Address,
+    sk: &SigningKey,
+    nonce: u64,
+    v_nonce: u64,
+) -> Result<(), Error> {
+    let message_id = BytesN::from_array(env, &[nonce as u8; 32]);
+    let request = sign_request(
+        env,
+        sk,
+        SubmitMessageRequest {
+            message_id: message_id.clone(),
+            source_chain: ChainId::Ethereum,
+            dest_chain: ChainId::Stellar,
+            sender: String::from_str(env, "0x1234567890abcdef"),
+            recipient: Address::generate(env),
+            payload_type: MessageType::RecordRequest,
+            payload: String::from_str(env, "{}"),
+            nonce,
+            signature: dummy_sig(env),
+            v_signature: create_sig(env, sk, &message_id, v_nonce),
+            v_nonce,
+            fee: 0,
+        },
+    );
+    match client.try_submit_message(validator, &request) {
+        Ok(_) => Ok(()),
+        Err(Ok(e)) => Err(e),
+        Err(Err(_)) => panic!("unexpected host error"),
+    }
+}
+
+#[test]
+fn test_nonce_window_accepts_in_order_and_reordered() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+    client.set_nonce_window(&admin, &8);
+
+    // In order
+    for (i, nonce) in [1u64, 2, 3].into_iter().enumerate() {
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, nonce, i as u64 + 1).unwrap();
+    }
+
+    // Small reorder: 6 arrives before 4 and 5
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 6, 4).unwrap();
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 4, 5).unwrap();
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 5, 6).unwrap();
+}
+
+#[test]
+fn test_nonce_window_rejects_replay_old_and_far_ahead() {
+    let env = Env::default();
+    let (client, admin, medical, identity, access) = create_contract(&env);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access);
+
+    let (validator, sk) = setup_validator(&env, &client, &admin);
+
+    env.mock_all_auths();
+    client.set_nonce_window(&admin, &4);
+
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 10, 1).unwrap();
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 8, 2).unwrap();
+
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 8, 3),
+        Err(Error::NonceReplayed)
+    );
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 10, 4),
+        Err(Error::NonceReplayed)
+    );
+    // 10 - 6 = 4 falls outside a window of 4
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 6, 5),
+        Err(Error::NonceTooOld)
+    );
+    // More than `window` ahead of the high watermark
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 15, 6),
+        Err(Error::InvalidNonce)
+    );
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 14, 7).unwrap();
+}
+
+#[test]
+fn test_nonce_window_new_sender_starts_at_first_nonce() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
-    let (validator, _sk) = setup_validator(&env, &client, &admin);
+    let (validator, sk) = setup_validator(&env, &client, &admin);
 
     env.mock_all_auths();
-    client.pause(&admin);
+    client.set_nonce_window(&admin, &4);
 
-    let result = client.try_submit_message(
-        &validator,
-        &SubmitMessageRequest {
-            message_id: generate_message_id(&env),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0x1234567890abcdef"),
-            recipient: Address::generate(&env),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\": 1}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: dummy_sig(&env),
-            v_nonce: 1,
-        },
+    // Only the first nonce is consumed; the rest of the window stays open
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 20, 1).unwrap();
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 17, 2).unwrap();
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 19, 3).unwrap();
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 16, 4),
+        Err(Error::NonceTooOld)
+    );
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 0, 5),
+        Err(Error::NonceTooOld)
     );
-
-    assert_eq!(result, Err(Ok(Error::ContractPaused)));
 }
 
-// ==================== Nonce Tests ====================
-
 #[test]
-fn test_nonce_replay_protection() {
+fn test_nonce_window_enabled_after_strict_mode() {
     let env = Env::default();
     let (client, admin, medical, identity, access) = create_contract(&env);
     initialize_contract(&env, &client, &admin, &medical, &identity, &access);
 
     let (validator, sk) = setup_validator(&env, &client, &admin);
-    let recipient = Address::generate(&env);
 
     env.mock_all_auths();
 
-    let sender = String::from_str(&env, "0x1234567890abcdef");
-
-    let msg_id_1 = BytesN::from_array(&env, &[1u8; 32]);
-    let v_sig_1 = create_sig(&env, &sk, &msg_id_1, 1);
-    client.submit_message(
-        &validator,
-        &SubmitMessageRequest {
-            message_id: msg_id_1,
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: sender.clone(),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_1,
-            v_nonce: 1,
-        },
+    // Strict mode by default: skipped nonces cannot be filled in later
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 5, 1).unwrap();
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 3, 2),
+        Err(Error::InvalidNonce)
     );
 
-    // Same nonce should fail (nonce 1 already used for this validator's pubkey)
-    let msg_id_4 = BytesN::from_array(&env, &[4u8; 32]);
-    let v_sig_2 = create_sig(&env, &sk, &msg_id_4, 2); // new nonce for second call
-    let result = client.try_submit_message(
-        &validator,
-        &SubmitMessageRequest {
-            message_id: msg_id_4,
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: sender.clone(),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{}"),
-            nonce: 1, // <-- sender nonce, not validator nonce
-            signature: dummy_sig(&env),
-            v_signature: v_sig_2, // validator nonce is 2, which IS valid
-            v_nonce: 2,
-        },
+    // Nonces at or below the strict watermark stay consumed once the window is on
+    assert_eq!(
+        client.try_set_nonce_window(&admin, &65),
+        Err(Ok(Error::InvalidAmount))
     );
-
-    // This test checks sender nonce replay, not validator nonce
-    assert_eq!(result, Err(Ok(Error::InvalidNonce)));
+    client.set_nonce_window(&admin, &8);
+    assert_eq!(
+        try_submit_with_sender_nonce(&env, &client, &validator, &sk, 3, 3),
+        Err(Error::NonceReplayed)
+    );
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 7, 4).unwrap();
+    try_submit_with_sender_nonce(&env, &client, &validator, &sk, 6, 5).unwrap();
 }
 
 // ==================== Chaos / Resilience Tests ====================
@@ -1301,19 +3698,24 @@ fn test_chaos_lost_acknowledgment_retry() {
     let v_sig = create_sig(&env, &sk, &message_id, 1);
     client.submit_message(
         &validator,
-        &SubmitMessageRequest {
-            message_id: message_id.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xSENDER"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\":42}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: message_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xSENDER"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\":42}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     let msg = client.get_message(&message_id).unwrap();
@@ -1354,36 +3756,46 @@ fn test_chaos_partial_delivery_atomicity() {
     let v_sig_a = create_sig(&env, &sk, &msg_id_a, 1);
     client.submit_message(
         &validator,
-        &SubmitMessageRequest {
-            message_id: msg_id_a.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xA"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"data\":\"a\"}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_a,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: msg_id_a.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xA"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"data\":\"a\"}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_a,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
     let v_sig_b = create_sig(&env, &sk, &msg_id_b, 2);
     client.submit_message(
         &validator,
-        &SubmitMessageRequest {
-            message_id: msg_id_b.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xB"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"data\":\"b\"}"),
-            nonce: 2,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_b,
-            v_nonce: 2,
-        },
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: msg_id_b.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xB"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"data\":\"b\"}"),
+                nonce: 2,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_b,
+                v_nonce: 2,
+                fee: 0,
+            },
+        ),
     );
 
     let message_ids = soroban_sdk::vec![&env, msg_id_a.clone(), msg_id_b.clone()];
@@ -1430,19 +3842,24 @@ fn test_chaos_relayer_offline_recovery() {
         let v_sig = create_sig(&env, &sk, &msg_id, nonce);
         client.submit_message(
             &validator,
-            &SubmitMessageRequest {
-                message_id: msg_id.clone(),
-                source_chain: ChainId::Ethereum,
-                dest_chain: ChainId::Stellar,
-                sender: String::from_str(&env, "0xRELAY"),
-                recipient: recipient.clone(),
-                payload_type: MessageType::RecordSync,
-                payload: String::from_str(&env, "{\"seq\":"),
-                nonce,
-                signature: dummy_sig(&env),
-                v_signature: v_sig,
-                v_nonce: nonce,
-            },
+            &sign_request(
+                &env,
+                &sk,
+                SubmitMessageRequest {
+                    message_id: msg_id.clone(),
+                    source_chain: ChainId::Ethereum,
+                    dest_chain: ChainId::Stellar,
+                    sender: String::from_str(&env, "0xRELAY"),
+                    recipient: recipient.clone(),
+                    payload_type: MessageType::RecordSync,
+                    payload: String::from_str(&env, "{\"seq\":"),
+                    nonce,
+                    signature: dummy_sig(&env),
+                    v_signature: v_sig,
+                    v_nonce: nonce,
+                    fee: 0,
+                },
+            ),
         );
         ids.push_back(msg_id);
     }
@@ -1492,6 +3909,9 @@ fn test_error_codes_are_stable() {
     assert_eq!(Error::Unauthorized as u32, 100);
     assert_eq!(Error::InsufficientConfirmations as u32, 120);
     assert_eq!(Error::InvalidSignature as u32, 207);
+    assert_eq!(Error::InvalidAmount as u32, 284);
+    assert_eq!(Error::NonceReplayed as u32, 285);
+    assert_eq!(Error::NonceTooOld as u32, 286);
     assert_eq!(Error::AlreadyInitialized as u32, 301);
     assert_eq!(Error::ContractPaused as u32, 302);
     assert_eq!(Error::MessageNotFound as u32, 480);
@@ -1539,19 +3959,24 @@ fn test_chaos_acknowledgment_lost_idempotent_retry() {
     let v_sig = create_sig(&env, &sk1, &msg_id, 1);
     client.submit_message(
         &validator1,
-        &SubmitMessageRequest {
-            message_id: msg_id.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0x1234"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\": 1}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk1,
+            SubmitMessageRequest {
+                message_id: msg_id.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0x1234"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 1}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     // First confirmation (acknowledgment is "lost" - not processed further)
@@ -1589,19 +4014,24 @@ fn test_chaos_partial_message_delivery_atomicity() {
     let v_sig_a = create_sig(&env, &sk, &msg_id_a, 1);
     client.submit_message(
         &validator,
-        &SubmitMessageRequest {
-            message_id: msg_id_a.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xAAA"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\": 1}"),
-            nonce: 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_a,
-            v_nonce: 1,
-        },
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: msg_id_a.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xAAA"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 1}"),
+                nonce: 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_a,
+                v_nonce: 1,
+                fee: 0,
+            },
+        ),
     );
 
     // Message B sent at nonce 2 (nonce prevents replay on A)
@@ -1609,19 +4039,24 @@ fn test_chaos_partial_message_delivery_atomicity() {
     let v_sig_b = create_sig(&env, &sk, &msg_id_b, 2);
     client.submit_message(
         &validator,
-        &SubmitMessageRequest {
-            message_id: msg_id_b.clone(),
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: String::from_str(&env, "0xAAA"),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{\"record_id\": 2}"),
-            nonce: 2,
-            signature: dummy_sig(&env),
-            v_signature: v_sig_b,
-            v_nonce: 2,
-        },
+        &sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: msg_id_b.clone(),
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: String::from_str(&env, "0xAAA"),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{\"record_id\": 2}"),
+                nonce: 2,
+                signature: dummy_sig(&env),
+                v_signature: v_sig_b,
+                v_nonce: 2,
+                fee: 0,
+            },
+        ),
     );
 
     // Only message B is confirmed by both validators
@@ -1674,19 +4109,24 @@ fn test_chaos_relayer_offline_mid_batch_recovery() {
         let v_sig = create_sig(&env, &sk1, &msg_id, nonce);
         client.submit_message(
             &validator1,
-            &SubmitMessageRequest {
-                message_id: msg_id.clone(),
-                source_chain: ChainId::Ethereum,
-                dest_chain: ChainId::Stellar,
-                sender: String::from_str(&env, "0xBatch"),
-                recipient: recipient.clone(),
-                payload_type: MessageType::RecordSync,
-                payload: String::from_str(&env, "{\"batch\": true}"),
-                nonce,
-                signature: dummy_sig(&env),
-                v_signature: v_sig,
-                v_nonce: nonce,
-            },
+            &sign_request(
+                &env,
+                &sk1,
+                SubmitMessageRequest {
+                    message_id: msg_id.clone(),
+                    source_chain: ChainId::Ethereum,
+                    dest_chain: ChainId::Stellar,
+                    sender: String::from_str(&env, "0xBatch"),
+                    recipient: recipient.clone(),
+                    payload_type: MessageType::RecordSync,
+                    payload: String::from_str(&env, "{\"batch\": true}"),
+                    nonce,
+                    signature: dummy_sig(&env),
+                    v_signature: v_sig,
+                    v_nonce: nonce,
+                    fee: 0,
+                },
+            ),
         );
     }
 
@@ -1738,6 +4178,7 @@ fn test_chaos_oracle_offline_mid_consensus() {
         &data_hash,
         &String::from_str(&env, "{}"),
         &100,
+        &env.ledger().timestamp(),
         &dummy_sig(&env),
     );
     client.submit_oracle_report(
@@ -1746,6 +4187,7 @@ fn test_chaos_oracle_offline_mid_consensus() {
         &data_hash,
         &String::from_str(&env, "{}"),
         &100,
+        &env.ledger().timestamp(),
         &dummy_sig(&env),
     );
 
@@ -1791,19 +4233,24 @@ fn test_submit_message_batch_single() {
 
     env.mock_all_auths();
     let v_sig = create_sig(&env, &sk, &message_id, 1);
-    let request = SubmitMessageRequest {
-        message_id: message_id.clone(),
-        source_chain: ChainId::Ethereum,
-        dest_chain: ChainId::Stellar,
-        sender: sender.clone(),
-        recipient: recipient.clone(),
-        payload_type: MessageType::RecordRequest,
-        payload: payload.clone(),
-        nonce: 1,
-        signature: dummy_sig(&env),
-        v_signature: v_sig,
-        v_nonce: 1,
-    };
+    let request = sign_request(
+        &env,
+        &sk,
+        SubmitMessageRequest {
+            message_id: message_id.clone(),
+            source_chain: ChainId::Ethereum,
+            dest_chain: ChainId::Stellar,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            payload_type: MessageType::RecordRequest,
+            payload: payload.clone(),
+            nonce: 1,
+            signature: dummy_sig(&env),
+            v_signature: v_sig,
+            v_nonce: 1,
+            fee: 0,
+        },
+    );
 
     let ids = client.submit_message_batch(&validator, &soroban_sdk::vec![&env, request]);
     assert_eq!(ids.len(), 1);
@@ -1830,32 +4277,42 @@ fn test_submit_message_batch_multiple() {
     let v_sig1 = create_sig(&env, &sk, &id1, 1);
     let v_sig2 = create_sig(&env, &sk, &id2, 2);
 
-    let req1 = SubmitMessageRequest {
-        message_id: id1.clone(),
-        source_chain: ChainId::Ethereum,
-        dest_chain: ChainId::Stellar,
-        sender: sender.clone(),
-        recipient: recipient.clone(),
-        payload_type: MessageType::RecordRequest,
-        payload: String::from_str(&env, "{}"),
-        nonce: 1,
-        signature: dummy_sig(&env),
-        v_signature: v_sig1,
-        v_nonce: 1,
-    };
-    let req2 = SubmitMessageRequest {
-        message_id: id2.clone(),
-        source_chain: ChainId::Polygon,
-        dest_chain: ChainId::Stellar,
-        sender: sender.clone(),
-        recipient: recipient.clone(),
-        payload_type: MessageType::RecordResponse,
-        payload: String::from_str(&env, r#"{"data":"test"}"#),
-        nonce: 2,
-        signature: dummy_sig(&env),
-        v_signature: v_sig2,
-        v_nonce: 2,
-    };
+    let req1 = sign_request(
+        &env,
+        &sk,
+        SubmitMessageRequest {
+            message_id: id1.clone(),
+            source_chain: ChainId::Ethereum,
+            dest_chain: ChainId::Stellar,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            payload_type: MessageType::RecordRequest,
+            payload: String::from_str(&env, "{}"),
+            nonce: 1,
+            signature: dummy_sig(&env),
+            v_signature: v_sig1,
+            v_nonce: 1,
+            fee: 0,
+        },
+    );
+    let req2 = sign_request(
+        &env,
+        &sk,
+        SubmitMessageRequest {
+            message_id: id2.clone(),
+            source_chain: ChainId::Polygon,
+            dest_chain: ChainId::Stellar,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            payload_type: MessageType::RecordResponse,
+            payload: String::from_str(&env, r#"{"data":"test"}"#),
+            nonce: 2,
+            signature: dummy_sig(&env),
+            v_signature: v_sig2,
+            v_nonce: 2,
+            fee: 0,
+        },
+    );
 
     let ids = client.submit_message_batch(&validator, &soroban_sdk::vec![&env, req1, req2]);
     assert_eq!(ids.len(), 2);
@@ -1879,19 +4336,24 @@ fn test_submit_message_batch_too_large() {
     for i in 0..51 {
         let mid = BytesN::from_array(&env, &[i as u8; 32]);
         let v_sig = create_sig(&env, &sk, &mid, i as u64 + 1);
-        requests.push_back(SubmitMessageRequest {
-            message_id: mid,
-            source_chain: ChainId::Ethereum,
-            dest_chain: ChainId::Stellar,
-            sender: sender.clone(),
-            recipient: recipient.clone(),
-            payload_type: MessageType::RecordRequest,
-            payload: String::from_str(&env, "{}"),
-            nonce: i as u64 + 1,
-            signature: dummy_sig(&env),
-            v_signature: v_sig,
-            v_nonce: i as u64 + 1,
-        });
+        requests.push_back(sign_request(
+            &env,
+            &sk,
+            SubmitMessageRequest {
+                message_id: mid,
+                source_chain: ChainId::Ethereum,
+                dest_chain: ChainId::Stellar,
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                payload_type: MessageType::RecordRequest,
+                payload: String::from_str(&env, "{}"),
+                nonce: i as u64 + 1,
+                signature: dummy_sig(&env),
+                v_signature: v_sig,
+                v_nonce: i as u64 + 1,
+                fee: 0,
+            },
+        ));
     }
 
     let result = client.try_submit_message_batch(&validator, &requests);
@@ -1911,20 +4373,68 @@ fn test_submit_message_batch_rejects_invalid_chain() {
     let v_sig = create_sig(&env, &sk, &mid, 1);
 
     env.mock_all_auths();
-    let request = SubmitMessageRequest {
-        message_id: mid,
-        source_chain: ChainId::BinanceSmartChain,
-        dest_chain: ChainId::Stellar,
-        sender,
-        recipient,
-        payload_type: MessageType::RecordRequest,
-        payload: String::from_str(&env, "{}"),
-        nonce: 1,
-        signature: dummy_sig(&env),
-        v_signature: v_sig,
-        v_nonce: 1,
-    };
+    let request = sign_request(
+        &env,
+        &sk,
+        SubmitMessageRequest {
+            message_id: mid,
+            source_chain: ChainId::BinanceSmartChain,
+            dest_chain: ChainId::Stellar,
+            sender,
+            recipient,
+            payload_type: MessageType::RecordRequest,
+            payload: String::from_str(&env, "{}"),
+            nonce: 1,
+            signature: dummy_sig(&env),
+            v_signature: v_sig,
+            v_nonce: 1,
+            fee: 0,
+        },
+    );
 
     let result = client.try_submit_message_batch(&validator, &soroban_sdk::vec![&env, request]);
     assert_eq!(result, Err(Ok(Error::ChainNotSupported)));
 }
+
+#[test]
+fn test_access_contract_revocation_relays_access_revoke() {
+    use cross_chain_access::{AccessScope, CrossChainAccessContractClient, PermissionLevel};
+
+    let env = Env::default();
+    let (client, admin, medical, identity, _) = create_contract(&env);
+    let access_id = env.register_contract(None, cross_chain_access::CrossChainAccessContract);
+    let access = CrossChainAccessContractClient::new(&env, &access_id);
+    initialize_contract(&env, &client, &admin, &medical, &identity, &access_id);
+    access.initialize(&admin, &client.address, &identity);
+
+    let patient = Address::generate(&env);
+    let grant = |grantee: &str| {
+        access.grant_access(
+            &patient,
+            &cross_chain_access::ChainId::Ethereum,
+            &String::from_str(&env, grantee),
+            &PermissionLevel::Read,
+            &AccessScope::AllRecords,
+            &86400,
+            &Vec::new(&env),
+        )
+    };
+
+    // Until the access contract is an authorized relayer the bridge refuses it
+    let first_id = grant("0xnurse");
+    assert!(!access.revoke_and_notify(&patient, &first_id));
+    assert_eq!(client.get_message_count(), 0);
+
+    client.add_relayer(&admin, &access_id);
+    let grant_id = grant("0xdoctor");
+    assert!(access.revoke_and_notify(&patient, &grant_id));
+
+    let page = client.get_messages_by_status(&MessageStatus::Pending, &None, &10);
+    assert_eq!(page.messages.len(), 1);
+    let message = page.messages.get(0).unwrap();
+    assert!(message.payload_type == MessageType::AccessRevoke);
+    assert_eq!(message.source_chain, ChainId::Stellar);
+    assert_eq!(message.dest_chain, ChainId::Ethereum);
+    assert_eq!(message.recipient, access_id);
+    assert_eq!(message.payload, String::from_str(&env, "2:0xdoctor"));
+}
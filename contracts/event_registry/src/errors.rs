@@ -0,0 +1,35 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum Error {
+    /// Caller is not the owner of the topic.
+    Unauthorized = 1,
+    /// A topic with this name is already registered.
+    TopicExists = 2,
+    /// No topic with this name is registered.
+    TopicNotFound = 3,
+    /// The subscriber is already listed for the topic.
+    AlreadySubscribed = 4,
+    /// The subscriber is not listed for the topic.
+    NotSubscribed = 5,
+    /// The topic already has the maximum number of subscribers.
+    SubscriberLimitReached = 6,
+    /// The publish counter for the topic would overflow.
+    Overflow = 7,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Unauthorized => write!(f, "unauthorized"),
+            Error::TopicExists => write!(f, "topic already registered"),
+            Error::TopicNotFound => write!(f, "topic not found"),
+            Error::AlreadySubscribed => write!(f, "already subscribed"),
+            Error::NotSubscribed => write!(f, "not subscribed"),
+            Error::SubscriberLimitReached => write!(f, "subscriber limit reached"),
+            Error::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::types::Delivery;
+
+// Indexers filter on ("EVT_REG", <action>); deliveries also carry the
+// subscriber as a third topic so each consumer can watch only its own.
+
+pub fn emit_topic_registered(env: &Env, topic: &Symbol, owner: &Address) {
+    env.events().publish(
+        ("EVT_REG", symbol_short!("TOPIC_NEW")),
+        (topic.clone(), owner.clone()),
+    );
+}
+
+pub fn emit_subscribed(env: &Env, topic: &Symbol, subscriber: &Address) {
+    env.events().publish(
+        ("EVT_REG", symbol_short!("SUB_ADD")),
+        (topic.clone(), subscriber.clone()),
+    );
+}
+
+pub fn emit_unsubscribed(env: &Env, topic: &Symbol, subscriber: &Address) {
+    env.events().publish(
+        ("EVT_REG", symbol_short!("SUB_RMV")),
+        (topic.clone(), subscriber.clone()),
+    );
+}
+
+pub fn emit_delivery(env: &Env, subscriber: &Address, delivery: &Delivery) {
+    env.events().publish(
+        ("EVT_REG", symbol_short!("DELIVER"), subscriber.clone()),
+        delivery.clone(),
+    );
+}
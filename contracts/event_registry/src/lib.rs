@@ -0,0 +1,208 @@
+//! # Event Registry
+//!
+//! Shared on-chain pub/sub so consumers register interest in a topic once
+//! instead of integrating with every producing contract:
+//! - A producer (account or contract) registers a topic and becomes its owner
+//! - Consumers `subscribe` to the topic, up to `MAX_SUBSCRIBERS_PER_TOPIC`
+//! - The owner `publish`es a payload hash; one `DELIVER` event is emitted per
+//!   subscriber, with the subscriber as a topic so each can filter for itself
+//!
+//! Producing contracts call `publish` through `EventRegistryClient`; because
+//! the owner check is `require_auth`, a contract owner authorizes simply by
+//! being the direct invoker.
+
+#![no_std]
+
+mod errors;
+mod events;
+#[cfg(test)]
+mod test;
+mod types;
+
+pub use errors::Error;
+pub use types::{Delivery, TopicInfo};
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, Symbol, Vec};
+
+/// Keeps the per-publish event fan-out within a single transaction's budget.
+pub const MAX_SUBSCRIBERS_PER_TOPIC: u32 = 50;
+
+/// Extend persistent entries if their remaining TTL falls below this.
+const TTL_THRESHOLD: u32 = 1_000;
+/// Extend persistent entries to this many ledgers (~6 days at 5s/ledger).
+const TTL_EXTEND_TO: u32 = 100_000;
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum DataKey {
+    Topic(Symbol),
+    Subscribers(Symbol),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct EventRegistry;
+
+#[contractimpl]
+impl EventRegistry {
+    // -----------------------------------------------------------------------
+    // Topics
+    // -----------------------------------------------------------------------
+
+    /// Register `topic` with `owner` as its only publisher.
+    ///
+    /// # Auth
+    /// Requires auth from `owner`.
+    pub fn register_topic(env: Env, owner: Address, topic: Symbol) -> Result<(), Error> {
+        owner.require_auth();
+
+        let key = DataKey::Topic(topic.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::TopicExists);
+        }
+
+        let info = TopicInfo {
+            owner: owner.clone(),
+            created_at: env.ledger().timestamp(),
+            publish_count: 0,
+        };
+        env.storage().persistent().set(&key, &info);
+        Self::bump(&env, &key);
+
+        events::emit_topic_registered(&env, &topic, &owner);
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Subscriptions
+    // -----------------------------------------------------------------------
+
+    /// Add `subscriber` to the delivery list of `topic`.
+    ///
+    /// # Auth
+    /// Requires auth from `subscriber`.
+    pub fn subscribe(env: Env, subscriber: Address, topic: Symbol) -> Result<(), Error> {
+        subscriber.require_auth();
+        Self::read_topic(&env, &topic)?;
+
+        let key = DataKey::Subscribers(topic.clone());
+        let mut subscribers = Self::read_subscribers(&env, &key);
+        if subscribers.contains(&subscriber) {
+            return Err(Error::AlreadySubscribed);
+        }
+        if subscribers.len() >= MAX_SUBSCRIBERS_PER_TOPIC {
+            return Err(Error::SubscriberLimitReached);
+        }
+
+        subscribers.push_back(subscriber.clone());
+        env.storage().persistent().set(&key, &subscribers);
+        Self::bump(&env, &key);
+
+        events::emit_subscribed(&env, &topic, &subscriber);
+        Ok(())
+    }
+
+    /// Remove `subscriber` from the delivery list of `topic`.
+    ///
+    /// # Auth
+    /// Requires auth from `subscriber`.
+    pub fn unsubscribe(env: Env, subscriber: Address, topic: Symbol) -> Result<(), Error> {
+        subscriber.require_auth();
+
+        let key = DataKey::Subscribers(topic.clone());
+        let mut subscribers = Self::read_subscribers(&env, &key);
+        let index = subscribers
+            .first_index_of(&subscriber)
+            .ok_or(Error::NotSubscribed)?;
+
+        subscribers.remove(index);
+        env.storage().persistent().set(&key, &subscribers);
+        Self::bump(&env, &key);
+
+        events::emit_unsubscribed(&env, &topic, &subscriber);
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Publishing
+    // -----------------------------------------------------------------------
+
+    /// Emit one delivery event per subscriber of `topic` and return the
+    /// payload's sequence number within the topic (starting at 1).
+    ///
+    /// Only the hash is stored on the event; consumers fetch the payload
+    /// from the producer using it.
+    ///
+    /// # Auth
+    /// Requires auth from `publisher`, who must own `topic`.
+    pub fn publish(
+        env: Env,
+        publisher: Address,
+        topic: Symbol,
+        payload_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        publisher.require_auth();
+
+        let topic_key = DataKey::Topic(topic.clone());
+        let mut info = Self::read_topic(&env, &topic)?;
+        if info.owner != publisher {
+            return Err(Error::Unauthorized);
+        }
+
+        info.publish_count = info.publish_count.checked_add(1).ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&topic_key, &info);
+        Self::bump(&env, &topic_key);
+
+        let delivery = Delivery {
+            topic: topic.clone(),
+            publisher,
+            payload_hash,
+            sequence: info.publish_count,
+            timestamp: env.ledger().timestamp(),
+        };
+        for subscriber in Self::read_subscribers(&env, &DataKey::Subscribers(topic)).iter() {
+            events::emit_delivery(&env, &subscriber, &delivery);
+        }
+
+        Ok(info.publish_count)
+    }
+
+    // -----------------------------------------------------------------------
+    // Read-only queries
+    // -----------------------------------------------------------------------
+
+    pub fn get_topic(env: Env, topic: Symbol) -> Option<TopicInfo> {
+        env.storage().persistent().get(&DataKey::Topic(topic))
+    }
+
+    pub fn get_subscribers(env: Env, topic: Symbol) -> Vec<Address> {
+        Self::read_subscribers(&env, &DataKey::Subscribers(topic))
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal helpers
+    // -----------------------------------------------------------------------
+
+    fn read_topic(env: &Env, topic: &Symbol) -> Result<TopicInfo, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Topic(topic.clone()))
+            .ok_or(Error::TopicNotFound)
+    }
+
+    fn read_subscribers(env: &Env, key: &DataKey) -> Vec<Address> {
+        env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+    }
+
+    fn bump(env: &Env, key: &DataKey) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+}
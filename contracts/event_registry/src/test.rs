@@ -0,0 +1,167 @@
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events},
+    Address, BytesN, Env, IntoVal, Symbol,
+};
+
+fn setup() -> (Env, Address, EventRegistryClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let owner = Address::generate(&env);
+    let contract_id = env.register_contract(None, EventRegistry);
+    let client = EventRegistryClient::new(&env, &contract_id);
+    client.register_topic(&owner, &symbol_short!("records"));
+    (env, owner, client)
+}
+
+fn delivered_to(env: &Env) -> Vec<Address> {
+    let deliver = symbol_short!("DELIVER").into_val(env);
+    let mut subscribers = Vec::new(env);
+    for (_, topics, _) in env.events().all().iter() {
+        if topics.len() == 3 && topics.get_unchecked(1).shallow_eq(&deliver) {
+            subscribers.push_back(topics.get_unchecked(2).into_val(env));
+        }
+    }
+    subscribers
+}
+
+#[test]
+fn test_register_topic_twice_fails() {
+    let (env, _, client) = setup();
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_register_topic(&other, &symbol_short!("records")),
+        Err(Ok(Error::TopicExists))
+    );
+}
+
+#[test]
+fn test_subscribe_and_publish() {
+    let (env, owner, client) = setup();
+    let topic = symbol_short!("records");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.subscribe(&alice, &topic);
+    client.subscribe(&bob, &topic);
+    assert_eq!(
+        client.get_subscribers(&topic),
+        Vec::from_array(&env, [alice.clone(), bob.clone()])
+    );
+    assert_eq!(
+        client.try_subscribe(&alice, &topic),
+        Err(Ok(Error::AlreadySubscribed))
+    );
+
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(client.publish(&owner, &topic, &hash), 1);
+    assert_eq!(delivered_to(&env), Vec::from_array(&env, [alice, bob]));
+    assert_eq!(client.get_topic(&topic).unwrap().publish_count, 1);
+}
+
+#[test]
+fn test_unsubscribe_stops_delivery() {
+    let (env, owner, client) = setup();
+    let topic = symbol_short!("records");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.subscribe(&alice, &topic);
+    client.subscribe(&bob, &topic);
+
+    client.unsubscribe(&alice, &topic);
+    assert_eq!(
+        client.try_unsubscribe(&alice, &topic),
+        Err(Ok(Error::NotSubscribed))
+    );
+
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(client.publish(&owner, &topic, &hash), 1);
+    assert_eq!(delivered_to(&env), Vec::from_array(&env, [bob]));
+}
+
+#[test]
+fn test_subscriber_cap_enforced() {
+    let (env, _, client) = setup();
+    let topic = symbol_short!("records");
+    for _ in 0..MAX_SUBSCRIBERS_PER_TOPIC {
+        client.subscribe(&Address::generate(&env), &topic);
+    }
+
+    let late = Address::generate(&env);
+    assert_eq!(
+        client.try_subscribe(&late, &topic),
+        Err(Ok(Error::SubscriberLimitReached))
+    );
+
+    // A freed slot can be taken again.
+    let first = client.get_subscribers(&topic).get_unchecked(0);
+    client.unsubscribe(&first, &topic);
+    client.subscribe(&late, &topic);
+    assert_eq!(
+        client.get_subscribers(&topic).len(),
+        MAX_SUBSCRIBERS_PER_TOPIC
+    );
+}
+
+#[test]
+fn test_publish_requires_topic_owner() {
+    let (env, _, client) = setup();
+    let other = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        client.try_publish(&other, &symbol_short!("records"), &hash),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_publish(&other, &symbol_short!("missing"), &hash),
+        Err(Ok(Error::TopicNotFound))
+    );
+    assert_eq!(
+        client.try_subscribe(&other, &symbol_short!("missing")),
+        Err(Ok(Error::TopicNotFound))
+    );
+}
+
+// ==================== Cross-contract producer ====================
+
+#[contract]
+struct Producer;
+
+#[contractimpl]
+impl Producer {
+    pub fn register(env: Env, registry: Address, topic: Symbol) {
+        EventRegistryClient::new(&env, &registry)
+            .register_topic(&env.current_contract_address(), &topic);
+    }
+
+    pub fn emit(env: Env, registry: Address, topic: Symbol, payload_hash: BytesN<32>) -> u64 {
+        EventRegistryClient::new(&env, &registry).publish(
+            &env.current_contract_address(),
+            &topic,
+            &payload_hash,
+        )
+    }
+}
+
+#[test]
+fn test_contract_producer_publishes_as_invoker() {
+    let env = Env::default();
+    let registry_id = env.register_contract(None, EventRegistry);
+    let registry = EventRegistryClient::new(&env, &registry_id);
+    let producer = ProducerClient::new(&env, &env.register_contract(None, Producer));
+    let topic = symbol_short!("bridge");
+
+    // No mocked auths: the producer contract authorizes as the direct invoker.
+    producer.register(&registry_id, &topic);
+    assert_eq!(registry.get_topic(&topic).unwrap().owner, producer.address);
+
+    let subscriber = Address::generate(&env);
+    env.mock_all_auths();
+    registry.subscribe(&subscriber, &topic);
+    env.set_auths(&[]);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    assert_eq!(producer.emit(&registry_id, &topic, &hash), 1);
+    assert_eq!(delivered_to(&env), Vec::from_array(&env, [subscriber]));
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TopicInfo {
+    /// The only address allowed to publish to the topic.
+    pub owner: Address,
+    pub created_at: u64,
+    /// Number of payloads published so far; also the sequence of the latest one.
+    pub publish_count: u64,
+}
+
+/// Payload of the delivery event emitted once per subscriber on `publish`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delivery {
+    pub topic: Symbol,
+    pub publisher: Address,
+    pub payload_hash: BytesN<32>,
+    pub sequence: u64,
+    pub timestamp: u64,
+}
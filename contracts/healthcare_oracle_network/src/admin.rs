@@ -1,8 +1,14 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Env, Map, String, Vec};
 
-use crate::types::{Config, DataKey, Error};
+use crate::types::{
+    AggregationConfig, AggregationMethod, Config, DataKey, DisputeBondConfig, Error, FeedKind,
+    StakeConfig,
+};
 use crate::utils;
 
+/// Seven days without a submission or admin update marks an oracle stale.
+const DEFAULT_INACTIVITY_TIMEOUT: u64 = 604_800;
+
 pub fn initialize(
     env: Env,
     admin: Address,
@@ -19,9 +25,12 @@ pub fn initialize(
         admin,
         arbiters,
         min_submissions,
+        feed_min_submissions: Map::new(&env),
         min_reputation: 0,
         max_drug_price_minor: 1_000_000_000,
         max_availability_units: 5_000_000,
+        inactivity_timeout: DEFAULT_INACTIVITY_TIMEOUT,
+        arbiter_quorum: 1,
     };
 
     env.storage().instance().set(&DataKey::Config, &config);
@@ -61,6 +70,69 @@ pub fn update_config(
     Ok(())
 }
 
+pub fn update_feed_thresholds(
+    env: Env,
+    admin: Address,
+    kind: FeedKind,
+    min_submissions: u32,
+) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if min_submissions == 0 {
+        return Err(Error::InvalidData);
+    }
+
+    let mut config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(Error::NotInitialized)?;
+
+    config.feed_min_submissions.set(kind, min_submissions);
+    env.storage().instance().set(&DataKey::Config, &config);
+    Ok(())
+}
+
+pub fn update_inactivity_timeout(
+    env: Env,
+    admin: Address,
+    inactivity_timeout: u64,
+) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if inactivity_timeout == 0 {
+        return Err(Error::InvalidData);
+    }
+
+    let mut config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(Error::NotInitialized)?;
+
+    config.inactivity_timeout = inactivity_timeout;
+    env.storage().instance().set(&DataKey::Config, &config);
+    Ok(())
+}
+
+pub fn update_arbiter_quorum(env: Env, admin: Address, arbiter_quorum: u32) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    let mut config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(Error::NotInitialized)?;
+
+    if arbiter_quorum == 0 || arbiter_quorum > config.arbiters.len() {
+        return Err(Error::InvalidData);
+    }
+
+    config.arbiter_quorum = arbiter_quorum;
+    env.storage().instance().set(&DataKey::Config, &config);
+    Ok(())
+}
+
 #[must_use]
 pub fn add_arbiter(env: Env, admin: Address, arbiter: Address) -> Result<(), Error> {
     utils::require_admin(&env, admin)?;
@@ -80,6 +152,114 @@ pub fn add_arbiter(env: Env, admin: Address, arbiter: Address) -> Result<(), Err
     Ok(())
 }
 
+/// Median aggregation and outlier filtering are only defined for drug
+/// pricing, where a single `price_minor` drives consensus.
+pub fn set_aggregation_config(
+    env: Env,
+    admin: Address,
+    kind: FeedKind,
+    method: AggregationMethod,
+    outlier_threshold_bps: u32,
+) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if kind != FeedKind::DrugPricing
+        && (method == AggregationMethod::Median || outlier_threshold_bps > 0)
+    {
+        return Err(Error::InvalidFeedType);
+    }
+
+    env.storage().instance().set(
+        &DataKey::AggregationConfig(kind),
+        &AggregationConfig {
+            method,
+            outlier_threshold_bps,
+        },
+    );
+    Ok(())
+}
+
+pub fn get_aggregation_config(env: Env, kind: FeedKind) -> AggregationConfig {
+    utils::read_aggregation_config(&env, kind)
+}
+
+/// Oracles registered afterwards must lock `min_stake` of `token`.
+pub fn configure_staking(
+    env: Env,
+    admin: Address,
+    token: Address,
+    min_stake: i128,
+) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if min_stake <= 0 {
+        return Err(Error::InvalidData);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::StakeConfig, &StakeConfig { token, min_stake });
+    Ok(())
+}
+
+pub fn get_stake_config(env: Env) -> Option<StakeConfig> {
+    env.storage().instance().get(&DataKey::StakeConfig)
+}
+
+/// Enables FX normalization: drug prices in other currencies are converted
+/// to `currency` before aggregation. Without a base currency, rounds that
+/// mix currencies are rejected.
+pub fn set_base_currency(env: Env, admin: Address, currency: String) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if currency.len() == 0 {
+        return Err(Error::InvalidData);
+    }
+
+    env.storage().instance().set(&DataKey::BaseCurrency, &currency);
+    Ok(())
+}
+
+/// `rate_bps` is the base-currency amount per 10_000 units of `currency`.
+pub fn set_fx_rate(env: Env, admin: Address, currency: String, rate_bps: i128) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if currency.len() == 0 || rate_bps <= 0 {
+        return Err(Error::InvalidData);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::FxRate(currency), &rate_bps);
+    Ok(())
+}
+
+/// Disputes raised afterwards must lock `amount` of `token`; the bond is
+/// refunded on a valid ruling and sent to `treasury` otherwise.
+pub fn configure_dispute_bond(
+    env: Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+    treasury: Address,
+) -> Result<(), Error> {
+    utils::require_admin(&env, admin)?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidData);
+    }
+
+    env.storage().instance().set(
+        &DataKey::DisputeBondConfig,
+        &DisputeBondConfig {
+            token,
+            amount,
+            treasury,
+        },
+    );
+    Ok(())
+}
+
 pub fn get_config(env: Env) -> Option<Config> {
     env.storage().instance().get(&DataKey::Config)
 }
@@ -1,7 +1,8 @@
-use soroban_sdk::{symbol_short, Address, Env, String};
+use soroban_sdk::{symbol_short, token, Address, Env, String};
 
 use crate::types::{
-    Config, ConsensusRecord, DataKey, Dispute, DisputeStatus, Error, FeedKey, FeedKind,
+    Config, ConsensusRecord, DataKey, Dispute, DisputeBondConfig, DisputeStatus, Error, FeedKey,
+    FeedKind,
 };
 use crate::utils;
 
@@ -30,6 +31,22 @@ pub fn raise_dispute(
         return Err(Error::InvalidDisputeState);
     }
 
+    let bond_cfg: Option<DisputeBondConfig> =
+        env.storage().instance().get(&DataKey::DisputeBondConfig);
+    let (bond, bond_token) = match bond_cfg {
+        Some(cfg) => {
+            let client = token::Client::new(&env, &cfg.token);
+            if client.balance(&challenger) < cfg.amount {
+                return Err(Error::InsufficientBond);
+            }
+            utils::with_reentrancy_guard(&env, || {
+                client.transfer(&challenger, &env.current_contract_address(), &cfg.amount)
+            })?;
+            (cfg.amount, Some(cfg.token))
+        },
+        None => (0, None),
+    };
+
     let dispute_id: u64 = env
         .storage()
         .instance()
@@ -48,6 +65,11 @@ pub fn raise_dispute(
         resolved_at: None,
         resolver: None,
         ruling: None,
+        bond,
+        bond_token,
+        penalized_oracle: None,
+        votes_valid: 0,
+        votes_invalid: 0,
     };
 
     env.storage()
@@ -56,12 +78,17 @@ pub fn raise_dispute(
     env.storage()
         .instance()
         .set(&DataKey::DisputeCount, &dispute_id);
+    utils::adjust_open_disputes(&env, &consensus.submitters, true);
 
     env.events()
         .publish((symbol_short!("dispute"),), dispute_id);
     Ok(dispute_id)
 }
 
+/// Casts the resolver's vote and records the proposed ruling. With the
+/// default quorum of 1 this resolves the dispute immediately. Under a larger
+/// quorum the first ruling and penalized oracle stand, and a valid vote that
+/// names a different oracle is rejected.
 pub fn resolve_dispute(
     env: Env,
     resolver: Address,
@@ -70,6 +97,36 @@ pub fn resolve_dispute(
     ruling: String,
     penalized_oracle: Option<Address>,
 ) -> Result<(), Error> {
+    cast_vote(
+        env,
+        resolver,
+        dispute_id,
+        valid_dispute,
+        Some(ruling),
+        penalized_oracle,
+    )?;
+    Ok(())
+}
+
+/// Records an arbiter's vote; the dispute resolves once either side reaches
+/// `arbiter_quorum`. Returns the dispute status after the vote.
+pub fn vote_on_dispute(
+    env: Env,
+    arbiter: Address,
+    dispute_id: u64,
+    valid: bool,
+) -> Result<DisputeStatus, Error> {
+    cast_vote(env, arbiter, dispute_id, valid, None, None)
+}
+
+fn cast_vote(
+    env: Env,
+    resolver: Address,
+    dispute_id: u64,
+    valid_dispute: bool,
+    ruling: Option<String>,
+    penalized_oracle: Option<Address>,
+) -> Result<DisputeStatus, Error> {
     resolver.require_auth();
     let config: Config = env
         .storage()
@@ -90,7 +147,105 @@ pub fn resolve_dispute(
     if dispute.status != DisputeStatus::Open {
         return Err(Error::DisputeAlreadyResolved);
     }
+    if voting_closed(&env, &dispute) {
+        return Err(Error::DisputeVotingClosed);
+    }
+
+    let vote_key = DataKey::DisputeVote(dispute_id, resolver.clone());
+    if env.storage().persistent().has(&vote_key) {
+        return Err(Error::AlreadyVoted);
+    }
 
+    // The first valid vote fixes the penalty; later valid votes endorse it and
+    // cannot swap in a different oracle before quorum is reached.
+    if valid_dispute && penalized_oracle.is_some() {
+        match &dispute.penalized_oracle {
+            Some(pinned) if Some(pinned) != penalized_oracle.as_ref() => {
+                return Err(Error::InvalidDisputeState);
+            },
+            None if dispute.votes_valid > 0 => return Err(Error::InvalidDisputeState),
+            _ => dispute.penalized_oracle = penalized_oracle,
+        }
+    }
+    if dispute.ruling.is_none() {
+        dispute.ruling = ruling;
+    }
+
+    env.storage().persistent().set(&vote_key, &valid_dispute);
+    if valid_dispute {
+        dispute.votes_valid = dispute.votes_valid.saturating_add(1);
+    } else {
+        dispute.votes_invalid = dispute.votes_invalid.saturating_add(1);
+    }
+
+    env.events().publish(
+        (symbol_short!("vote"), dispute_id),
+        (resolver.clone(), valid_dispute),
+    );
+
+    let quorum = config.arbiter_quorum.max(1);
+    if dispute.votes_valid >= quorum {
+        apply_resolution(&env, &config, &mut dispute, true, resolver)?;
+    } else if dispute.votes_invalid >= quorum {
+        apply_resolution(&env, &config, &mut dispute, false, resolver)?;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(dispute_id), &dispute);
+    Ok(dispute.status)
+}
+
+/// Closes a dispute whose voting window elapsed without either side reaching
+/// `arbiter_quorum`, so a split panel cannot leave it open and pin the
+/// submitters' stake. The side with more votes wins; a tie or no votes leaves
+/// the dispute unproven and resolves it invalid. Anyone may call it.
+pub fn close_expired_dispute(env: Env, dispute_id: u64) -> Result<DisputeStatus, Error> {
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(Error::NotInitialized)?;
+
+    let mut dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Dispute(dispute_id))
+        .ok_or(Error::DisputeNotFound)?;
+
+    if dispute.status != DisputeStatus::Open {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+    if !voting_closed(&env, &dispute) {
+        return Err(Error::DisputeVotingOpen);
+    }
+
+    let valid_dispute = dispute.votes_valid > dispute.votes_invalid;
+    apply_resolution(
+        &env,
+        &config,
+        &mut dispute,
+        valid_dispute,
+        env.current_contract_address(),
+    )?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(dispute_id), &dispute);
+    Ok(dispute.status)
+}
+
+fn voting_closed(env: &Env, dispute: &Dispute) -> bool {
+    env.ledger().timestamp() >= dispute.opened_at.saturating_add(utils::DISPUTE_VOTING_SECS)
+}
+
+fn apply_resolution(
+    env: &Env,
+    config: &Config,
+    dispute: &mut Dispute,
+    valid_dispute: bool,
+    resolver: Address,
+) -> Result<(), Error> {
     let mut consensus: ConsensusRecord = env
         .storage()
         .persistent()
@@ -101,33 +256,55 @@ pub fn resolve_dispute(
         consensus.disputed = true;
         dispute.status = DisputeStatus::ResolvedValid;
 
-        if let Some(oracle) = penalized_oracle {
-            utils::adjust_reputation(&env, oracle, -15, true)?;
+        if let Some(oracle) = dispute.penalized_oracle.clone() {
+            utils::adjust_reputation(env, oracle, -15, true)?;
         }
     } else {
         dispute.status = DisputeStatus::ResolvedInvalid;
         let mut i = 0;
         while i < consensus.submitters.len() {
             let submitter = consensus.submitters.get(i).unwrap();
-            utils::adjust_reputation(&env, submitter, 2, false)?;
+            utils::adjust_reputation(env, submitter, 2, false)?;
             i += 1;
         }
     }
 
+    utils::adjust_open_disputes(env, &consensus.submitters, false);
+
+    if let Some(bond_token) = dispute.bond_token.clone() {
+        if dispute.bond > 0 {
+            let recipient = if valid_dispute {
+                dispute.challenger.clone()
+            } else {
+                env.storage()
+                    .instance()
+                    .get::<DataKey, DisputeBondConfig>(&DataKey::DisputeBondConfig)
+                    .map(|cfg| cfg.treasury)
+                    .unwrap_or(config.admin.clone())
+            };
+            utils::with_reentrancy_guard(env, || {
+                token::Client::new(env, &bond_token).transfer(
+                    &env.current_contract_address(),
+                    &recipient,
+                    &dispute.bond,
+                )
+            })?;
+        }
+    }
+
     dispute.resolved_at = Some(env.ledger().timestamp());
     dispute.resolver = Some(resolver);
-    dispute.ruling = Some(ruling);
 
     env.storage()
         .persistent()
         .set(&DataKey::Consensus(dispute.key.clone()), &consensus);
-    env.storage()
-        .persistent()
-        .set(&DataKey::Dispute(dispute_id), &dispute);
+    env.storage().persistent().set(
+        &DataKey::ConsensusHistory(dispute.key.clone(), dispute.round_id),
+        &consensus,
+    );
 
     env.events()
-        .publish((symbol_short!("resolve"), dispute_id), valid_dispute);
-
+        .publish((symbol_short!("resolve"), dispute.id), valid_dispute);
     Ok(())
 }
 
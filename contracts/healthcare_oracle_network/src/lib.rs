@@ -19,9 +19,10 @@ mod utils;
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec};
 
 pub use types::{
-    AggregationRound, ClinicalTrialData, Config, ConsensusRecord, DataKey, Dispute, DisputeStatus,
-    DrugPriceData, Error, FeedKey, FeedKind, FeedPayload, OracleNode, RegulatoryAuthority,
-    RegulatoryStatus, RegulatoryUpdateData, SourceType, TreatmentOutcomeData,
+    AggregationConfig, AggregationMethod, AggregationRound, ClinicalTrialData, Config,
+    ConsensusRecord, DataKey, Dispute, DisputeStatus, DrugPriceData, Error, FeedKey, FeedKind,
+    FeedPayload, OracleNode, RegulatoryAuthority, RegulatoryStatus, RegulatoryUpdateData,
+    SourceType, StakeConfig, TreatmentOutcomeData,
 };
 
 #[contract]
@@ -57,6 +58,10 @@ impl HealthcareOracleNetwork {
         oracles::verify_oracle(env, admin, operator, verified, active)
     }
 
+    pub fn withdraw_stake(env: Env, operator: Address) -> Result<i128, Error> {
+        oracles::withdraw_stake(env, operator)
+    }
+
     pub fn update_oracle_endpoint(
         env: Env,
         operator: Address,
@@ -83,10 +88,89 @@ impl HealthcareOracleNetwork {
         )
     }
 
+    pub fn update_feed_thresholds(
+        env: Env,
+        admin: Address,
+        kind: FeedKind,
+        min_submissions: u32,
+    ) -> Result<(), Error> {
+        admin::update_feed_thresholds(env, admin, kind, min_submissions)
+    }
+
+    pub fn update_inactivity_timeout(
+        env: Env,
+        admin: Address,
+        inactivity_timeout: u64,
+    ) -> Result<(), Error> {
+        admin::update_inactivity_timeout(env, admin, inactivity_timeout)
+    }
+
+    pub fn prune_inactive_oracles(env: Env, admin: Address) -> Result<u32, Error> {
+        oracles::prune_inactive_oracles(env, admin)
+    }
+
+    pub fn update_arbiter_quorum(
+        env: Env,
+        admin: Address,
+        arbiter_quorum: u32,
+    ) -> Result<(), Error> {
+        admin::update_arbiter_quorum(env, admin, arbiter_quorum)
+    }
+
     pub fn add_arbiter(env: Env, admin: Address, arbiter: Address) -> Result<(), Error> {
         admin::add_arbiter(env, admin, arbiter)
     }
 
+    pub fn configure_staking(
+        env: Env,
+        admin: Address,
+        token: Address,
+        min_stake: i128,
+    ) -> Result<(), Error> {
+        admin::configure_staking(env, admin, token, min_stake)
+    }
+
+    pub fn get_stake_config(env: Env) -> Option<StakeConfig> {
+        admin::get_stake_config(env)
+    }
+
+    pub fn configure_dispute_bond(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        treasury: Address,
+    ) -> Result<(), Error> {
+        admin::configure_dispute_bond(env, admin, token, amount, treasury)
+    }
+
+    pub fn set_base_currency(env: Env, admin: Address, currency: String) -> Result<(), Error> {
+        admin::set_base_currency(env, admin, currency)
+    }
+
+    pub fn set_fx_rate(
+        env: Env,
+        admin: Address,
+        currency: String,
+        rate_bps: i128,
+    ) -> Result<(), Error> {
+        admin::set_fx_rate(env, admin, currency, rate_bps)
+    }
+
+    pub fn set_aggregation_config(
+        env: Env,
+        admin: Address,
+        kind: FeedKind,
+        method: AggregationMethod,
+        outlier_threshold_bps: u32,
+    ) -> Result<(), Error> {
+        admin::set_aggregation_config(env, admin, kind, method, outlier_threshold_bps)
+    }
+
+    pub fn get_aggregation_config(env: Env, kind: FeedKind) -> AggregationConfig {
+        admin::get_aggregation_config(env, kind)
+    }
+
     pub fn submit_drug_price(
         env: Env,
         operator: Address,
@@ -189,6 +273,10 @@ impl HealthcareOracleNetwork {
         submissions::finalize_feed(env, kind, feed_id)
     }
 
+    pub fn expire_round(env: Env, kind: FeedKind, feed_id: String) -> Result<u64, Error> {
+        submissions::expire_round(env, kind, feed_id)
+    }
+
     pub fn raise_dispute(
         env: Env,
         challenger: Address,
@@ -217,10 +305,64 @@ impl HealthcareOracleNetwork {
         )
     }
 
+    pub fn vote_on_dispute(
+        env: Env,
+        arbiter: Address,
+        dispute_id: u64,
+        valid: bool,
+    ) -> Result<DisputeStatus, Error> {
+        disputes::vote_on_dispute(env, arbiter, dispute_id, valid)
+    }
+
+    pub fn close_expired_dispute(env: Env, dispute_id: u64) -> Result<DisputeStatus, Error> {
+        disputes::close_expired_dispute(env, dispute_id)
+    }
+
     pub fn get_consensus(env: Env, kind: FeedKind, feed_id: String) -> Option<ConsensusRecord> {
         submissions::get_consensus(env, kind, feed_id)
     }
 
+    pub fn get_consensus_at(
+        env: Env,
+        kind: FeedKind,
+        feed_id: String,
+        round_id: u64,
+    ) -> Option<ConsensusRecord> {
+        submissions::get_consensus_at(env, kind, feed_id, round_id)
+    }
+
+    pub fn get_consensus_history(
+        env: Env,
+        kind: FeedKind,
+        feed_id: String,
+        page: u32,
+        page_size: u32,
+    ) -> Vec<ConsensusRecord> {
+        submissions::get_consensus_history(env, kind, feed_id, page, page_size)
+    }
+
+    pub fn subscribe_feed(
+        env: Env,
+        subscriber: Address,
+        kind: FeedKind,
+        feed_id: String,
+    ) -> Result<(), Error> {
+        submissions::subscribe_feed(env, subscriber, kind, feed_id)
+    }
+
+    pub fn unsubscribe_feed(
+        env: Env,
+        subscriber: Address,
+        kind: FeedKind,
+        feed_id: String,
+    ) -> Result<(), Error> {
+        submissions::unsubscribe_feed(env, subscriber, kind, feed_id)
+    }
+
+    pub fn get_feed_subscribers(env: Env, kind: FeedKind, feed_id: String) -> Vec<Address> {
+        submissions::get_feed_subscribers(env, kind, feed_id)
+    }
+
     pub fn report_oracle_misbehavior(
         env: Env,
         reporter: Address,
@@ -250,7 +392,9 @@ impl HealthcareOracleNetwork {
     ) -> Result<FeedPayload, Error> {
         let symbol = Symbol::new(&env, "get_feed_payload");
         let args = Vec::from_array(&env, [feed_id.clone().into_val(&env)]);
-        let payload: FeedPayload = utils::invoke_contract_cached(&env, provider, symbol, args);
+        let payload: FeedPayload = utils::with_reentrancy_guard(&env, || {
+            utils::invoke_contract_cached(&env, provider, symbol, args)
+        })?;
         Ok(payload)
     }
 
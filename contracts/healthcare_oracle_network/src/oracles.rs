@@ -1,6 +1,6 @@
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{symbol_short, token, Address, Env, String, Symbol, Vec};
 
-use crate::types::{DataKey, Error, OracleNode, SourceType};
+use crate::types::{Config, DataKey, Error, OracleNode, SourceType, StakeConfig};
 use crate::utils;
 
 pub fn register_oracle(
@@ -31,6 +31,28 @@ pub fn register_oracle(
         .unwrap_or(Vec::new(&env));
     oracles.push_back(operator.clone());
 
+    let stake = match env
+        .storage()
+        .instance()
+        .get::<DataKey, StakeConfig>(&DataKey::StakeConfig)
+    {
+        Some(cfg) => {
+            let client = token::Client::new(&env, &cfg.token);
+            if client.balance(&operator) < cfg.min_stake {
+                return Err(Error::InsufficientStake);
+            }
+            utils::with_reentrancy_guard(&env, || {
+                client.transfer(
+                    &operator,
+                    &env.current_contract_address(),
+                    &cfg.min_stake,
+                )
+            })?;
+            cfg.min_stake
+        },
+        None => 0,
+    };
+
     let node = OracleNode {
         operator: operator.clone(),
         endpoint,
@@ -41,6 +63,7 @@ pub fn register_oracle(
         submissions: 0,
         disputes: 0,
         last_seen: env.ledger().timestamp(),
+        stake,
     };
 
     env.storage()
@@ -60,6 +83,9 @@ pub fn verify_oracle(
     utils::require_admin(&env, admin)?;
 
     let mut node = utils::read_oracle(&env, operator.clone())?;
+    if verified && active && node.stake < utils::min_stake(&env) {
+        return Err(Error::InsufficientStake);
+    }
     node.verified = verified;
     node.active = active;
     node.last_seen = env.ledger().timestamp();
@@ -86,6 +112,100 @@ pub fn update_oracle_endpoint(env: Env, operator: Address, endpoint: String) ->
     Ok(())
 }
 
+/// Returns the operator's remaining stake and deactivates the oracle.
+/// Blocked while any consensus the oracle contributed to is under dispute,
+/// or while it has submissions in rounds that have neither finalized nor
+/// expired.
+pub fn withdraw_stake(env: Env, operator: Address) -> Result<i128, Error> {
+    operator.require_auth();
+    let cfg: StakeConfig = env
+        .storage()
+        .instance()
+        .get(&DataKey::StakeConfig)
+        .ok_or(Error::InsufficientStake)?;
+
+    let mut node = utils::read_oracle(&env, operator.clone())?;
+    if node.stake <= 0 {
+        return Err(Error::InsufficientStake);
+    }
+
+    let open_disputes: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::OpenDisputes(operator.clone()))
+        .unwrap_or(0);
+    if open_disputes > 0 {
+        return Err(Error::InvalidDisputeState);
+    }
+    let pending: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PendingSubmissions(operator.clone()))
+        .unwrap_or(0);
+    if pending > 0 {
+        return Err(Error::SubmissionsPending);
+    }
+
+    // Zero the stake before paying out so a re-entrant call finds nothing left.
+    let amount = node.stake;
+    node.stake = 0;
+    node.active = false;
+    node.last_seen = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Oracle(operator.clone()), &node);
+
+    utils::with_reentrancy_guard(&env, || {
+        token::Client::new(&env, &cfg.token).transfer(
+            &env.current_contract_address(),
+            &operator,
+            &amount,
+        )
+    })?;
+
+    env.events()
+        .publish((symbol_short!("unstake"),), (operator, amount));
+    Ok(amount)
+}
+
+/// Deactivates every active oracle whose `last_seen` is older than the
+/// configured inactivity timeout. Returns the number pruned.
+pub fn prune_inactive_oracles(env: Env, admin: Address) -> Result<u32, Error> {
+    utils::require_admin(&env, admin)?;
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(Error::NotInitialized)?;
+
+    let oracles: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::OracleList)
+        .unwrap_or(Vec::new(&env));
+    let now = env.ledger().timestamp();
+
+    let mut pruned = 0u32;
+    let mut i = 0;
+    while i < oracles.len() {
+        let operator = oracles.get(i).unwrap();
+        let mut node = utils::read_oracle(&env, operator.clone())?;
+        if node.active && !utils::is_live(&node, now, config.inactivity_timeout) {
+            node.active = false;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Oracle(operator.clone()), &node);
+            env.events().publish(
+                (Symbol::new(&env, "OraclePruned"),),
+                (operator, node.last_seen),
+            );
+            pruned += 1;
+        }
+        i += 1;
+    }
+    Ok(pruned)
+}
+
 pub fn get_oracle(env: Env, operator: Address) -> Option<OracleNode> {
     env.storage().persistent().get(&DataKey::Oracle(operator))
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, String, symbol_short};
+use soroban_sdk::{Address, Env, String, Vec, symbol_short};
 
 use crate::types::{
     ClinicalTrialData, ConsensusRecord, DataKey, DrugPriceData, Error, FeedKey, FeedKind,
@@ -193,11 +193,140 @@ pub fn finalize_feed(env: Env, kind: FeedKind, feed_id: String) -> Result<Consen
     utils::finalize_round(env, key, round_id)
 }
 
+/// Closes a round that has been open longer than ROUND_EXPIRY_SECS without
+/// reaching quorum, so its submitters can withdraw their stake. Anyone may
+/// call it; the next submission also expires a stale round on its own.
+pub fn expire_round(env: Env, kind: FeedKind, feed_id: String) -> Result<u64, Error> {
+    utils::require_initialized(&env)?;
+    if feed_id.len() == 0 {
+        return Err(Error::InvalidData);
+    }
+
+    utils::expire_round(&env, FeedKey { kind, feed_id })
+}
+
 pub fn get_consensus(env: Env, kind: FeedKind, feed_id: String) -> Option<ConsensusRecord> {
     let key = FeedKey { kind, feed_id };
     env.storage().persistent().get(&DataKey::Consensus(key))
 }
 
+/// Maximum addresses subscribed to a single feed.
+const MAX_FEED_SUBSCRIBERS: u32 = 50;
+
+/// Registers `subscriber` to be listed in the feed's `FeedFinalized` event.
+/// Subscribing twice is a no-op.
+pub fn subscribe_feed(
+    env: Env,
+    subscriber: Address,
+    kind: FeedKind,
+    feed_id: String,
+) -> Result<(), Error> {
+    subscriber.require_auth();
+    utils::require_initialized(&env)?;
+    if feed_id.len() == 0 {
+        return Err(Error::InvalidData);
+    }
+
+    let key = DataKey::FeedSubscribers(FeedKey { kind, feed_id });
+    let mut subscribers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+    if subscribers.contains(&subscriber) {
+        return Ok(());
+    }
+    if subscribers.len() >= MAX_FEED_SUBSCRIBERS {
+        return Err(Error::SubscriberLimitReached);
+    }
+
+    subscribers.push_back(subscriber);
+    env.storage().persistent().set(&key, &subscribers);
+    Ok(())
+}
+
+pub fn unsubscribe_feed(
+    env: Env,
+    subscriber: Address,
+    kind: FeedKind,
+    feed_id: String,
+) -> Result<(), Error> {
+    subscriber.require_auth();
+    utils::require_initialized(&env)?;
+
+    let key = DataKey::FeedSubscribers(FeedKey { kind, feed_id });
+    let subscribers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+    if let Some(index) = subscribers.first_index_of(&subscriber) {
+        let mut updated = subscribers;
+        updated.remove(index);
+        env.storage().persistent().set(&key, &updated);
+    }
+    Ok(())
+}
+
+pub fn get_feed_subscribers(env: Env, kind: FeedKind, feed_id: String) -> Vec<Address> {
+    let key = DataKey::FeedSubscribers(FeedKey { kind, feed_id });
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Maximum records returned by a single `get_consensus_history` page.
+const MAX_HISTORY_PAGE_SIZE: u32 = 50;
+
+pub fn get_consensus_at(
+    env: Env,
+    kind: FeedKind,
+    feed_id: String,
+    round_id: u64,
+) -> Option<ConsensusRecord> {
+    let key = FeedKey { kind, feed_id };
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConsensusHistory(key, round_id))
+}
+
+/// Finalized records for a feed in round order, oldest first. `page` is
+/// zero-based and `page_size` is capped at MAX_HISTORY_PAGE_SIZE.
+pub fn get_consensus_history(
+    env: Env,
+    kind: FeedKind,
+    feed_id: String,
+    page: u32,
+    page_size: u32,
+) -> Vec<ConsensusRecord> {
+    let key = FeedKey { kind, feed_id };
+    let mut records = Vec::<ConsensusRecord>::new(&env);
+    let page_size = page_size.min(MAX_HISTORY_PAGE_SIZE) as u64;
+    if page_size == 0 {
+        return records;
+    }
+
+    let latest: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RoundCounter(key.clone()))
+        .unwrap_or(0);
+    let mut round_id = (page as u64).saturating_mul(page_size).saturating_add(1);
+    let end = round_id.saturating_add(page_size);
+    while round_id < end && round_id <= latest {
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ConsensusHistory(key.clone(), round_id))
+        {
+            records.push_back(record);
+        }
+        round_id += 1;
+    }
+    records
+}
+
 pub fn report_oracle_misbehavior(
     env: Env,
     reporter: Address,
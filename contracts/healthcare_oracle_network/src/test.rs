@@ -2,11 +2,15 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
 
+use crate::utils::weighted_median;
 use crate::{
-    DisputeStatus, Error, FeedKind, FeedPayload, HealthcareOracleNetwork,
+    AggregationMethod, DisputeStatus, Error, FeedKind, FeedPayload, HealthcareOracleNetwork,
     HealthcareOracleNetworkClient, RegulatoryAuthority, RegulatoryStatus, SourceType,
 };
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, String, Symbol, Vec,
+};
 
 fn setup_contract(
     env: &Env,
@@ -99,6 +103,212 @@ fn test_drug_feed_consensus_and_weighted_aggregation() {
     }
 }
 
+#[test]
+fn test_median_aggregation_rejects_and_slashes_outlier() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 5);
+    client.set_aggregation_config(
+        &admin,
+        &FeedKind::DrugPricing,
+        &AggregationMethod::Median,
+        &2_000u32,
+    );
+
+    let feed_id = String::from_str(&env, "NDC:0409-4888-02:US");
+    let ndc = String::from_str(&env, "0409-4888-02");
+    let currency = String::from_str(&env, "USD");
+    let prices: [i128; 5] = [1000, 1010, 990, 1005, 10_000];
+
+    let mut oracles = Vec::<Address>::new(&env);
+    for (i, price) in prices.iter().enumerate() {
+        let oracle = Address::generate(&env);
+        register_and_verify_oracle(&env, &client, &admin, &oracle, "https://median.example");
+        client.submit_drug_price(
+            &oracle,
+            &feed_id,
+            &ndc,
+            &currency,
+            price,
+            &100u32,
+            &(200u64 + i as u64),
+        );
+        oracles.push_back(oracle);
+    }
+
+    let consensus = client
+        .get_consensus(&FeedKind::DrugPricing, &feed_id)
+        .expect("consensus should exist");
+    assert_eq!(consensus.submitters.len(), 4);
+    assert_eq!(consensus.outliers.len(), 1);
+    let outlier = oracles.get(4).unwrap();
+    assert_eq!(consensus.outliers.get(0).unwrap(), outlier);
+
+    match consensus.payload {
+        FeedPayload::DrugPrice(data) => assert_eq!(data.price_minor, 1002),
+        _ => panic!("expected drug pricing payload"),
+    }
+
+    let honest = client.get_oracle(&oracles.get(0).unwrap()).unwrap();
+    let slashed = client.get_oracle(&outlier).unwrap();
+    assert!(slashed.reputation < 50);
+    assert!(honest.reputation > 50);
+
+    let invalid = client.try_set_aggregation_config(
+        &admin,
+        &FeedKind::RegulatoryUpdate,
+        &AggregationMethod::Median,
+        &0u32,
+    );
+    assert_eq!(invalid, Err(Ok(Error::InvalidFeedType)));
+}
+
+#[test]
+fn test_consensus_history_preserves_each_round() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 1);
+
+    let oracle = Address::generate(&env);
+    register_and_verify_oracle(&env, &client, &admin, &oracle, "https://history.example");
+
+    let feed_id = String::from_str(&env, "NDC:0078-0357-15:US");
+    let ndc = String::from_str(&env, "0078-0357-15");
+    let currency = String::from_str(&env, "USD");
+
+    let r1 = client.submit_drug_price(&oracle, &feed_id, &ndc, &currency, &1000i128, &40u32, &10u64);
+    let r2 = client.submit_drug_price(&oracle, &feed_id, &ndc, &currency, &1200i128, &35u32, &20u64);
+    assert_eq!((r1, r2), (1, 2));
+
+    let first = client
+        .get_consensus_at(&FeedKind::DrugPricing, &feed_id, &1u64)
+        .expect("round 1 should be archived");
+    match first.payload {
+        FeedPayload::DrugPrice(data) => assert_eq!(data.price_minor, 1000),
+        _ => panic!("expected drug pricing payload"),
+    }
+
+    let history = client.get_consensus_history(&FeedKind::DrugPricing, &feed_id, &0u32, &10u32);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().round_id, 1);
+    assert_eq!(history.get(1).unwrap().round_id, 2);
+    match history.get(1).unwrap().payload {
+        FeedPayload::DrugPrice(data) => assert_eq!(data.price_minor, 1200),
+        _ => panic!("expected drug pricing payload"),
+    }
+
+    let second_page = client.get_consensus_history(&FeedKind::DrugPricing, &feed_id, &1u32, &1u32);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().round_id, 2);
+}
+
+#[test]
+fn test_mixed_currency_round_is_rejected() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 3);
+
+    let oracle_1 = Address::generate(&env);
+    let oracle_2 = Address::generate(&env);
+    let oracle_3 = Address::generate(&env);
+    register_and_verify_oracle(&env, &client, &admin, &oracle_1, "https://usd.example");
+    register_and_verify_oracle(&env, &client, &admin, &oracle_2, "https://eur.example");
+    register_and_verify_oracle(&env, &client, &admin, &oracle_3, "https://usd-2.example");
+
+    let feed_id = String::from_str(&env, "NDC:0006-0749-31:INTL");
+    let ndc = String::from_str(&env, "0006-0749-31");
+    let usd = String::from_str(&env, "USD");
+    let eur = String::from_str(&env, "EUR");
+
+    client.submit_drug_price(&oracle_1, &feed_id, &ndc, &usd, &1100i128, &10u32, &1u64);
+    let mixed = client.try_submit_drug_price(&oracle_2, &feed_id, &ndc, &eur, &1000i128, &10u32, &2u64);
+    assert_eq!(mixed, Err(Ok(Error::CurrencyMismatch)));
+    assert!(client.get_consensus(&FeedKind::DrugPricing, &feed_id).is_none());
+
+    // The rejection happens at intake, so matching submissions still settle.
+    client.submit_drug_price(&oracle_2, &feed_id, &ndc, &usd, &1000i128, &10u32, &2u64);
+    client.submit_drug_price(&oracle_3, &feed_id, &ndc, &usd, &1050i128, &10u32, &3u64);
+    assert!(client.get_consensus(&FeedKind::DrugPricing, &feed_id).is_some());
+}
+
+#[test]
+fn test_fx_rate_normalizes_to_base_currency() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 2);
+    let usd = String::from_str(&env, "USD");
+    let eur = String::from_str(&env, "EUR");
+    client.set_base_currency(&admin, &usd);
+    client.set_fx_rate(&admin, &eur, &11_000i128);
+
+    let oracle_1 = Address::generate(&env);
+    let oracle_2 = Address::generate(&env);
+    register_and_verify_oracle(&env, &client, &admin, &oracle_1, "https://usd.example");
+    register_and_verify_oracle(&env, &client, &admin, &oracle_2, "https://eur.example");
+
+    let feed_id = String::from_str(&env, "NDC:0006-0749-31:INTL");
+    let ndc = String::from_str(&env, "0006-0749-31");
+    client.submit_drug_price(&oracle_1, &feed_id, &ndc, &usd, &1100i128, &10u32, &1u64);
+    client.submit_drug_price(&oracle_2, &feed_id, &ndc, &eur, &1000i128, &10u32, &2u64);
+
+    let consensus = client
+        .get_consensus(&FeedKind::DrugPricing, &feed_id)
+        .expect("consensus should exist");
+    match consensus.payload {
+        FeedPayload::DrugPrice(data) => {
+            assert_eq!(data.currency, usd);
+            assert_eq!(data.price_minor, 1100);
+        },
+        _ => panic!("expected drug pricing payload"),
+    }
+}
+
+#[test]
+fn test_feed_kind_thresholds_override_global_minimum() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 2);
+    client.update_feed_thresholds(&admin, &FeedKind::RegulatoryUpdate, &1u32);
+    client.update_feed_thresholds(&admin, &FeedKind::DrugPricing, &3u32);
+    assert_eq!(
+        client.try_update_feed_thresholds(&admin, &FeedKind::ClinicalTrial, &0u32),
+        Err(Ok(Error::InvalidData))
+    );
+
+    let mut oracles = Vec::<Address>::new(&env);
+    for _ in 0..3 {
+        let oracle = Address::generate(&env);
+        register_and_verify_oracle(&env, &client, &admin, &oracle, "https://quorum.example");
+        oracles.push_back(oracle);
+    }
+
+    let regulation_id = String::from_str(&env, "WHO-2026-GUIDANCE-3");
+    client.submit_regulatory_update(
+        &oracles.get(0).unwrap(),
+        &regulation_id,
+        &RegulatoryAuthority::WHO,
+        &RegulatoryStatus::GuidelineUpdate,
+        &String::from_str(&env, "Updated dosing guidance"),
+        &String::from_str(&env, "sha256:who-guidance-3"),
+        &50u64,
+    );
+    assert!(client
+        .get_consensus(&FeedKind::RegulatoryUpdate, &regulation_id)
+        .is_some());
+
+    let feed_id = String::from_str(&env, "NDC:0173-0682-20:US");
+    let ndc = String::from_str(&env, "0173-0682-20");
+    let currency = String::from_str(&env, "USD");
+    for (i, price) in [900i128, 910, 905].iter().enumerate() {
+        client.submit_drug_price(
+            &oracles.get(i as u32).unwrap(),
+            &feed_id,
+            &ndc,
+            &currency,
+            price,
+            &10u32,
+            &(60u64 + i as u64),
+        );
+        let finalized = client.get_consensus(&FeedKind::DrugPricing, &feed_id).is_some();
+        assert_eq!(finalized, i == 2);
+    }
+}
+
 #[test]
 fn test_clinical_trial_and_regulatory_feeds() {
     let env = Env::default();
@@ -292,6 +502,66 @@ fn test_treatment_outcome_feed_consensus() {
     assert_eq!(invalid, Err(Ok(Error::InvalidData)));
 }
 
+#[test]
+fn test_weighted_median_picks_cumulative_midpoint() {
+    let env = Env::default();
+    let values = Vec::from_array(&env, [300i128, 100, 200]);
+
+    let equal = Vec::from_array(&env, [1i128, 1, 1]);
+    assert_eq!(weighted_median(&values, &equal), 200);
+
+    // 300 carries more than half of the total weight.
+    let heavy_top = Vec::from_array(&env, [6i128, 2, 2]);
+    assert_eq!(weighted_median(&values, &heavy_top), 300);
+
+    // Midpoint lands exactly between 100 and 200.
+    let split = Vec::from_array(&env, [0i128, 5, 5]);
+    assert_eq!(weighted_median(&values, &split), 150);
+
+    assert_eq!(weighted_median(&Vec::new(&env), &Vec::new(&env)), 0);
+}
+
+#[test]
+fn test_treatment_outcome_uses_weighted_median() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 3);
+
+    let outcome_id = String::from_str(&env, "OUTCOME:T2D:MET:2026Q2");
+    let condition = String::from_str(&env, "E11.9");
+    let treatment = String::from_str(&env, "METFORMIN");
+    let rates: [(u32, u32, u32); 3] = [(7000, 900, 100), (7100, 950, 120), (9500, 200, 20)];
+
+    for (i, (improvement, readmission, mortality)) in rates.iter().enumerate() {
+        let oracle = Address::generate(&env);
+        register_and_verify_oracle(&env, &client, &admin, &oracle, "https://outcome.example");
+        client.submit_treatment_outcome(
+            &oracle,
+            &outcome_id,
+            &condition,
+            &treatment,
+            improvement,
+            readmission,
+            mortality,
+            &1000u32,
+            &(2000u64 + i as u64),
+        );
+    }
+
+    let consensus = client
+        .get_consensus(&FeedKind::TreatmentOutcome, &outcome_id)
+        .expect("treatment outcome consensus should exist");
+    match consensus.payload {
+        FeedPayload::TreatmentOutcome(data) => {
+            // The weighted mean would be 7866; the median ignores the high outlier.
+            assert_eq!(data.improvement_rate_bps, 7100);
+            assert_eq!(data.readmission_rate_bps, 900);
+            assert_eq!(data.mortality_rate_bps, 100);
+            assert_eq!(data.sample_size, 1000);
+        },
+        _ => panic!("expected treatment outcome payload"),
+    }
+}
+
 #[test]
 fn test_dispute_resolution_marks_consensus_and_penalizes_oracle() {
     let env = Env::default();
@@ -340,3 +610,470 @@ fn test_dispute_resolution_marks_consensus_and_penalizes_oracle() {
         .unwrap();
     assert!(consensus.disputed);
 }
+
+fn setup_staking(
+    env: &Env,
+    client: &HealthcareOracleNetworkClient,
+    admin: &Address,
+    min_stake: i128,
+) -> Address {
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.configure_staking(admin, &token_id, &min_stake);
+    token_id
+}
+
+#[test]
+fn test_registration_locks_minimum_stake() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 1);
+    let token_id = setup_staking(&env, &client, &admin, 500);
+    let token = token::Client::new(&env, &token_id);
+
+    let oracle = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&oracle, &1_000);
+    register_and_verify_oracle(&env, &client, &admin, &oracle, "https://staked.example");
+
+    assert_eq!(client.get_oracle(&oracle).unwrap().stake, 500);
+    assert_eq!(token.balance(&oracle), 500);
+    assert_eq!(token.balance(&client.address), 500);
+
+    let unfunded = Address::generate(&env);
+    let result = client.try_register_oracle(
+        &unfunded,
+        &String::from_str(&env, "https://unfunded.example"),
+        &SourceType::MarketAggregator,
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientStake)));
+}
+
+#[test]
+fn test_dispute_slashes_stake_and_blocks_withdrawal() {
+    let env = Env::default();
+    let (client, admin, arbiter) = setup_contract(&env, 1);
+    let token_id = setup_staking(&env, &client, &admin, 1_000);
+    let token = token::Client::new(&env, &token_id);
+
+    let oracle = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&oracle, &1_000);
+    register_and_verify_oracle(&env, &client, &admin, &oracle, "https://staked-reg.example");
+
+    let regulation_id = String::from_str(&env, "MHRA-2026-RECALL-7");
+    client.submit_regulatory_update(
+        &oracle,
+        &regulation_id,
+        &RegulatoryAuthority::MHRA,
+        &RegulatoryStatus::Recall,
+        &String::from_str(&env, "Batch recall"),
+        &String::from_str(&env, "sha256:mhra-recall-7"),
+        &1_200u64,
+    );
+
+    let dispute_id = client.raise_dispute(
+        &Address::generate(&env),
+        &FeedKind::RegulatoryUpdate,
+        &regulation_id,
+        &String::from_str(&env, "Recall not published by authority"),
+    );
+    assert_eq!(
+        client.try_withdraw_stake(&oracle),
+        Err(Ok(Error::InvalidDisputeState))
+    );
+
+    client.resolve_dispute(
+        &arbiter,
+        &dispute_id,
+        &true,
+        &String::from_str(&env, "Recall notice not found"),
+        &Some(oracle.clone()),
+    );
+
+    // A 15-point reputation penalty burns 15% of the locked stake.
+    let node = client.get_oracle(&oracle).unwrap();
+    assert_eq!(node.stake, 850);
+
+    assert_eq!(client.withdraw_stake(&oracle), 850);
+    assert_eq!(token.balance(&oracle), 850);
+    // The slashed 150 was burned, not left behind in the contract.
+    assert_eq!(token.balance(&client.address), 0);
+    let node = client.get_oracle(&oracle).unwrap();
+    assert_eq!(node.stake, 0);
+    assert!(!node.active);
+    assert_eq!(
+        client.try_verify_oracle(&admin, &oracle, &true, &true),
+        Err(Ok(Error::InsufficientStake))
+    );
+}
+
+#[test]
+fn test_withdrawal_blocked_while_round_is_open() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 2);
+    let token_id = setup_staking(&env, &client, &admin, 1_000);
+    let token = token::Client::new(&env, &token_id);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&first, &1_000);
+    token::StellarAssetClient::new(&env, &token_id).mint(&second, &1_000);
+    register_and_verify_oracle(&env, &client, &admin, &first, "https://first.example");
+    register_and_verify_oracle(&env, &client, &admin, &second, "https://second.example");
+
+    let regulation_id = String::from_str(&env, "EMA-2026-HOLD-4");
+    let submit = |oracle: &Address| {
+        client.submit_regulatory_update(
+            oracle,
+            &regulation_id,
+            &RegulatoryAuthority::EMA,
+            &RegulatoryStatus::TrialHold,
+            &String::from_str(&env, "Trial hold"),
+            &String::from_str(&env, "sha256:ema-hold-4"),
+            &400u64,
+        );
+    };
+
+    submit(&first);
+    assert_eq!(
+        client.try_withdraw_stake(&first),
+        Err(Ok(Error::SubmissionsPending))
+    );
+
+    // The second submission reaches quorum and finalizes the round.
+    submit(&second);
+    assert_eq!(client.withdraw_stake(&first), 1_000);
+    assert_eq!(token.balance(&first), 1_000);
+    assert_eq!(client.get_oracle(&first).unwrap().stake, 0);
+}
+
+#[test]
+fn test_stale_round_expires_and_releases_stake() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 2);
+    let token_id = setup_staking(&env, &client, &admin, 1_000);
+
+    let oracle = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_id).mint(&oracle, &1_000);
+    register_and_verify_oracle(&env, &client, &admin, &oracle, "https://lonely.example");
+
+    let regulation_id = String::from_str(&env, "PMDA-2026-APPROVAL-2");
+    let round_id = client.submit_regulatory_update(
+        &oracle,
+        &regulation_id,
+        &RegulatoryAuthority::PMDA,
+        &RegulatoryStatus::Approved,
+        &String::from_str(&env, "Approval"),
+        &String::from_str(&env, "sha256:pmda-approval-2"),
+        &100u64,
+    );
+    assert_eq!(
+        client.try_expire_round(&FeedKind::RegulatoryUpdate, &regulation_id),
+        Err(Ok(Error::RoundNotExpired))
+    );
+    assert_eq!(
+        client.try_withdraw_stake(&oracle),
+        Err(Ok(Error::SubmissionsPending))
+    );
+
+    env.ledger()
+        .set_timestamp(crate::utils::ROUND_EXPIRY_SECS + 1);
+    assert_eq!(
+        client.expire_round(&FeedKind::RegulatoryUpdate, &regulation_id),
+        round_id
+    );
+    assert_eq!(
+        client.try_expire_round(&FeedKind::RegulatoryUpdate, &regulation_id),
+        Err(Ok(Error::RoundNotFound))
+    );
+    assert_eq!(client.withdraw_stake(&oracle), 1_000);
+}
+
+fn setup_disputed_feed(
+    env: &Env,
+    client: &HealthcareOracleNetworkClient,
+    admin: &Address,
+    bond: i128,
+) -> (Address, Address, Address, String) {
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let treasury = Address::generate(env);
+    client.configure_dispute_bond(admin, &token_id, &bond, &treasury);
+
+    let oracle = Address::generate(env);
+    register_and_verify_oracle(env, client, admin, &oracle, "https://bonded.example");
+    let regulation_id = String::from_str(env, "PMDA-2026-HOLD-2");
+    client.submit_regulatory_update(
+        &oracle,
+        &regulation_id,
+        &RegulatoryAuthority::PMDA,
+        &RegulatoryStatus::TrialHold,
+        &String::from_str(env, "Trial hold"),
+        &String::from_str(env, "sha256:pmda-hold-2"),
+        &300u64,
+    );
+
+    let challenger = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_id).mint(&challenger, &bond);
+    (token_id, treasury, challenger, regulation_id)
+}
+
+#[test]
+fn test_valid_dispute_refunds_bond() {
+    let env = Env::default();
+    let (client, admin, arbiter) = setup_contract(&env, 1);
+    let (token_id, treasury, challenger, regulation_id) =
+        setup_disputed_feed(&env, &client, &admin, 200);
+    let token = token::Client::new(&env, &token_id);
+
+    let dispute_id = client.raise_dispute(
+        &challenger,
+        &FeedKind::RegulatoryUpdate,
+        &regulation_id,
+        &String::from_str(&env, "Hold lifted last week"),
+    );
+    assert_eq!(client.get_dispute(&dispute_id).unwrap().bond, 200);
+    assert_eq!(token.balance(&challenger), 0);
+
+    client.resolve_dispute(
+        &arbiter,
+        &dispute_id,
+        &true,
+        &String::from_str(&env, "Hold was lifted"),
+        &None,
+    );
+    assert_eq!(token.balance(&challenger), 200);
+    assert_eq!(token.balance(&treasury), 0);
+}
+
+#[test]
+fn test_invalid_dispute_forfeits_bond() {
+    let env = Env::default();
+    let (client, admin, arbiter) = setup_contract(&env, 1);
+    let (token_id, treasury, challenger, regulation_id) =
+        setup_disputed_feed(&env, &client, &admin, 200);
+    let token = token::Client::new(&env, &token_id);
+
+    let dispute_id = client.raise_dispute(
+        &challenger,
+        &FeedKind::RegulatoryUpdate,
+        &regulation_id,
+        &String::from_str(&env, "Looks wrong"),
+    );
+    client.resolve_dispute(
+        &arbiter,
+        &dispute_id,
+        &false,
+        &String::from_str(&env, "Hold confirmed by authority"),
+        &None,
+    );
+    assert_eq!(token.balance(&challenger), 0);
+    assert_eq!(token.balance(&treasury), 200);
+
+    let unbonded = client.try_raise_dispute(
+        &Address::generate(&env),
+        &FeedKind::RegulatoryUpdate,
+        &regulation_id,
+        &String::from_str(&env, "No funds"),
+    );
+    assert_eq!(unbonded, Err(Ok(Error::InsufficientBond)));
+}
+
+#[test]
+fn test_stale_oracle_is_pruned_and_excluded_from_confidence() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 2);
+    client.update_inactivity_timeout(&admin, &1_000u64);
+
+    let live_1 = Address::generate(&env);
+    let live_2 = Address::generate(&env);
+    let stale = Address::generate(&env);
+    register_and_verify_oracle(&env, &client, &admin, &live_1, "https://live-1.example");
+    register_and_verify_oracle(&env, &client, &admin, &live_2, "https://live-2.example");
+    register_and_verify_oracle(&env, &client, &admin, &stale, "https://stale.example");
+
+    env.ledger().set_timestamp(5_000);
+    let feed_id = String::from_str(&env, "NDC:0310-0751-39:US");
+    let ndc = String::from_str(&env, "0310-0751-39");
+    let currency = String::from_str(&env, "USD");
+    client.submit_drug_price(&live_1, &feed_id, &ndc, &currency, &700i128, &5u32, &4_990u64);
+    client.submit_drug_price(&live_2, &feed_id, &ndc, &currency, &710i128, &5u32, &4_995u64);
+
+    // Two live submitters out of two live oracles, despite three registered.
+    let consensus = client.get_consensus(&FeedKind::DrugPricing, &feed_id).unwrap();
+    assert_eq!(consensus.confidence_bps, 10_000);
+
+    assert_eq!(client.prune_inactive_oracles(&admin), 1);
+    assert!(!client.get_oracle(&stale).unwrap().active);
+    assert!(client.get_oracle(&live_1).unwrap().active);
+    assert_eq!(client.prune_inactive_oracles(&admin), 0);
+}
+
+#[test]
+fn test_feed_finalized_event_lists_subscribers() {
+    let env = Env::default();
+    let (client, admin, _arbiter) = setup_contract(&env, 1);
+
+    let oracle = Address::generate(&env);
+    register_and_verify_oracle(&env, &client, &admin, &oracle, "https://push.example");
+
+    let feed_id = String::from_str(&env, "NDC:0002-1433-80:US");
+    let sub_1 = Address::generate(&env);
+    let sub_2 = Address::generate(&env);
+    let leaver = Address::generate(&env);
+    client.subscribe_feed(&sub_1, &FeedKind::DrugPricing, &feed_id);
+    client.subscribe_feed(&sub_2, &FeedKind::DrugPricing, &feed_id);
+    client.subscribe_feed(&sub_2, &FeedKind::DrugPricing, &feed_id);
+    client.subscribe_feed(&leaver, &FeedKind::DrugPricing, &feed_id);
+    client.unsubscribe_feed(&leaver, &FeedKind::DrugPricing, &feed_id);
+    assert_eq!(client.get_feed_subscribers(&FeedKind::DrugPricing, &feed_id).len(), 2);
+
+    client.submit_drug_price(
+        &oracle,
+        &feed_id,
+        &String::from_str(&env, "0002-1433-80"),
+        &String::from_str(&env, "USD"),
+        &4200i128,
+        &12u32,
+        &77u64,
+    );
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let topic: Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(topic, Symbol::new(&env, "FeedFinalized"));
+    let (round_id, subscribers): (u64, Vec<Address>) = data.into_val(&env);
+    assert_eq!(round_id, 1);
+    assert_eq!(subscribers, Vec::from_array(&env, [sub_1, sub_2]));
+}
+
+fn setup_quorum_dispute(env: &Env) -> (HealthcareOracleNetworkClient<'_>, Vec<Address>, u64) {
+    let (client, admin, arbiter_1) = setup_contract(env, 1);
+    let arbiter_2 = Address::generate(env);
+    let arbiter_3 = Address::generate(env);
+    client.add_arbiter(&admin, &arbiter_2);
+    client.add_arbiter(&admin, &arbiter_3);
+    client.update_arbiter_quorum(&admin, &2u32);
+
+    let oracle = Address::generate(env);
+    register_and_verify_oracle(env, &client, &admin, &oracle, "https://quorum-reg.example");
+    let regulation_id = String::from_str(env, "CDSCO-2026-APPROVAL-9");
+    client.submit_regulatory_update(
+        &oracle,
+        &regulation_id,
+        &RegulatoryAuthority::CDSCO,
+        &RegulatoryStatus::Approved,
+        &String::from_str(env, "Generic approval"),
+        &String::from_str(env, "sha256:cdsco-approval-9"),
+        &400u64,
+    );
+    let dispute_id = client.raise_dispute(
+        &Address::generate(env),
+        &FeedKind::RegulatoryUpdate,
+        &regulation_id,
+        &String::from_str(env, "Approval was withdrawn"),
+    );
+    let arbiters = Vec::from_array(env, [arbiter_1, arbiter_2, arbiter_3]);
+    (client, arbiters, dispute_id)
+}
+
+#[test]
+fn test_dispute_resolves_when_quorum_reached() {
+    let env = Env::default();
+    let (client, arbiters, dispute_id) = setup_quorum_dispute(&env);
+
+    let status = client.vote_on_dispute(&arbiters.get(0).unwrap(), &dispute_id, &true);
+    assert_eq!(status, DisputeStatus::Open);
+    assert_eq!(
+        client.try_vote_on_dispute(&arbiters.get(0).unwrap(), &dispute_id, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    let status = client.vote_on_dispute(&arbiters.get(1).unwrap(), &dispute_id, &true);
+    assert_eq!(status, DisputeStatus::ResolvedValid);
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.votes_valid, 2);
+    assert_eq!(dispute.resolver, Some(arbiters.get(1).unwrap()));
+}
+
+#[test]
+fn test_split_vote_leaves_dispute_open() {
+    let env = Env::default();
+    let (client, arbiters, dispute_id) = setup_quorum_dispute(&env);
+
+    client.vote_on_dispute(&arbiters.get(0).unwrap(), &dispute_id, &true);
+    let status = client.vote_on_dispute(&arbiters.get(1).unwrap(), &dispute_id, &false);
+    assert_eq!(status, DisputeStatus::Open);
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!((dispute.votes_valid, dispute.votes_invalid), (1, 1));
+    assert!(dispute.resolved_at.is_none());
+}
+
+#[test]
+fn test_tied_dispute_closes_invalid_after_voting_window() {
+    let env = Env::default();
+    let (client, arbiters, dispute_id) = setup_quorum_dispute(&env);
+
+    client.vote_on_dispute(&arbiters.get(0).unwrap(), &dispute_id, &true);
+    client.vote_on_dispute(&arbiters.get(1).unwrap(), &dispute_id, &false);
+    assert_eq!(
+        client.try_close_expired_dispute(&dispute_id),
+        Err(Ok(Error::DisputeVotingOpen))
+    );
+
+    let opened_at = client.get_dispute(&dispute_id).unwrap().opened_at;
+    env.ledger()
+        .set_timestamp(opened_at + crate::utils::DISPUTE_VOTING_SECS);
+    assert_eq!(
+        client.try_vote_on_dispute(&arbiters.get(2).unwrap(), &dispute_id, &true),
+        Err(Ok(Error::DisputeVotingClosed))
+    );
+
+    let status = client.close_expired_dispute(&dispute_id);
+    assert_eq!(status, DisputeStatus::ResolvedInvalid);
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.resolver, Some(client.address.clone()));
+    assert_eq!(
+        client.try_close_expired_dispute(&dispute_id),
+        Err(Ok(Error::DisputeAlreadyResolved))
+    );
+}
+
+#[test]
+fn test_later_voter_cannot_swap_penalized_oracle() {
+    let env = Env::default();
+    let (client, arbiters, dispute_id) = setup_quorum_dispute(&env);
+    let key = client.get_dispute(&dispute_id).unwrap().key;
+    let oracle = client
+        .get_consensus(&key.kind, &key.feed_id)
+        .unwrap()
+        .submitters
+        .get(0)
+        .unwrap();
+    let reputation = client.get_oracle(&oracle).unwrap().reputation;
+
+    client.resolve_dispute(
+        &arbiters.get(0).unwrap(),
+        &dispute_id,
+        &true,
+        &String::from_str(&env, "Approval withdrawn"),
+        &Some(oracle.clone()),
+    );
+    assert_eq!(
+        client.try_resolve_dispute(
+            &arbiters.get(1).unwrap(),
+            &dispute_id,
+            &true,
+            &String::from_str(&env, "Different ruling"),
+            &Some(Address::generate(&env)),
+        ),
+        Err(Ok(Error::InvalidDisputeState))
+    );
+
+    let status = client.vote_on_dispute(&arbiters.get(1).unwrap(), &dispute_id, &true);
+    assert_eq!(status, DisputeStatus::ResolvedValid);
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.penalized_oracle, Some(oracle.clone()));
+    assert_eq!(dispute.ruling, Some(String::from_str(&env, "Approval withdrawn")));
+    assert_eq!(client.get_oracle(&oracle).unwrap().reputation, reputation - 15);
+}
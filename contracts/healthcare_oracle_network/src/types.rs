@@ -1,4 +1,4 @@
-use soroban_sdk::{BytesN, contracterror, contracttype, Address, String, Symbol, Vec};
+use soroban_sdk::{BytesN, contracterror, contracttype, Address, Map, String, Symbol, Vec};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -23,6 +23,16 @@ pub enum Error {
     InvalidFeedType = 17,
     ArbiterExists = 18,
     AlreadyReported = 19,
+    InsufficientStake = 20,
+    CurrencyMismatch = 21,
+    InsufficientBond = 22,
+    SubscriberLimitReached = 23,
+    AlreadyVoted = 24,
+    ReentrantCall = 25,
+    SubmissionsPending = 26,
+    RoundNotExpired = 27,
+    DisputeVotingClosed = 28,
+    DisputeVotingOpen = 29,
 }
 
 impl core::fmt::Display for Error {
@@ -47,6 +57,16 @@ impl core::fmt::Display for Error {
             Error::InvalidFeedType => write!(f, "invalid feed type"),
             Error::ArbiterExists => write!(f, "arbiter exists"),
             Error::AlreadyReported => write!(f, "already reported"),
+            Error::InsufficientStake => write!(f, "insufficient stake"),
+            Error::CurrencyMismatch => write!(f, "currency mismatch"),
+            Error::InsufficientBond => write!(f, "insufficient bond"),
+            Error::SubscriberLimitReached => write!(f, "subscriber limit reached"),
+            Error::AlreadyVoted => write!(f, "already voted"),
+            Error::ReentrantCall => write!(f, "reentrant call"),
+            Error::SubmissionsPending => write!(f, "submissions pending"),
+            Error::RoundNotExpired => write!(f, "round not expired"),
+            Error::DisputeVotingClosed => write!(f, "dispute voting closed"),
+            Error::DisputeVotingOpen => write!(f, "dispute voting still open"),
         }
     }
 }
@@ -99,6 +119,13 @@ pub enum DisputeStatus {
     ResolvedInvalid = 3,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AggregationMethod {
+    WeightedMean = 1,
+    Median = 2,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct FeedKey {
@@ -173,6 +200,8 @@ pub struct OracleNode {
     pub submissions: u32,
     pub disputes: u32,
     pub last_seen: u64,
+    /// Tokens locked at registration; reduced by reputation penalties.
+    pub stake: i128,
 }
 
 #[derive(Clone)]
@@ -181,9 +210,43 @@ pub struct Config {
     pub admin: Address,
     pub arbiters: Vec<Address>,
     pub min_submissions: u32,
+    /// Per-kind quorum overrides; kinds without an entry use `min_submissions`.
+    pub feed_min_submissions: Map<FeedKind, u32>,
     pub min_reputation: i128,
     pub max_drug_price_minor: i128,
     pub max_availability_units: u32,
+    /// Seconds since `last_seen` after which an oracle no longer counts as live.
+    pub inactivity_timeout: u64,
+    /// Matching arbiter votes needed to resolve a dispute either way.
+    pub arbiter_quorum: u32,
+}
+
+/// Per-feed-kind aggregation settings. Kinds without an entry use a
+/// reputation-weighted mean with no outlier filtering.
+#[derive(Clone)]
+#[contracttype]
+pub struct AggregationConfig {
+    pub method: AggregationMethod,
+    /// Maximum deviation from the preliminary median, in bps. 0 disables the filter.
+    pub outlier_threshold_bps: u32,
+}
+
+/// Token and minimum amount an operator must lock to register an oracle.
+#[derive(Clone)]
+#[contracttype]
+pub struct StakeConfig {
+    pub token: Address,
+    pub min_stake: i128,
+}
+
+/// Bond a challenger must lock to raise a dispute. Forfeited bonds go to
+/// `treasury`.
+#[derive(Clone)]
+#[contracttype]
+pub struct DisputeBondConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address,
 }
 
 #[derive(Clone)]
@@ -193,6 +256,8 @@ pub struct AggregationRound {
     pub started_at: u64,
     pub finalized: bool,
     pub submissions: u32,
+    /// Set when the round outlived ROUND_EXPIRY_SECS and was closed unfinalized.
+    pub expired: bool,
 }
 
 #[derive(Clone)]
@@ -203,6 +268,8 @@ pub struct ConsensusRecord {
     pub round_id: u64,
     pub finalized_at: u64,
     pub submitters: Vec<Address>,
+    /// Submitters dropped by the outlier filter; slashed on finalization.
+    pub outliers: Vec<Address>,
     pub confidence_bps: u32,
     pub disputed: bool,
 }
@@ -220,6 +287,13 @@ pub struct Dispute {
     pub resolved_at: Option<u64>,
     pub resolver: Option<Address>,
     pub ruling: Option<String>,
+    /// Amount locked by the challenger; 0 when bonding was not configured.
+    pub bond: i128,
+    pub bond_token: Option<Address>,
+    /// Oracle proposed for the reputation penalty if the dispute is upheld.
+    pub penalized_oracle: Option<Address>,
+    pub votes_valid: u32,
+    pub votes_invalid: u32,
 }
 
 #[derive(Clone)]
@@ -234,8 +308,24 @@ pub enum DataKey {
     LastSubmissionHash(FeedKey, Address),
     MisbehaviorReport(FeedKey, Address, Address),
     Consensus(FeedKey),
+    ConsensusHistory(FeedKey, u64),
+    AggregationConfig(FeedKind),
+    StakeConfig,
+    /// Running total of slashed stake burned from the stake token.
+    SlashedStake,
+    OpenDisputes(Address),
+    /// Submissions an oracle has in rounds that are not finalized yet.
+    PendingSubmissions(Address),
+    BaseCurrency,
+    FxRate(String),
+    /// Currency of the first drug-price submission in a round.
+    RoundCurrency(FeedKey, u64),
+    DisputeBondConfig,
+    FeedSubscribers(FeedKey),
+    DisputeVote(u64, Address),
     DisputeCount,
     Dispute(u64),
+    ReentrancyLock,
 }
 
 #[derive(Clone)]
@@ -1,9 +1,10 @@
-use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, RawVal, String, Symbol, TryFromVal, Val, Vec, xdr::ToXdr};
+use access_control_commons::ReentrancyGuard;
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, IntoVal, RawVal, String, Symbol, TryFromVal, Val, Vec, xdr::ToXdr};
 
 use crate::types::{
-    AggregationRound, ClinicalTrialData, CallCacheKey, Config, ConsensusRecord, DataKey,
-    DrugPriceData, Error, FeedKey, FeedKind, FeedPayload, OracleNode, RegulatoryUpdateData,
-    TreatmentOutcomeData,
+    AggregationConfig, AggregationMethod, AggregationRound, ClinicalTrialData, CallCacheKey,
+    Config, ConsensusRecord, DataKey, DrugPriceData, Error, FeedKey, FeedKind, FeedPayload,
+    OracleNode, RegulatoryUpdateData, StakeConfig, TreatmentOutcomeData,
 };
 
 #[must_use]
@@ -46,6 +47,15 @@ pub fn require_admin(env: &Env, admin: Address) -> Result<(), Error> {
     Ok(())
 }
 
+const REENTRANCY: ReentrancyGuard<DataKey> = ReentrancyGuard::new(DataKey::ReentrancyLock);
+
+/// Runs an external contract call under the contract-wide reentrancy lock.
+pub fn with_reentrancy_guard<R>(env: &Env, f: impl FnOnce() -> R) -> Result<R, Error> {
+    REENTRANCY
+        .with_guard(env, f)
+        .map_err(|_| Error::ReentrantCall)
+}
+
 pub fn make_cross_contract_cache_key(
     env: &Env,
     contract: &Address,
@@ -78,6 +88,40 @@ pub fn invoke_contract_cached<
     result
 }
 
+/// Reputation penalty applied to submitters rejected by the outlier filter.
+const OUTLIER_PENALTY: i128 = 10;
+/// Share of an oracle's stake burned per reputation point lost, in bps.
+const STAKE_SLASH_BPS_PER_POINT: i128 = 100;
+/// Seconds a round may stay open before it can be expired unfinalized.
+pub const ROUND_EXPIRY_SECS: u64 = 86_400;
+/// Seconds arbiters have to reach quorum on a dispute before it can be closed
+/// with the default ruling.
+pub const DISPUTE_VOTING_SECS: u64 = 604_800;
+
+pub fn min_submissions_for(cfg: &Config, kind: FeedKind) -> u32 {
+    cfg.feed_min_submissions
+        .get(kind)
+        .unwrap_or(cfg.min_submissions)
+}
+
+pub fn min_stake(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get::<DataKey, StakeConfig>(&DataKey::StakeConfig)
+        .map(|cfg| cfg.min_stake)
+        .unwrap_or(0)
+}
+
+pub fn read_aggregation_config(env: &Env, kind: FeedKind) -> AggregationConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::AggregationConfig(kind))
+        .unwrap_or(AggregationConfig {
+            method: AggregationMethod::WeightedMean,
+            outlier_threshold_bps: 0,
+        })
+}
+
 #[must_use]
 pub fn require_verified_oracle(env: &Env, operator: Address) -> Result<Config, Error> {
     let cfg: Config = env
@@ -167,8 +211,10 @@ pub fn submit_payload(
     if env.storage().persistent().has(&submission_key) {
         return Err(Error::SubmissionAlreadyExists);
     }
+    check_round_currency(&env, &key, round_id, &payload)?;
 
     env.storage().persistent().set(&submission_key, &payload);
+    adjust_pending_submissions(&env, &operator, true);
 
     let mut round: AggregationRound = env
         .storage()
@@ -187,7 +233,7 @@ pub fn submit_payload(
         .persistent()
         .set(&DataKey::Oracle(operator), &node);
 
-    if round.submissions >= cfg.min_submissions {
+    if round.submissions >= min_submissions_for(&cfg, key.kind) {
         let _ = finalize_round(env.clone(), key.clone(), round_id)?;
     }
 
@@ -221,51 +267,65 @@ pub fn finalize_round(env: Env, key: FeedKey, round_id: u64) -> Result<Consensus
     let mut submitters = Vec::<Address>::new(&env);
     let mut payloads = Vec::<FeedPayload>::new(&env);
     let mut weights = Vec::<i128>::new(&env);
+    let now = env.ledger().timestamp();
+    let mut live_oracles = 0u32;
 
     let mut i = 0;
     while i < all_oracles.len() {
         let oracle = all_oracles.get(i).unwrap();
         let node = read_oracle(&env, oracle.clone())?;
+        if node.active && is_live(&node, now, cfg.inactivity_timeout) {
+            live_oracles += 1;
+        }
 
-        if node.verified && node.active && node.reputation >= cfg.min_reputation {
-            let submission_key = DataKey::Submission(key.clone(), round_id, oracle.clone());
-            if env.storage().persistent().has(&submission_key) {
-                let payload: FeedPayload = env
-                    .storage()
-                    .persistent()
-                    .get(&submission_key)
-                    .ok_or(Error::InvalidData)?;
-                submitters.push_back(oracle);
-                payloads.push_back(payload);
-                weights.push_back(if node.reputation > 0 {
-                    node.reputation
-                } else {
-                    1
-                });
-            }
+        let submission_key = DataKey::Submission(key.clone(), round_id, oracle.clone());
+        let submitted = env.storage().persistent().has(&submission_key);
+        if submitted {
+            adjust_pending_submissions(&env, &oracle, false);
+        }
+        if submitted && node.verified && node.active && node.reputation >= cfg.min_reputation {
+            let payload: FeedPayload = env
+                .storage()
+                .persistent()
+                .get(&submission_key)
+                .ok_or(Error::InvalidData)?;
+            submitters.push_back(oracle);
+            payloads.push_back(payload);
+            weights.push_back(if node.reputation > 0 {
+                node.reputation
+            } else {
+                1
+            });
         }
         i += 1;
     }
 
-    if submitters.len() < cfg.min_submissions {
+    if submitters.len() < min_submissions_for(&cfg, key.kind) {
         return Err(Error::InsufficientSubmissions);
     }
 
+    let payloads = normalize_currencies(&env, key.kind, payloads)?;
+    let agg_cfg = read_aggregation_config(&env, key.kind);
+    let (submitters, payloads, weights, outliers) =
+        reject_outliers(&env, &agg_cfg, submitters, payloads, weights)?;
+
     let aggregated = aggregate_payload(
         &env,
         key.kind,
+        agg_cfg.method,
         payloads.clone(),
         weights.clone(),
         key.feed_id.clone(),
     )?;
 
-    let confidence_bps = compute_confidence_bps(submitters.len(), all_oracles.len());
+    let confidence_bps = compute_confidence_bps(submitters.len(), live_oracles);
     let consensus = ConsensusRecord {
         key: key.clone(),
         payload: aggregated,
         round_id,
         finalized_at: env.ledger().timestamp(),
         submitters: submitters.clone(),
+        outliers,
         confidence_bps,
         disputed: false,
     };
@@ -273,6 +333,9 @@ pub fn finalize_round(env: Env, key: FeedKey, round_id: u64) -> Result<Consensus
     env.storage()
         .persistent()
         .set(&DataKey::Consensus(key.clone()), &consensus);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ConsensusHistory(key.clone(), round_id), &consensus);
 
     round.finalized = true;
     env.storage()
@@ -284,12 +347,215 @@ pub fn finalize_round(env: Env, key: FeedKey, round_id: u64) -> Result<Consensus
     env.events()
         .publish((symbol_short!("consens"), round_id), confidence_bps);
 
+    // Subscribers are capped per feed, so the list stays small enough to
+    // ship in the event body for cheap off-chain filtering.
+    let subscribers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FeedSubscribers(consensus.key.clone()))
+        .unwrap_or(Vec::new(&env));
+    env.events().publish(
+        (
+            Symbol::new(&env, "FeedFinalized"),
+            consensus.key.kind,
+            consensus.key.feed_id.clone(),
+        ),
+        (round_id, subscribers),
+    );
+
     Ok(consensus)
 }
 
+/// Rejects a drug-price submission that `normalize_currencies` could not
+/// fold into the round, so a stray currency can no longer wedge finalization.
+/// Without a base currency the round's first submission fixes its currency.
+pub fn check_round_currency(
+    env: &Env,
+    key: &FeedKey,
+    round_id: u64,
+    payload: &FeedPayload,
+) -> Result<(), Error> {
+    let value = match payload {
+        FeedPayload::DrugPrice(value) => value,
+        _ => return Ok(()),
+    };
+
+    let base: Option<String> = env.storage().instance().get(&DataKey::BaseCurrency);
+    if let Some(base_currency) = base {
+        if value.currency != base_currency
+            && !env
+                .storage()
+                .instance()
+                .has(&DataKey::FxRate(value.currency.clone()))
+        {
+            return Err(Error::CurrencyMismatch);
+        }
+        return Ok(());
+    }
+
+    let currency_key = DataKey::RoundCurrency(key.clone(), round_id);
+    match env.storage().persistent().get::<_, String>(&currency_key) {
+        Some(currency) if currency != value.currency => Err(Error::CurrencyMismatch),
+        Some(_) => Ok(()),
+        None => {
+            env.storage()
+                .persistent()
+                .set(&currency_key, &value.currency);
+            Ok(())
+        },
+    }
+}
+
+/// Brings drug-price submissions to a single currency. With a base currency
+/// configured, other currencies are converted at their admin-set FX rate;
+/// otherwise every submission must already share the first one's currency.
+pub fn normalize_currencies(
+    env: &Env,
+    kind: FeedKind,
+    payloads: Vec<FeedPayload>,
+) -> Result<Vec<FeedPayload>, Error> {
+    if kind != FeedKind::DrugPricing || payloads.len() == 0 {
+        return Ok(payloads);
+    }
+
+    let base: Option<String> = env.storage().instance().get(&DataKey::BaseCurrency);
+    let mut normalized = Vec::<FeedPayload>::new(env);
+    let mut expected: Option<String> = base.clone();
+
+    let mut i = 0;
+    while i < payloads.len() {
+        let mut value = match payloads.get(i).unwrap() {
+            FeedPayload::DrugPrice(value) => value,
+            _ => return Err(Error::InvalidFeedType),
+        };
+
+        match (&base, &expected) {
+            (Some(base_currency), _) => {
+                if value.currency != *base_currency {
+                    let rate: i128 = env
+                        .storage()
+                        .instance()
+                        .get(&DataKey::FxRate(value.currency.clone()))
+                        .ok_or(Error::CurrencyMismatch)?;
+                    value.price_minor = value.price_minor.saturating_mul(rate) / 10_000;
+                    value.currency = base_currency.clone();
+                }
+            },
+            (None, Some(currency)) => {
+                if value.currency != *currency {
+                    return Err(Error::CurrencyMismatch);
+                }
+            },
+            (None, None) => expected = Some(value.currency.clone()),
+        }
+
+        normalized.push_back(FeedPayload::DrugPrice(value));
+        i += 1;
+    }
+
+    Ok(normalized)
+}
+
+/// Drops drug-price submissions whose `price_minor` deviates from the
+/// preliminary median by more than `outlier_threshold_bps`. Returns the
+/// surviving submitters, payloads and weights plus the rejected submitters.
+pub fn reject_outliers(
+    env: &Env,
+    agg_cfg: &AggregationConfig,
+    submitters: Vec<Address>,
+    payloads: Vec<FeedPayload>,
+    weights: Vec<i128>,
+) -> Result<(Vec<Address>, Vec<FeedPayload>, Vec<i128>, Vec<Address>), Error> {
+    if agg_cfg.outlier_threshold_bps == 0 {
+        return Ok((submitters, payloads, weights, Vec::new(env)));
+    }
+
+    let mut prices = Vec::<i128>::new(env);
+    let mut i = 0;
+    while i < payloads.len() {
+        match payloads.get(i).unwrap() {
+            FeedPayload::DrugPrice(value) => prices.push_back(value.price_minor),
+            _ => return Err(Error::InvalidFeedType),
+        }
+        i += 1;
+    }
+    let preliminary = median_i128(&prices);
+
+    let mut kept_submitters = Vec::<Address>::new(env);
+    let mut kept_payloads = Vec::<FeedPayload>::new(env);
+    let mut kept_weights = Vec::<i128>::new(env);
+    let mut outliers = Vec::<Address>::new(env);
+    let threshold = agg_cfg.outlier_threshold_bps as i128;
+
+    let mut i = 0;
+    while i < submitters.len() {
+        let submitter = submitters.get(i).unwrap();
+        if deviation_bps(prices.get(i).unwrap(), preliminary) > threshold {
+            outliers.push_back(submitter);
+        } else {
+            kept_submitters.push_back(submitter);
+            kept_payloads.push_back(payloads.get(i).unwrap());
+            kept_weights.push_back(weights.get(i).unwrap_or(1));
+        }
+        i += 1;
+    }
+
+    if kept_submitters.len() == 0 {
+        return Err(Error::InsufficientSubmissions);
+    }
+
+    Ok((kept_submitters, kept_payloads, kept_weights, outliers))
+}
+
+/// Median of `values`; the mean of the two middle values for even lengths.
+pub fn median_i128(values: &Vec<i128>) -> i128 {
+    let mut sorted = values.clone();
+    let n = sorted.len();
+    if n == 0 {
+        return 0;
+    }
+
+    // Insertion sort: submission counts are bounded by the oracle set.
+    let mut i = 1;
+    while i < n {
+        let current = sorted.get(i).unwrap();
+        let mut j = i;
+        while j > 0 && sorted.get(j - 1).unwrap() > current {
+            sorted.set(j, sorted.get(j - 1).unwrap());
+            j -= 1;
+        }
+        sorted.set(j, current);
+        i += 1;
+    }
+
+    let mid = n / 2;
+    if n % 2 == 1 {
+        sorted.get(mid).unwrap()
+    } else {
+        sorted
+            .get(mid - 1)
+            .unwrap()
+            .saturating_add(sorted.get(mid).unwrap())
+            / 2
+    }
+}
+
+pub fn deviation_bps(value: i128, reference: i128) -> i128 {
+    if reference <= 0 {
+        return 0;
+    }
+    let diff = if value > reference {
+        value.saturating_sub(reference)
+    } else {
+        reference.saturating_sub(value)
+    };
+    diff.saturating_mul(10_000) / reference
+}
+
 pub fn aggregate_payload(
     env: &Env,
     kind: FeedKind,
+    method: AggregationMethod,
     payloads: Vec<FeedPayload>,
     weights: Vec<i128>,
     feed_id: String,
@@ -312,6 +578,8 @@ pub fn aggregate_payload(
             let mut observed_at = 0u64;
             let mut ndc_code = String::from_str(env, "");
             let mut currency = String::from_str(env, "");
+            let mut prices = Vec::<i128>::new(env);
+            let mut availabilities = Vec::<i128>::new(env);
 
             let mut i = 0;
             while i < payloads.len() {
@@ -322,6 +590,8 @@ pub fn aggregate_payload(
                             ndc_code = value.ndc_code.clone();
                             currency = value.currency.clone();
                         }
+                        prices.push_back(value.price_minor);
+                        availabilities.push_back(value.availability_units as i128);
                         price_weighted =
                             price_weighted.saturating_add(value.price_minor.saturating_mul(weight));
                         availability_weighted = availability_weighted.saturating_add(
@@ -336,11 +606,19 @@ pub fn aggregate_payload(
                 i += 1;
             }
 
+            let (price_minor, availability) = match method {
+                AggregationMethod::Median => (median_i128(&prices), median_i128(&availabilities)),
+                AggregationMethod::WeightedMean => (
+                    price_weighted / sum_weight,
+                    availability_weighted / sum_weight,
+                ),
+            };
+
             Ok(FeedPayload::DrugPrice(DrugPriceData {
                 ndc_code,
                 currency,
-                price_minor: price_weighted / sum_weight,
-                availability_units: (availability_weighted / sum_weight) as u32,
+                price_minor,
+                availability_units: availability as u32,
                 observed_at,
             }))
         },
@@ -413,9 +691,9 @@ pub fn aggregate_payload(
             }
         },
         FeedKind::TreatmentOutcome => {
-            let mut improvement_weighted = 0i128;
-            let mut readmission_weighted = 0i128;
-            let mut mortality_weighted = 0i128;
+            let mut improvement = Vec::<i128>::new(env);
+            let mut readmission = Vec::<i128>::new(env);
+            let mut mortality = Vec::<i128>::new(env);
             let mut sample_weighted = 0i128;
             let mut reported_at = 0u64;
             let mut condition_code = String::from_str(env, "");
@@ -430,15 +708,9 @@ pub fn aggregate_payload(
                             condition_code = value.condition_code.clone();
                             treatment_code = value.treatment_code.clone();
                         }
-                        improvement_weighted = improvement_weighted.saturating_add(
-                            (value.improvement_rate_bps as i128).saturating_mul(weight),
-                        );
-                        readmission_weighted = readmission_weighted.saturating_add(
-                            (value.readmission_rate_bps as i128).saturating_mul(weight),
-                        );
-                        mortality_weighted = mortality_weighted.saturating_add(
-                            (value.mortality_rate_bps as i128).saturating_mul(weight),
-                        );
+                        improvement.push_back(value.improvement_rate_bps as i128);
+                        readmission.push_back(value.readmission_rate_bps as i128);
+                        mortality.push_back(value.mortality_rate_bps as i128);
                         sample_weighted = sample_weighted
                             .saturating_add((value.sample_size as i128).saturating_mul(weight));
                         if value.reported_at > reported_at {
@@ -454,9 +726,9 @@ pub fn aggregate_payload(
                 outcome_id: feed_id,
                 condition_code,
                 treatment_code,
-                improvement_rate_bps: (improvement_weighted / sum_weight) as u32,
-                readmission_rate_bps: (readmission_weighted / sum_weight) as u32,
-                mortality_rate_bps: (mortality_weighted / sum_weight) as u32,
+                improvement_rate_bps: weighted_median(&improvement, &weights) as u32,
+                readmission_rate_bps: weighted_median(&readmission, &weights) as u32,
+                mortality_rate_bps: weighted_median(&mortality, &weights) as u32,
                 sample_size: (sample_weighted / sum_weight) as u32,
                 reported_at,
             }))
@@ -464,6 +736,59 @@ pub fn aggregate_payload(
     }
 }
 
+/// Value at the cumulative-weight midpoint of `values` sorted ascending.
+/// When the midpoint falls exactly between two values, their mean is used,
+/// so equal weights reduce to the ordinary median.
+pub fn weighted_median(values: &Vec<i128>, weights: &Vec<i128>) -> i128 {
+    let n = values.len();
+    if n == 0 {
+        return 0;
+    }
+
+    let mut sorted_values = values.clone();
+    let mut sorted_weights = weights.clone();
+    let mut i = 1;
+    while i < n {
+        let value = sorted_values.get(i).unwrap();
+        let weight = sorted_weights.get(i).unwrap_or(1);
+        let mut j = i;
+        while j > 0 && sorted_values.get(j - 1).unwrap() > value {
+            sorted_values.set(j, sorted_values.get(j - 1).unwrap());
+            sorted_weights.set(j, sorted_weights.get(j - 1).unwrap_or(1));
+            j -= 1;
+        }
+        sorted_values.set(j, value);
+        sorted_weights.set(j, weight);
+        i += 1;
+    }
+
+    let mut total = 0i128;
+    let mut i = 0;
+    while i < n {
+        total = total.saturating_add(sorted_weights.get(i).unwrap_or(1));
+        i += 1;
+    }
+
+    let mut cumulative = 0i128;
+    let mut i = 0;
+    while i < n {
+        cumulative = cumulative.saturating_add(sorted_weights.get(i).unwrap_or(1));
+        let doubled = cumulative.saturating_mul(2);
+        if doubled == total && i + 1 < n {
+            return sorted_values
+                .get(i)
+                .unwrap()
+                .saturating_add(sorted_values.get(i + 1).unwrap())
+                / 2;
+        }
+        if doubled >= total {
+            return sorted_values.get(i).unwrap();
+        }
+        i += 1;
+    }
+    sorted_values.get(n - 1).unwrap()
+}
+
 pub fn reward_and_slash(
     env: &Env,
     consensus: &ConsensusRecord,
@@ -478,6 +803,17 @@ pub fn reward_and_slash(
         adjust_reputation(env, submitter, delta, delta < 0)?;
         i += 1;
     }
+
+    let mut i = 0;
+    while i < consensus.outliers.len() {
+        slash_oracle(
+            env,
+            consensus.outliers.get(i).unwrap(),
+            OUTLIER_PENALTY,
+            String::from_str(env, "Outlier submission rejected"),
+        )?;
+        i += 1;
+    }
     Ok(())
 }
 
@@ -563,6 +899,15 @@ pub fn adjust_reputation(
     if node.reputation < 0 {
         node.reputation = 0;
     }
+    let mut slashed = 0i128;
+    if delta < 0 && node.stake > 0 {
+        let slash_bps = delta
+            .saturating_neg()
+            .saturating_mul(STAKE_SLASH_BPS_PER_POINT)
+            .min(10_000);
+        slashed = node.stake.saturating_mul(slash_bps) / 10_000;
+        node.stake = node.stake.saturating_sub(slashed);
+    }
     if is_dispute {
         node.disputes = node.disputes.saturating_add(1);
     }
@@ -570,14 +915,73 @@ pub fn adjust_reputation(
     env.storage()
         .persistent()
         .set(&DataKey::Oracle(operator), &node);
+
+    if slashed > 0 {
+        burn_slashed_stake(env, slashed)?;
+    }
     Ok(())
 }
 
+/// Burns slashed stake from the contract's balance of the stake token so it
+/// can never be paid back out, and adds it to the `SlashedStake` total.
+fn burn_slashed_stake(env: &Env, amount: i128) -> Result<(), Error> {
+    let cfg: StakeConfig = match env.storage().instance().get(&DataKey::StakeConfig) {
+        Some(cfg) => cfg,
+        None => return Ok(()),
+    };
+    let burned: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::SlashedStake)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::SlashedStake, &burned.saturating_add(amount));
+
+    with_reentrancy_guard(env, || {
+        token::Client::new(env, &cfg.token).burn(&env.current_contract_address(), &amount)
+    })?;
+    env.events().publish((symbol_short!("burn"),), amount);
+    Ok(())
+}
+
+/// Tracks per-oracle submissions in unfinalized rounds so stake cannot be
+/// withdrawn before the round settles.
+pub fn adjust_pending_submissions(env: &Env, operator: &Address, submitted: bool) {
+    let key = DataKey::PendingSubmissions(operator.clone());
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    let updated = if submitted {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    env.storage().persistent().set(&key, &updated);
+}
+
+/// Tracks per-oracle open disputes so stake cannot be withdrawn mid-dispute.
+pub fn adjust_open_disputes(env: &Env, submitters: &Vec<Address>, opened: bool) {
+    let mut i = 0;
+    while i < submitters.len() {
+        let key = DataKey::OpenDisputes(submitters.get(i).unwrap());
+        let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let updated = if opened {
+            count.saturating_add(1)
+        } else {
+            count.saturating_sub(1)
+        };
+        env.storage().persistent().set(&key, &updated);
+        i += 1;
+    }
+}
+
 #[must_use]
 pub fn ensure_active_round(env: &Env, key: FeedKey) -> Result<u64, Error> {
     if let Ok(round_id) = active_round_id(env, key.clone()) {
         return Ok(round_id);
     }
+    // A stale round releases its pending counts before a new one opens; with
+    // no open round left this is a no-op.
+    let _ = expire_round(env, key.clone());
 
     let next_id = env
         .storage()
@@ -591,6 +995,7 @@ pub fn ensure_active_round(env: &Env, key: FeedKey) -> Result<u64, Error> {
         started_at: env.ledger().timestamp(),
         finalized: false,
         submissions: 0,
+        expired: false,
     };
 
     env.storage()
@@ -617,13 +1022,71 @@ pub fn active_round_id(env: &Env, key: FeedKey) -> Result<u64, Error> {
         .get(&DataKey::Round(key, latest))
         .ok_or(Error::RoundNotFound)?;
 
-    if round.finalized {
+    if round.finalized || round.expired || is_round_stale(&round, env.ledger().timestamp()) {
         return Err(Error::RoundNotFound);
     }
 
     Ok(latest)
 }
 
+pub fn is_round_stale(round: &AggregationRound, now: u64) -> bool {
+    now > round.started_at.saturating_add(ROUND_EXPIRY_SECS)
+}
+
+/// Closes the feed's latest round unfinalized once it has been open longer
+/// than ROUND_EXPIRY_SECS, releasing its submitters' pending counts so their
+/// stake can be withdrawn. Returns the expired round id.
+#[must_use]
+pub fn expire_round(env: &Env, key: FeedKey) -> Result<u64, Error> {
+    let latest: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RoundCounter(key.clone()))
+        .ok_or(Error::RoundNotFound)?;
+    let mut round: AggregationRound = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Round(key.clone(), latest))
+        .ok_or(Error::RoundNotFound)?;
+
+    if round.finalized || round.expired {
+        return Err(Error::RoundNotFound);
+    }
+    if !is_round_stale(&round, env.ledger().timestamp()) {
+        return Err(Error::RoundNotExpired);
+    }
+
+    let all_oracles: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::OracleList)
+        .unwrap_or(Vec::new(env));
+    let mut i = 0;
+    while i < all_oracles.len() {
+        let oracle = all_oracles.get(i).unwrap();
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Submission(key.clone(), latest, oracle.clone()))
+        {
+            adjust_pending_submissions(env, &oracle, false);
+        }
+        i += 1;
+    }
+
+    round.expired = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Round(key, latest), &round);
+    env.events()
+        .publish((symbol_short!("expired"), latest), round.submissions);
+    Ok(latest)
+}
+
+pub fn is_live(node: &OracleNode, now: u64, inactivity_timeout: u64) -> bool {
+    now.saturating_sub(node.last_seen) <= inactivity_timeout
+}
+
 pub fn compute_confidence_bps(submitters: u32, total_oracles: u32) -> u32 {
     if total_oracles == 0 {
         return 0;
@@ -7,13 +7,21 @@ mod test;
 
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env,
-    String, Symbol, Vec,
+    IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
 
 const ROLE_OPERATOR: u32 = 1;
 const ROLE_AUDITOR: u32 = 2;
 const ROLE_RECOVERY: u32 = 4;
 const ALL_ROLES: u32 = ROLE_OPERATOR | ROLE_AUDITOR | ROLE_RECOVERY;
+/// Capacity charged against a target for each artifact replica it stores.
+const ARTIFACT_CAPACITY_UNITS: u64 = 1;
+/// Upper bound on the targets considered when selecting replicas for a backup.
+const MAX_SELECTION_CANDIDATES: u32 = 32;
+/// Longest parent chain an incremental backup may build on.
+const MAX_BACKUP_CHAIN_DEPTH: u32 = 32;
+/// Largest page returned by the paginated listing functions.
+const MAX_PAGE_SIZE: u32 = 50;
 
 const ADMIN: Symbol = symbol_short!("ADMIN");
 const PAUSED: Symbol = symbol_short!("PAUSED");
@@ -25,6 +33,9 @@ const NEXT_TST: Symbol = symbol_short!("NEXT_TST");
 const NEXT_RST: Symbol = symbol_short!("NEXT_RST");
 const LAST_RUN: Symbol = symbol_short!("LAST_RUN");
 const NEXT_RUN: Symbol = symbol_short!("NEXT_RUN");
+const ACT_COST: Symbol = symbol_short!("ACT_COST");
+const MISSED: Symbol = symbol_short!("MISSED");
+const KEY_VER: Symbol = symbol_short!("KEY_VER");
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -98,6 +109,8 @@ pub enum AlertKind {
     CostThresholdExceeded,
     ScheduleMissed,
     RecoveryDrillFailed,
+    /// Backups have failed `failure_escalation_streak` times in a row.
+    FailureStreak,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -109,11 +122,37 @@ pub enum RestoreStatus {
     Rejected,
 }
 
+/// How long an artifact is kept before cleanup may archive it. Each class
+/// maps to a policy duration; `Permanent` artifacts are never auto-archived.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum RetentionClass {
+    ShortTerm,
+    Standard,
+    LongTerm,
+    Permanent,
+}
+
+/// Fine-grained permissions that can be granted on top of the coarse role
+/// mask.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Capability {
+    RegisterTarget,
+    RunBackup,
+    RequestRestore,
+    ApproveRestore,
+    ResolveAlert,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct BackupPolicy {
     pub interval_seconds: u64,
+    /// Retention of `Standard` artifacts.
     pub retention_seconds: u64,
+    pub short_term_retention_seconds: u64,
+    pub long_term_retention_seconds: u64,
     pub max_active_backups: u32,
     pub min_targets_per_backup: u32,
     pub min_region_count: u32,
@@ -122,6 +161,15 @@ pub struct BackupPolicy {
     pub encryption_required: bool,
     pub auto_cleanup: bool,
     pub min_restore_approvals: u32,
+    pub restore_request_ttl: u64,
+    pub max_target_failures: u32,
+    pub cost_alert_threshold: u32,
+    pub schedule_grace_seconds: u64,
+    /// Matching replicas needed for `verify_backup_integrity` to pass; 0
+    /// requires every replica.
+    pub integrity_quorum: u32,
+    /// Consecutive failures that open a `FailureStreak` alert; 0 disables it.
+    pub failure_escalation_streak: u32,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -135,6 +183,7 @@ pub struct BackupTarget {
     pub encrypted_only: bool,
     pub cost_weight: u32,
     pub max_capacity_units: u64,
+    pub used_capacity_units: u64,
     pub failure_count: u32,
 }
 
@@ -149,6 +198,7 @@ pub struct BackupArtifact {
     pub encrypted: bool,
     pub created_at: u64,
     pub expires_at: u64,
+    pub retention_class: RetentionClass,
     pub target_ids: Vec<u32>,
     pub region_count: u32,
     pub total_cost_weight: u32,
@@ -156,6 +206,7 @@ pub struct BackupArtifact {
     pub last_verified_at: u64,
     pub last_restored_at: u64,
     pub restore_drill_passed: bool,
+    pub parent_artifact_id: Option<u64>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -216,6 +267,25 @@ pub struct RestoreRequest {
     pub approvals: Vec<Address>,
     pub status: RestoreStatus,
     pub executed_at: u64,
+    pub expires_at: u64,
+}
+
+/// Pre-flight findings for a restore request; `viable` is set only when
+/// every other check passes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RestoreValidation {
+    pub request_id: u64,
+    pub approved: bool,
+    pub expired: bool,
+    pub artifact_found: bool,
+    pub artifact_archived: bool,
+    /// Every replica matches, or at least `integrity_quorum` of them when a
+    /// quorum is set.
+    pub replicas_intact: bool,
+    /// Every ancestor of an incremental artifact is present and unarchived.
+    pub chain_intact: bool,
+    pub viable: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -243,6 +313,7 @@ pub struct CleanupReport {
 pub enum DataKey {
     Policy,
     Roles(Address),
+    Capabilities(Address),
     Target(u32),
     TargetIds,
     Artifact(u64),
@@ -278,6 +349,9 @@ pub enum Error {
     AlreadyExecuted = 16,
     DuplicateApproval = 17,
     CostLimitExceeded = 18,
+    RestoreRequestExpired = 19,
+    BrokenBackupChain = 20,
+    QuorumNotMet = 21,
 }
 
 impl core::fmt::Display for Error {
@@ -301,10 +375,23 @@ impl core::fmt::Display for Error {
             Error::AlreadyExecuted => write!(f, "already executed"),
             Error::DuplicateApproval => write!(f, "duplicate approval"),
             Error::CostLimitExceeded => write!(f, "cost limit exceeded"),
+            Error::RestoreRequestExpired => write!(f, "restore request expired"),
+            Error::BrokenBackupChain => write!(f, "broken backup chain"),
+            Error::QuorumNotMet => write!(f, "quorum not met"),
         }
     }
 }
 
+/// Severity of a `BackupFailure` alert after `streak` consecutive failures.
+pub fn get_alert_severity_for_streak(streak: u32) -> AlertSeverity {
+    match streak {
+        0 | 1 => AlertSeverity::Low,
+        2 => AlertSeverity::Medium,
+        3 => AlertSeverity::High,
+        _ => AlertSeverity::Critical,
+    }
+}
+
 #[contract]
 pub struct MedicalRecordBackupContract;
 
@@ -341,6 +428,8 @@ impl MedicalRecordBackupContract {
             &BackupPolicy {
                 interval_seconds: 21_600,
                 retention_seconds: 2_592_000,
+                short_term_retention_seconds: 604_800,
+                long_term_retention_seconds: 220_752_000,
                 max_active_backups: 30,
                 min_targets_per_backup: 2,
                 min_region_count: 2,
@@ -349,6 +438,12 @@ impl MedicalRecordBackupContract {
                 encryption_required: true,
                 auto_cleanup: true,
                 min_restore_approvals: 1,
+                restore_request_ttl: 86_400,
+                max_target_failures: 5,
+                cost_alert_threshold: 10_000,
+                schedule_grace_seconds: 3_600,
+                integrity_quorum: 0,
+                failure_escalation_streak: 5,
             },
         );
 
@@ -394,16 +489,78 @@ impl MedicalRecordBackupContract {
         Ok(true)
     }
 
+    pub fn grant_capability(
+        env: Env,
+        admin: Address,
+        user: Address,
+        capability: Capability,
+    ) -> Result<bool, Error> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::Capabilities(user.clone());
+        let mut granted: Vec<Capability> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        if !granted.contains(capability) {
+            granted.push_back(capability);
+            env.storage().persistent().set(&key, &granted);
+        }
+        env.events()
+            .publish((symbol_short!("BKP_CGRT"),), (user, capability));
+        Ok(true)
+    }
+
+    pub fn revoke_capability(
+        env: Env,
+        admin: Address,
+        user: Address,
+        capability: Capability,
+    ) -> Result<bool, Error> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::Capabilities(user.clone());
+        let granted: Vec<Capability> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        let mut kept = Vec::new(&env);
+        for c in granted.iter() {
+            if c != capability {
+                kept.push_back(c);
+            }
+        }
+        env.storage().persistent().set(&key, &kept);
+        env.events()
+            .publish((symbol_short!("BKP_CRVK"),), (user, capability));
+        Ok(true)
+    }
+
+    pub fn get_capabilities(env: Env, user: Address) -> Vec<Capability> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Capabilities(user))
+            .unwrap_or(Vec::new(&env))
+    }
+
     pub fn set_policy(env: Env, caller: Address, policy: BackupPolicy) -> Result<bool, Error> {
         caller.require_auth();
         Self::require_admin(&env, &caller)?;
 
         if policy.interval_seconds == 0
             || policy.retention_seconds == 0
+            || policy.short_term_retention_seconds == 0
+            || policy.long_term_retention_seconds == 0
             || policy.max_active_backups == 0
             || policy.min_targets_per_backup == 0
             || policy.min_region_count == 0
             || policy.max_total_cost_weight == 0
+            || policy.restore_request_ttl == 0
+            || policy.max_target_failures == 0
+            || policy.cost_alert_threshold == 0
+            || policy.integrity_quorum > policy.min_targets_per_backup
         {
             return Err(Error::InvalidInput);
         }
@@ -432,7 +589,7 @@ impl MedicalRecordBackupContract {
         max_capacity_units: u64,
     ) -> Result<u32, Error> {
         caller.require_auth();
-        Self::require_operator(&env, &caller)?;
+        Self::require_operator(&env, &caller, Some(Capability::RegisterTarget))?;
         Self::require_not_paused(&env)?;
         if cost_weight == 0 || max_capacity_units == 0 {
             return Err(Error::InvalidInput);
@@ -448,6 +605,7 @@ impl MedicalRecordBackupContract {
             encrypted_only,
             cost_weight,
             max_capacity_units,
+            used_capacity_units: 0,
             failure_count: 0,
         };
 
@@ -473,7 +631,7 @@ impl MedicalRecordBackupContract {
         active: bool,
     ) -> Result<bool, Error> {
         caller.require_auth();
-        Self::require_operator(&env, &caller)?;
+        Self::require_operator(&env, &caller, Some(Capability::RegisterTarget))?;
         let mut target: BackupTarget = env
             .storage()
             .persistent()
@@ -517,41 +675,167 @@ impl MedicalRecordBackupContract {
         encryption_key_version: u32,
     ) -> Result<u64, Error> {
         caller.require_auth();
-        Self::require_operator(&env, &caller)?;
+        Self::require_operator(&env, &caller, Some(Capability::RunBackup))?;
         let now = env.ledger().timestamp();
         let next_run: u64 = env.storage().instance().get(&NEXT_RUN).unwrap_or(0);
         if now < next_run {
             return Err(Error::ScheduleNotDue);
         }
-        Self::execute_backup(
-            env,
+        let artifact_id = Self::execute_backup(
+            env.clone(),
             caller,
             source_root,
             snapshot_ref,
             encryption_key_version,
+            RetentionClass::Standard,
             true,
-        )
+            None,
+        )?;
+        env.storage().instance().remove(&MISSED);
+        Ok(artifact_id)
+    }
+
+    /// Raises a `ScheduleMissed` alert if the next scheduled run is overdue by
+    /// more than the policy grace period. Each missed window is reported once;
+    /// returns whether the schedule is currently overdue.
+    pub fn check_schedule_health(env: Env, caller: Address) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_auditor(&env, &caller, None)?;
+        let policy = Self::get_policy_internal(&env)?;
+        let now = env.ledger().timestamp();
+        let next_run: u64 = env.storage().instance().get(&NEXT_RUN).unwrap_or(0);
+        if now <= next_run.saturating_add(policy.schedule_grace_seconds) {
+            return Ok(false);
+        }
+
+        let reported: Option<u64> = env.storage().instance().get(&MISSED);
+        if reported != Some(next_run) {
+            env.storage().instance().set(&MISSED, &next_run);
+            let mut health = Self::get_health(env.clone());
+            health.consecutive_failures = health.consecutive_failures.saturating_add(1);
+            env.storage().persistent().set(&DataKey::Health, &health);
+            let details =
+                Self::compute_reason_hash(&env, AlertKind::ScheduleMissed as u32, next_run);
+            Self::append_alert(
+                &env,
+                AlertKind::ScheduleMissed,
+                AlertSeverity::High,
+                details,
+            );
+        }
+        Ok(true)
     }
 
+    /// Backs up `source_root` to the cheapest targets meeting the policy.
+    /// A run that cannot be placed returns its error and, like any failed
+    /// call, leaves no trace; log it with `record_backup_failure`.
     pub fn run_backup_now(
         env: Env,
         caller: Address,
         source_root: BytesN<32>,
         snapshot_ref: String,
         encryption_key_version: u32,
+        retention_class: RetentionClass,
     ) -> Result<u64, Error> {
         caller.require_auth();
-        Self::require_operator(&env, &caller)?;
+        Self::require_operator(&env, &caller, Some(Capability::RunBackup))?;
         Self::execute_backup(
             env,
             caller,
             source_root,
             snapshot_ref,
             encryption_key_version,
+            retention_class,
             false,
+            None,
         )
     }
 
+    /// Records a failed run in the execution log, backup health and alerts.
+    ///
+    /// The placement checks are run again, so only a backup that really
+    /// cannot be placed right now (encryption, target or cost limits) can be
+    /// logged; otherwise this returns `InvalidInput`. A `scheduled` failure
+    /// also requires the schedule to be due and moves the next run forward.
+    pub fn record_backup_failure(
+        env: Env,
+        caller: Address,
+        encryption_key_version: u32,
+        scheduled: bool,
+    ) -> Result<BackupExecution, Error> {
+        caller.require_auth();
+        Self::require_operator(&env, &caller, Some(Capability::RunBackup))?;
+        Self::require_initialized(&env)?;
+        Self::require_not_paused(&env)?;
+        if scheduled {
+            let next_run: u64 = env.storage().instance().get(&NEXT_RUN).unwrap_or(0);
+            if env.ledger().timestamp() < next_run {
+                return Err(Error::ScheduleNotDue);
+            }
+        }
+
+        let policy = Self::get_policy_internal(&env)?;
+        match Self::plan_backup(&env, &policy, encryption_key_version) {
+            Ok(_) => Err(Error::InvalidInput),
+            Err(e) => Ok(Self::record_failed_execution(
+                &env, caller, scheduled, e as u32, 0,
+            )),
+        }
+    }
+
+    /// Records a backup holding only `delta_root` on top of
+    /// `parent_artifact_id`, inheriting the parent's retention class.
+    /// Restoring it requires every ancestor in the chain to still be present
+    /// and unarchived, so cleanup keeps an artifact for as long as any
+    /// unarchived incremental builds on it.
+    pub fn execute_incremental_backup(
+        env: Env,
+        caller: Address,
+        parent_artifact_id: u64,
+        delta_root: BytesN<32>,
+        snapshot_ref: String,
+        encryption_key_version: u32,
+    ) -> Result<u64, Error> {
+        caller.require_auth();
+        Self::require_operator(&env, &caller, Some(Capability::RunBackup))?;
+        let parent: BackupArtifact = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Artifact(parent_artifact_id))
+            .ok_or(Error::BackupNotFound)?;
+        if parent.status == BackupStatus::Archived {
+            return Err(Error::BrokenBackupChain);
+        }
+        if Self::backup_chain_depth(&env, &parent)? + 1 >= MAX_BACKUP_CHAIN_DEPTH {
+            return Err(Error::InvalidInput);
+        }
+
+        let artifact_id = Self::execute_backup(
+            env.clone(),
+            caller,
+            delta_root,
+            snapshot_ref,
+            encryption_key_version,
+            parent.retention_class,
+            false,
+            Some(parent_artifact_id),
+        )?;
+        env.events().publish(
+            (symbol_short!("bkp_incr"),),
+            (artifact_id, parent_artifact_id),
+        );
+        Ok(artifact_id)
+    }
+
+    /// Checks `observed_checksum` against the artifact and its replicas.
+    ///
+    /// With no `integrity_quorum` every replica must match, and any mismatch
+    /// marks the artifact `Failed`. With a quorum the artifact passes once
+    /// that many replicas match; the rest are flagged `Failed` for
+    /// `repair_replica`. Too few matches mark the artifact `Failed` and
+    /// raise a `QuorumNotMet` alert, keeping the flagged replicas.
+    ///
+    /// Returns whether the artifact passed.
     pub fn verify_backup_integrity(
         env: Env,
         caller: Address,
@@ -559,37 +843,38 @@ impl MedicalRecordBackupContract {
         observed_checksum: BytesN<32>,
     ) -> Result<bool, Error> {
         caller.require_auth();
-        Self::require_auditor(&env, &caller)?;
+        Self::require_auditor(&env, &caller, None)?;
+        let policy = Self::get_policy_internal(&env)?;
 
         let mut artifact: BackupArtifact = env
             .storage()
             .persistent()
             .get(&DataKey::Artifact(artifact_id))
             .ok_or(Error::BackupNotFound)?;
-
-        let mut ok = artifact.checksum == observed_checksum;
-        if ok {
-            for target_id in artifact.target_ids.iter() {
-                let replica: BackupReplica = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Replica(artifact_id, target_id))
-                    .ok_or(Error::IntegrityMismatch)?;
-                if replica.status == ReplicaStatus::Failed || replica.checksum != artifact.checksum
-                {
-                    ok = false;
-                    break;
-                }
-            }
-        }
+        Self::backup_chain_depth(&env, &artifact)?;
+
+        let (ok, reason) = if artifact.checksum != observed_checksum {
+            (false, Error::IntegrityMismatch)
+        } else if policy.integrity_quorum == 0 {
+            (
+                Self::replicas_intact(&env, &artifact)?,
+                Error::IntegrityMismatch,
+            )
+        } else {
+            // An error would roll back the replica flags, so a missed
+            // quorum is reported as a failed check instead.
+            (
+                Self::flag_mismatched_replicas(&env, &artifact) >= policy.integrity_quorum,
+                Error::QuorumNotMet,
+            )
+        };
 
         if ok {
             artifact.status = BackupStatus::Verified;
             artifact.last_verified_at = env.ledger().timestamp();
         } else {
             artifact.status = BackupStatus::Failed;
-            let details =
-                Self::compute_reason_hash(&env, Error::IntegrityMismatch as u32, artifact_id);
+            let details = Self::compute_reason_hash(&env, reason as u32, artifact_id);
             Self::append_alert(
                 &env,
                 AlertKind::IntegrityCheckFailed,
@@ -603,6 +888,61 @@ impl MedicalRecordBackupContract {
         Ok(ok)
     }
 
+    /// Re-syncs a single bad replica of an artifact and re-runs the
+    /// artifact-level integrity check, restoring `Verified` if every replica
+    /// now matches. A `new_checksum` that still differs from the artifact's
+    /// fails with `IntegrityMismatch` and leaves the replica flagged.
+    pub fn repair_replica(
+        env: Env,
+        caller: Address,
+        artifact_id: u64,
+        target_id: u32,
+        new_checksum: BytesN<32>,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_operator(&env, &caller, None)?;
+        let mut artifact: BackupArtifact = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Artifact(artifact_id))
+            .ok_or(Error::BackupNotFound)?;
+        if artifact.status == BackupStatus::Archived || !artifact.target_ids.contains(target_id) {
+            return Err(Error::InvalidInput);
+        }
+        let key = DataKey::Replica(artifact_id, target_id);
+        let mut replica: BackupReplica = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::IntegrityMismatch)?;
+        if replica.status != ReplicaStatus::Failed && replica.checksum == artifact.checksum {
+            return Err(Error::InvalidInput);
+        }
+        if new_checksum != artifact.checksum {
+            return Err(Error::IntegrityMismatch);
+        }
+
+        let now = env.ledger().timestamp();
+        replica.checksum = new_checksum;
+        replica.status = ReplicaStatus::Synced;
+        replica.synced_at = now;
+        env.storage().persistent().set(&key, &replica);
+
+        let intact = Self::replicas_intact(&env, &artifact)?;
+        if intact {
+            artifact.status = BackupStatus::Verified;
+            artifact.last_verified_at = now;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Artifact(artifact_id), &artifact);
+        }
+        env.events().publish(
+            (Symbol::new(&env, "ReplicaRepaired"),),
+            (artifact_id, target_id, intact),
+        );
+        Ok(intact)
+    }
+
     pub fn request_restore(
         env: Env,
         caller: Address,
@@ -610,23 +950,26 @@ impl MedicalRecordBackupContract {
         reason_hash: BytesN<32>,
     ) -> Result<u64, Error> {
         caller.require_auth();
-        Self::require_recovery(&env, &caller)?;
+        Self::require_recovery(&env, &caller, Some(Capability::RequestRestore))?;
         let _: BackupArtifact = env
             .storage()
             .persistent()
             .get(&DataKey::Artifact(artifact_id))
             .ok_or(Error::BackupNotFound)?;
+        let policy = Self::get_policy_internal(&env)?;
 
         let request_id = Self::next_restore_request_id(&env);
+        let now = env.ledger().timestamp();
         let request = RestoreRequest {
             request_id,
             artifact_id,
             requested_by: caller,
             reason_hash,
-            requested_at: env.ledger().timestamp(),
+            requested_at: now,
             approvals: Vec::new(&env),
             status: RestoreStatus::Pending,
             executed_at: 0,
+            expires_at: now.saturating_add(policy.restore_request_ttl),
         };
         env.storage()
             .persistent()
@@ -638,7 +981,7 @@ impl MedicalRecordBackupContract {
 
     pub fn approve_restore(env: Env, caller: Address, request_id: u64) -> Result<bool, Error> {
         caller.require_auth();
-        Self::require_auditor(&env, &caller)?;
+        Self::require_auditor(&env, &caller, Some(Capability::ApproveRestore))?;
         let mut request: RestoreRequest = env
             .storage()
             .persistent()
@@ -647,6 +990,9 @@ impl MedicalRecordBackupContract {
         if request.status == RestoreStatus::Executed {
             return Err(Error::AlreadyExecuted);
         }
+        if env.ledger().timestamp() >= request.expires_at {
+            return Err(Error::RestoreRequestExpired);
+        }
         if request.approvals.iter().any(|a| a == caller) {
             return Err(Error::DuplicateApproval);
         }
@@ -665,7 +1011,7 @@ impl MedicalRecordBackupContract {
 
     pub fn execute_restore(env: Env, caller: Address, request_id: u64) -> Result<String, Error> {
         caller.require_auth();
-        Self::require_recovery(&env, &caller)?;
+        Self::require_recovery(&env, &caller, None)?;
         let mut request: RestoreRequest = env
             .storage()
             .persistent()
@@ -674,12 +1020,20 @@ impl MedicalRecordBackupContract {
         if request.status != RestoreStatus::Approved {
             return Err(Error::RestoreNotApproved);
         }
+        if env.ledger().timestamp() >= request.expires_at {
+            return Err(Error::RestoreRequestExpired);
+        }
 
         let mut artifact: BackupArtifact = env
             .storage()
             .persistent()
             .get(&DataKey::Artifact(request.artifact_id))
             .ok_or(Error::BackupNotFound)?;
+        if artifact.status == BackupStatus::Archived {
+            return Err(Error::IntegrityMismatch);
+        }
+        Self::check_restorable_replicas(&env, &artifact)?;
+        Self::backup_chain_depth(&env, &artifact)?;
 
         request.status = RestoreStatus::Executed;
         request.executed_at = env.ledger().timestamp();
@@ -700,6 +1054,80 @@ impl MedicalRecordBackupContract {
         Ok(artifact.snapshot_ref)
     }
 
+    /// Runs the checks `execute_restore` depends on without changing any
+    /// state, so drills can confirm a restore is viable first.
+    pub fn validate_restore(
+        env: Env,
+        caller: Address,
+        request_id: u64,
+    ) -> Result<RestoreValidation, Error> {
+        caller.require_auth();
+        Self::require_recovery(&env, &caller, None)?;
+        let request: RestoreRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RestoreRequest(request_id))
+            .ok_or(Error::RestoreRequestNotFound)?;
+        let artifact: Option<BackupArtifact> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Artifact(request.artifact_id));
+
+        let approved = request.status == RestoreStatus::Approved;
+        let expired = env.ledger().timestamp() >= request.expires_at;
+        let artifact_archived = artifact
+            .as_ref()
+            .is_some_and(|a| a.status == BackupStatus::Archived);
+        let replicas_intact = artifact
+            .as_ref()
+            .is_some_and(|a| Self::check_restorable_replicas(&env, a).is_ok());
+        let chain_intact = artifact
+            .as_ref()
+            .is_some_and(|a| Self::backup_chain_depth(&env, a).is_ok());
+        Ok(RestoreValidation {
+            request_id,
+            approved,
+            expired,
+            artifact_found: artifact.is_some(),
+            artifact_archived,
+            replicas_intact,
+            chain_intact,
+            viable: approved && !expired && !artifact_archived && replicas_intact && chain_intact,
+        })
+    }
+
+    /// Marks a pending or approved restore request that has outlived its TTL
+    /// as rejected so it can no longer be acted on.
+    pub fn expire_restore_request(
+        env: Env,
+        caller: Address,
+        request_id: u64,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        Self::require_recovery(&env, &caller, None)?;
+        let mut request: RestoreRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RestoreRequest(request_id))
+            .ok_or(Error::RestoreRequestNotFound)?;
+        match request.status {
+            RestoreStatus::Executed => return Err(Error::AlreadyExecuted),
+            RestoreStatus::Rejected => return Err(Error::InvalidInput),
+            RestoreStatus::Pending | RestoreStatus::Approved => {},
+        }
+        if env.ledger().timestamp() < request.expires_at {
+            return Err(Error::InvalidInput);
+        }
+
+        request.status = RestoreStatus::Rejected;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RestoreRequest(request_id), &request);
+        env.events()
+            .publish((symbol_short!("BKP_REXP"),), (request_id, caller));
+        Ok(true)
+    }
+
     pub fn run_recovery_test(
         env: Env,
         caller: Address,
@@ -707,12 +1135,13 @@ impl MedicalRecordBackupContract {
         validation_hash: BytesN<32>,
     ) -> Result<u64, Error> {
         caller.require_auth();
-        Self::require_recovery(&env, &caller)?;
+        Self::require_recovery(&env, &caller, None)?;
         let mut artifact: BackupArtifact = env
             .storage()
             .persistent()
             .get(&DataKey::Artifact(artifact_id))
             .ok_or(Error::BackupNotFound)?;
+        Self::backup_chain_depth(&env, &artifact)?;
 
         let passed = validation_hash == artifact.checksum
             && artifact.status != BackupStatus::Archived
@@ -753,7 +1182,7 @@ impl MedicalRecordBackupContract {
 
     pub fn optimize_and_cleanup(env: Env, caller: Address) -> Result<CleanupReport, Error> {
         caller.require_auth();
-        Self::require_operator(&env, &caller)?;
+        Self::require_operator(&env, &caller, None)?;
         let policy = Self::get_policy_internal(&env)?;
         let report = Self::optimize_and_cleanup_internal(&env, &policy);
         env.events()
@@ -768,28 +1197,65 @@ impl MedicalRecordBackupContract {
         reason_hash: BytesN<32>,
     ) -> Result<bool, Error> {
         caller.require_auth();
-        Self::require_operator(&env, &caller)?;
+        Self::require_operator(&env, &caller, None)?;
         let mut target: BackupTarget = env
             .storage()
             .persistent()
             .get(&DataKey::Target(target_id))
             .ok_or(Error::TargetNotFound)?;
+        let policy = Self::get_policy_internal(&env)?;
         target.failure_count = target.failure_count.saturating_add(1);
+        let disable = target.is_active && target.failure_count >= policy.max_target_failures;
+        if disable {
+            target.is_active = false;
+        }
         env.storage()
             .persistent()
             .set(&DataKey::Target(target_id), &target);
-        Self::append_alert(
-            &env,
-            AlertKind::TargetFailure,
-            AlertSeverity::High,
-            reason_hash,
-        );
+        // A target that crosses the failure threshold is dropped from
+        // selection, so the alert is escalated to critical.
+        let severity = if disable {
+            AlertSeverity::Critical
+        } else {
+            AlertSeverity::High
+        };
+        Self::append_alert(&env, AlertKind::TargetFailure, severity, reason_hash);
+        if disable {
+            env.events().publish(
+                (symbol_short!("BKP_TOFF"),),
+                (target_id, target.failure_count),
+            );
+        }
+        Ok(true)
+    }
+
+    /// Clears a target's failure count after remediation and puts it back
+    /// into rotation.
+    pub fn reset_target_failures(
+        env: Env,
+        operator: Address,
+        target_id: u32,
+    ) -> Result<bool, Error> {
+        operator.require_auth();
+        Self::require_operator(&env, &operator, Some(Capability::RegisterTarget))?;
+        let mut target: BackupTarget = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Target(target_id))
+            .ok_or(Error::TargetNotFound)?;
+        target.failure_count = 0;
+        target.is_active = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Target(target_id), &target);
+        env.events()
+            .publish((symbol_short!("BKP_TON"),), (target_id, operator));
         Ok(true)
     }
 
     pub fn resolve_alert(env: Env, caller: Address, alert_id: u64) -> Result<bool, Error> {
         caller.require_auth();
-        Self::require_auditor(&env, &caller)?;
+        Self::require_auditor(&env, &caller, Some(Capability::ResolveAlert))?;
         let mut alert: AlertEntry = env
             .storage()
             .persistent()
@@ -803,6 +1269,9 @@ impl MedicalRecordBackupContract {
         Ok(true)
     }
 
+    /// Returns every alert in one call. The cost grows with the alert
+    /// history, so large deployments should use `list_alerts_paged` to stay
+    /// within gas limits.
     pub fn list_alerts(env: Env, open_only: bool) -> Vec<AlertEntry> {
         let ids: Vec<u64> = env
             .storage()
@@ -824,6 +1293,27 @@ impl MedicalRecordBackupContract {
         out
     }
 
+    /// Returns one page of alerts, at most `MAX_PAGE_SIZE` long, and whether
+    /// more matching alerts follow it.
+    pub fn list_alerts_paged(
+        env: Env,
+        open_only: bool,
+        page: u32,
+        page_size: u32,
+    ) -> (Vec<AlertEntry>, bool) {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AlertIds)
+            .unwrap_or(Vec::new(&env));
+        let open = |a: &AlertEntry| !a.resolved;
+        let keep: Option<&dyn Fn(&AlertEntry) -> bool> = if open_only { Some(&open) } else { None };
+        Self::load_page(&env, &ids, DataKey::Alert, page, page_size, keep)
+    }
+
+    /// Returns every artifact in one call. The cost grows with the backup
+    /// history, so large deployments should use `list_artifacts_paged` to
+    /// stay within gas limits.
     pub fn list_artifacts(env: Env, include_archived: bool) -> Vec<BackupArtifact> {
         let ids: Vec<u64> = env
             .storage()
@@ -845,6 +1335,65 @@ impl MedicalRecordBackupContract {
         out
     }
 
+    /// Returns one page of artifacts, at most `MAX_PAGE_SIZE` long, and
+    /// whether more matching artifacts follow it.
+    pub fn list_artifacts_paged(
+        env: Env,
+        include_archived: bool,
+        page: u32,
+        page_size: u32,
+    ) -> (Vec<BackupArtifact>, bool) {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ArtifactIds)
+            .unwrap_or(Vec::new(&env));
+        let live = |a: &BackupArtifact| a.status != BackupStatus::Archived;
+        let keep: Option<&dyn Fn(&BackupArtifact) -> bool> =
+            if include_archived { None } else { Some(&live) };
+        Self::load_page(&env, &ids, DataKey::Artifact, page, page_size, keep)
+    }
+
+    /// Records a new current encryption key version. Versions only move
+    /// forward; artifacts under older versions become stale.
+    pub fn set_current_key_version(env: Env, admin: Address, version: u32) -> Result<bool, Error> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        let previous: u32 = env.storage().instance().get(&KEY_VER).unwrap_or(0);
+        if version <= previous {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().instance().set(&KEY_VER, &version);
+        env.events().publish(
+            (Symbol::new(&env, "KeyVersionRotated"),),
+            (previous, version),
+        );
+        Ok(true)
+    }
+
+    pub fn get_current_key_version(env: Env) -> u32 {
+        env.storage().instance().get(&KEY_VER).unwrap_or(0)
+    }
+
+    /// Returns one page of unarchived, encrypted artifacts whose key version
+    /// is older than the current one, and whether more follow it.
+    pub fn get_stale_encryption_artifacts(
+        env: Env,
+        page: u32,
+        page_size: u32,
+    ) -> (Vec<BackupArtifact>, bool) {
+        let current: u32 = env.storage().instance().get(&KEY_VER).unwrap_or(0);
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ArtifactIds)
+            .unwrap_or(Vec::new(&env));
+        let stale = |a: &BackupArtifact| {
+            a.status != BackupStatus::Archived && a.encrypted && a.encryption_key_version < current
+        };
+        Self::load_page(&env, &ids, DataKey::Artifact, page, page_size, Some(&stale))
+    }
+
     pub fn get_artifact(env: Env, artifact_id: u64) -> Option<BackupArtifact> {
         env.storage()
             .persistent()
@@ -884,6 +1433,37 @@ impl MedicalRecordBackupContract {
             })
     }
 
+    /// Returns the cost weight of all unarchived backups and the policy
+    /// threshold above which a cost alert is raised.
+    pub fn get_cost_summary(env: Env) -> (u32, u32) {
+        let active: u32 = env.storage().instance().get(&ACT_COST).unwrap_or(0);
+        let threshold = Self::get_policy_internal(&env)
+            .map(|p| p.cost_alert_threshold)
+            .unwrap_or(0);
+        (active, threshold)
+    }
+
+    /// Advisory only: the lowest-cost set of targets that could take a
+    /// backup needing `required_regions` regions and `required_targets`
+    /// targets, chosen as `execute_backup` would for an encrypted artifact
+    /// under the current encryption policy. Empty when no such set exists.
+    pub fn suggest_target_mix(env: Env, required_regions: u32, required_targets: u32) -> Vec<u32> {
+        let encryption_required = Self::get_policy_internal(&env)
+            .map(|p| p.encryption_required)
+            .unwrap_or(false);
+        let (selected, region_count, _) = Self::cheapest_target_mix(
+            &env,
+            required_regions,
+            required_targets,
+            encryption_required,
+            false,
+        );
+        if selected.len() < required_targets || region_count < required_regions {
+            return Vec::new(&env);
+        }
+        selected
+    }
+
     pub fn get_schedule(env: Env) -> (u64, u64) {
         (
             env.storage().instance().get(&LAST_RUN).unwrap_or(0),
@@ -897,42 +1477,15 @@ impl MedicalRecordBackupContract {
         source_root: BytesN<32>,
         snapshot_ref: String,
         encryption_key_version: u32,
+        retention_class: RetentionClass,
         scheduled: bool,
+        parent_artifact_id: Option<u64>,
     ) -> Result<u64, Error> {
         Self::require_initialized(&env)?;
         Self::require_not_paused(&env)?;
         let policy = Self::get_policy_internal(&env)?;
-
-        if policy.encryption_required && encryption_key_version == 0 {
-            Self::record_failed_execution(
-                &env,
-                caller,
-                scheduled,
-                Error::EncryptionRequired as u32,
-                0,
-            );
-            return Err(Error::EncryptionRequired);
-        }
-
-        let selected = Self::select_targets(&env, &policy);
-        let (target_ids, region_count, total_cost) = match selected {
-            Ok(v) => v,
-            Err(e) => {
-                Self::record_failed_execution(&env, caller, scheduled, e as u32, 0);
-                return Err(e);
-            },
-        };
-
-        if total_cost > policy.max_total_cost_weight {
-            Self::record_failed_execution(
-                &env,
-                caller,
-                scheduled,
-                Error::CostLimitExceeded as u32,
-                0,
-            );
-            return Err(Error::CostLimitExceeded);
-        }
+        let (target_ids, region_count, total_cost) =
+            Self::plan_backup(&env, &policy, encryption_key_version)?;
 
         let artifact_id = Self::next_backup_id(&env);
         let now = env.ledger().timestamp();
@@ -956,6 +1509,7 @@ impl MedicalRecordBackupContract {
             env.storage()
                 .persistent()
                 .set(&DataKey::Replica(artifact_id, target_id), &replica);
+            Self::adjust_target_usage(&env, target_id, true);
         }
         if policy.verify_on_write {
             status = BackupStatus::Verified;
@@ -970,7 +1524,8 @@ impl MedicalRecordBackupContract {
             encryption_key_version,
             encrypted: encryption_key_version > 0,
             created_at: now,
-            expires_at: now.saturating_add(policy.retention_seconds),
+            expires_at: Self::retention_expiry(&policy, retention_class, now),
+            retention_class,
             target_ids: target_ids.clone(),
             region_count,
             total_cost_weight: total_cost,
@@ -978,6 +1533,7 @@ impl MedicalRecordBackupContract {
             last_verified_at: verified_at,
             last_restored_at: 0,
             restore_drill_passed: false,
+            parent_artifact_id,
         };
 
         env.storage()
@@ -993,6 +1549,24 @@ impl MedicalRecordBackupContract {
             .persistent()
             .set(&DataKey::ArtifactIds, &artifact_ids);
 
+        let previous_cost: u32 = env.storage().instance().get(&ACT_COST).unwrap_or(0);
+        let active_cost = previous_cost.saturating_add(total_cost);
+        env.storage().instance().set(&ACT_COST, &active_cost);
+        if previous_cost <= policy.cost_alert_threshold && active_cost > policy.cost_alert_threshold
+        {
+            let details = Self::compute_reason_hash(
+                &env,
+                Error::CostLimitExceeded as u32,
+                active_cost as u64,
+            );
+            Self::append_alert(
+                &env,
+                AlertKind::CostThresholdExceeded,
+                AlertSeverity::Medium,
+                details,
+            );
+        }
+
         Self::record_success_execution(
             &env,
             caller.clone(),
@@ -1017,17 +1591,97 @@ impl MedicalRecordBackupContract {
         Ok(artifact_id)
     }
 
+    /// Targets, region count and total cost for a backup under `policy`, or
+    /// the reason it cannot be placed (encryption, target or cost limits).
+    fn plan_backup(
+        env: &Env,
+        policy: &BackupPolicy,
+        encryption_key_version: u32,
+    ) -> Result<(Vec<u32>, u32, u32), Error> {
+        if policy.encryption_required && encryption_key_version == 0 {
+            return Err(Error::EncryptionRequired);
+        }
+        let (target_ids, region_count, total_cost) =
+            Self::select_targets(env, policy, encryption_key_version == 0)?;
+        if total_cost > policy.max_total_cost_weight {
+            return Err(Error::CostLimitExceeded);
+        }
+        Ok((target_ids, region_count, total_cost))
+    }
+
     #[must_use]
-    fn select_targets(env: &Env, policy: &BackupPolicy) -> Result<(Vec<u32>, u32, u32), Error> {
+    fn select_targets(
+        env: &Env,
+        policy: &BackupPolicy,
+        plaintext: bool,
+    ) -> Result<(Vec<u32>, u32, u32), Error> {
+        let (selected, region_count, total_cost) = Self::cheapest_target_mix(
+            env,
+            policy.min_region_count,
+            policy.min_targets_per_backup,
+            policy.encryption_required,
+            plaintext,
+        );
+        let short = selected.len() < policy.min_targets_per_backup
+            || region_count < policy.min_region_count;
+
+        // Encrypted-only targets never take plaintext, whatever the policy.
+        // Report that, rather than a capacity shortfall, when they are what
+        // the backup would have needed.
+        if short && plaintext {
+            let (encrypted, encrypted_regions, _) = Self::cheapest_target_mix(
+                env,
+                policy.min_region_count,
+                policy.min_targets_per_backup,
+                policy.encryption_required,
+                false,
+            );
+            if encrypted.len() >= policy.min_targets_per_backup
+                && encrypted_regions >= policy.min_region_count
+            {
+                return Err(Error::EncryptionRequired);
+            }
+        }
+
+        // The run's BackupFailure alert is raised by record_failed_execution
+        if selected.len() < policy.min_targets_per_backup {
+            return Err(Error::InsufficientTargets);
+        }
+        if region_count < policy.min_region_count {
+            let details = Self::compute_reason_hash(env, Error::GeoRedundancyNotMet as u32, 0);
+            Self::append_alert(
+                env,
+                AlertKind::GeoRedundancyRisk,
+                AlertSeverity::Critical,
+                details,
+            );
+            return Err(Error::GeoRedundancyNotMet);
+        }
+        Ok((selected, region_count, total_cost))
+    }
+
+    /// Cheapest set of eligible targets covering `min_regions` regions and
+    /// `min_targets` targets, with its region count and total cost. Falls
+    /// short of the minimums when too few targets are eligible. `plaintext`
+    /// leaves out `encrypted_only` targets.
+    fn cheapest_target_mix(
+        env: &Env,
+        min_regions: u32,
+        min_targets: u32,
+        encryption_required: bool,
+        plaintext: bool,
+    ) -> (Vec<u32>, u32, u32) {
         let ids: Vec<u32> = env
             .storage()
             .persistent()
             .get(&DataKey::TargetIds)
             .unwrap_or(Vec::new(env));
-        let mut selected = Vec::new(env);
-        let mut regions = Vec::new(env);
-        let mut total_cost = 0u32;
-
+        // Eligible targets ordered by (cost_weight, target_id): the cheapest
+        // MAX_SELECTION_CANDIDATES, plus the cheapest target of every region
+        // so that region coverage never depends on the cap. The cap only
+        // bounds the sorted list; every target is still read once.
+        let mut candidates: Vec<BackupTarget> = Vec::new(env);
+        let mut region_best: Vec<BackupTarget> = Vec::new(env);
         for id in ids.iter() {
             let target: BackupTarget = match env.storage().persistent().get(&DataKey::Target(id)) {
                 Some(t) => t,
@@ -1036,46 +1690,262 @@ impl MedicalRecordBackupContract {
             if !target.is_active {
                 continue;
             }
-            if policy.encryption_required && !target.encrypted_only {
+            if encryption_required && !target.encrypted_only {
                 continue;
             }
-            if total_cost.saturating_add(target.cost_weight) > policy.max_total_cost_weight {
+            if plaintext && target.encrypted_only {
+                continue;
+            }
+            if target
+                .used_capacity_units
+                .saturating_add(ARTIFACT_CAPACITY_UNITS)
+                > target.max_capacity_units
+            {
                 continue;
             }
 
-            selected.push_back(id);
-            total_cost = total_cost.saturating_add(target.cost_weight);
-            if !Self::contains_region(&regions, target.region) {
-                regions.push_back(target.region);
+            let found = region_best
+                .iter()
+                .enumerate()
+                .find(|(_, b)| b.region == target.region);
+            match found {
+                Some((i, best)) => {
+                    if (target.cost_weight, target.target_id) < (best.cost_weight, best.target_id) {
+                        region_best.set(i as u32, target.clone());
+                    }
+                },
+                None => region_best.push_back(target.clone()),
             }
-            if selected.len() >= policy.min_targets_per_backup
-                && regions.len() >= policy.min_region_count
-            {
+
+            let pos = Self::cost_position(&candidates, &target);
+            if pos >= MAX_SELECTION_CANDIDATES {
+                continue;
+            }
+            candidates.insert(pos, target);
+            if candidates.len() > MAX_SELECTION_CANDIDATES {
+                candidates.pop_back();
+            }
+        }
+        for best in region_best.iter() {
+            if !candidates.iter().any(|c| c.target_id == best.target_id) {
+                let pos = Self::cost_position(&candidates, &best);
+                candidates.insert(pos, best);
+            }
+        }
+
+        // First cover the required regions with the cheapest target of each of
+        // the cheapest regions, then fill the remaining slots with the
+        // cheapest targets left. Both passes walk the same cost ordering, so
+        // the result is the cost-minimal set meeting the policy.
+        let mut selected = Vec::new(env);
+        let mut regions = Vec::new(env);
+        let mut total_cost = 0u32;
+        for c in candidates.iter() {
+            if regions.len() >= min_regions {
+                break;
+            }
+            if Self::contains_region(&regions, c.region) {
+                continue;
+            }
+            regions.push_back(c.region);
+            selected.push_back(c.target_id);
+            total_cost = total_cost.saturating_add(c.cost_weight);
+        }
+        for c in candidates.iter() {
+            if selected.len() >= min_targets {
                 break;
             }
+            if selected.contains(c.target_id) {
+                continue;
+            }
+            if !Self::contains_region(&regions, c.region) {
+                regions.push_back(c.region);
+            }
+            selected.push_back(c.target_id);
+            total_cost = total_cost.saturating_add(c.cost_weight);
         }
+        (selected, regions.len(), total_cost)
+    }
 
-        if selected.len() < policy.min_targets_per_backup {
-            let details = Self::compute_reason_hash(env, Error::InsufficientTargets as u32, 0);
-            Self::append_alert(
-                env,
-                AlertKind::BackupFailure,
-                AlertSeverity::Critical,
-                details,
-            );
-            return Err(Error::InsufficientTargets);
+    /// Charges or releases one artifact's worth of capacity on a target.
+    fn adjust_target_usage(env: &Env, target_id: u32, assign: bool) {
+        let key = DataKey::Target(target_id);
+        if let Some(mut target) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BackupTarget>(&key)
+        {
+            target.used_capacity_units = if assign {
+                target
+                    .used_capacity_units
+                    .saturating_add(ARTIFACT_CAPACITY_UNITS)
+            } else {
+                target
+                    .used_capacity_units
+                    .saturating_sub(ARTIFACT_CAPACITY_UNITS)
+            };
+            env.storage().persistent().set(&key, &target);
         }
-        if regions.len() < policy.min_region_count {
-            let details = Self::compute_reason_hash(env, Error::GeoRedundancyNotMet as u32, 0);
-            Self::append_alert(
-                env,
-                AlertKind::GeoRedundancyRisk,
-                AlertSeverity::Critical,
-                details,
-            );
-            return Err(Error::GeoRedundancyNotMet);
+    }
+
+    /// Walks the parent chain of `artifact`, returning how many ancestors it
+    /// has. Fails with `BrokenBackupChain` if any ancestor is missing or
+    /// archived.
+    fn backup_chain_depth(env: &Env, artifact: &BackupArtifact) -> Result<u32, Error> {
+        let mut depth = 0u32;
+        let mut next = artifact.parent_artifact_id;
+        while let Some(parent_id) = next {
+            if depth >= MAX_BACKUP_CHAIN_DEPTH {
+                return Err(Error::BrokenBackupChain);
+            }
+            let parent: BackupArtifact = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Artifact(parent_id))
+                .ok_or(Error::BrokenBackupChain)?;
+            if parent.status == BackupStatus::Archived {
+                return Err(Error::BrokenBackupChain);
+            }
+            depth = depth.saturating_add(1);
+            next = parent.parent_artifact_id;
+        }
+        Ok(depth)
+    }
+
+    /// Whether every replica of `artifact` is healthy and carries the
+    /// artifact's checksum.
+    fn replicas_intact(env: &Env, artifact: &BackupArtifact) -> Result<bool, Error> {
+        for target_id in artifact.target_ids.iter() {
+            let replica: BackupReplica = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Replica(artifact.artifact_id, target_id))
+                .ok_or(Error::IntegrityMismatch)?;
+            if replica.status == ReplicaStatus::Failed || replica.checksum != artifact.checksum {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Fails unless enough replicas of `artifact` match to restore from it:
+    /// all of them without an `integrity_quorum`, otherwise at least the
+    /// quorum, mirroring what `verify_backup_integrity` accepts.
+    fn check_restorable_replicas(env: &Env, artifact: &BackupArtifact) -> Result<(), Error> {
+        let quorum = Self::get_policy_internal(env)?.integrity_quorum;
+        if quorum == 0 {
+            if !Self::replicas_intact(env, artifact)? {
+                return Err(Error::IntegrityMismatch);
+            }
+        } else if Self::matching_replicas(env, artifact) < quorum {
+            return Err(Error::QuorumNotMet);
+        }
+        Ok(())
+    }
+
+    /// Counts the healthy replicas of `artifact` that carry its checksum.
+    fn matching_replicas(env: &Env, artifact: &BackupArtifact) -> u32 {
+        let mut matching = 0u32;
+        for target_id in artifact.target_ids.iter() {
+            if let Some(replica) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, BackupReplica>(&DataKey::Replica(artifact.artifact_id, target_id))
+            {
+                if replica.status != ReplicaStatus::Failed && replica.checksum == artifact.checksum
+                {
+                    matching = matching.saturating_add(1);
+                }
+            }
         }
-        Ok((selected, regions.len(), total_cost))
+        matching
+    }
+
+    /// Loads one page (at most `MAX_PAGE_SIZE` long) of the entries stored
+    /// under `key(id)` for `ids`, and whether more follow it. Without a filter
+    /// the page is sliced straight out of `ids`; with one, entries are scanned
+    /// in order until the page fills.
+    fn load_page<T>(
+        env: &Env,
+        ids: &Vec<u64>,
+        key: fn(u64) -> DataKey,
+        page: u32,
+        page_size: u32,
+        keep: Option<&dyn Fn(&T) -> bool>,
+    ) -> (Vec<T>, bool)
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        let size = page_size.clamp(1, MAX_PAGE_SIZE);
+        let skip = page.saturating_mul(size);
+        let mut out = Vec::new(env);
+
+        let Some(keep) = keep else {
+            let end = skip.saturating_add(size).min(ids.len());
+            if skip < end {
+                for id in ids.slice(skip..end).iter() {
+                    if let Some(entry) = env.storage().persistent().get::<DataKey, T>(&key(id)) {
+                        out.push_back(entry);
+                    }
+                }
+            }
+            return (out, ids.len() > end);
+        };
+
+        let mut matched = 0u32;
+        for id in ids.iter() {
+            let Some(entry) = env.storage().persistent().get::<DataKey, T>(&key(id)) else {
+                continue;
+            };
+            if !keep(&entry) {
+                continue;
+            }
+            if matched >= skip {
+                if out.len() >= size {
+                    return (out, true);
+                }
+                out.push_back(entry);
+            }
+            matched = matched.saturating_add(1);
+        }
+        (out, false)
+    }
+
+    /// Marks every replica of `artifact` that no longer carries its checksum
+    /// as `Failed` and returns how many still match.
+    fn flag_mismatched_replicas(env: &Env, artifact: &BackupArtifact) -> u32 {
+        let mut matching = 0u32;
+        for target_id in artifact.target_ids.iter() {
+            let key = DataKey::Replica(artifact.artifact_id, target_id);
+            let Some(mut replica) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, BackupReplica>(&key)
+            else {
+                continue;
+            };
+            if replica.status == ReplicaStatus::Failed {
+                continue;
+            }
+            if replica.checksum == artifact.checksum {
+                matching = matching.saturating_add(1);
+            } else {
+                replica.status = ReplicaStatus::Failed;
+                env.storage().persistent().set(&key, &replica);
+            }
+        }
+        matching
+    }
+
+    /// Index at which `target` keeps `candidates` ordered by
+    /// (cost_weight, target_id).
+    fn cost_position(candidates: &Vec<BackupTarget>, target: &BackupTarget) -> u32 {
+        for (i, c) in candidates.iter().enumerate() {
+            if (target.cost_weight, target.target_id) < (c.cost_weight, c.target_id) {
+                return i as u32;
+            }
+        }
+        candidates.len()
     }
 
     fn contains_region(regions: &Vec<GeoRegion>, candidate: GeoRegion) -> bool {
@@ -1133,7 +2003,7 @@ impl MedicalRecordBackupContract {
         scheduled: bool,
         error_code: u32,
         failed_targets: u32,
-    ) {
+    ) -> BackupExecution {
         let execution_id = Self::next_execution_id(env);
         let now = env.ledger().timestamp();
         let exec = BackupExecution {
@@ -1175,15 +2045,26 @@ impl MedicalRecordBackupContract {
         Self::append_alert(
             env,
             AlertKind::BackupFailure,
-            AlertSeverity::Critical,
-            details,
+            get_alert_severity_for_streak(health.consecutive_failures),
+            details.clone(),
         );
         if let Ok(policy) = Self::get_policy_internal(env) {
+            if policy.failure_escalation_streak > 0
+                && health.consecutive_failures == policy.failure_escalation_streak
+            {
+                Self::append_alert(
+                    env,
+                    AlertKind::FailureStreak,
+                    AlertSeverity::Critical,
+                    details,
+                );
+            }
             env.storage().instance().set(&LAST_RUN, &now);
             env.storage()
                 .instance()
                 .set(&NEXT_RUN, &now.saturating_add(policy.interval_seconds));
         }
+        exec
     }
 
     fn append_alert(env: &Env, kind: AlertKind, severity: AlertSeverity, details_hash: BytesN<32>) {
@@ -1244,7 +2125,10 @@ impl MedicalRecordBackupContract {
             .get(&DataKey::ArtifactIds)
             .unwrap_or(Vec::new(env));
 
+        // Parents of unarchived incrementals are kept so chains stay
+        // restorable; they become eligible once their children are archived.
         let mut active_count: u32 = 0;
+        let mut live_parents: Vec<u64> = Vec::new(env);
         for id in ids.iter() {
             if let Some(a) = env
                 .storage()
@@ -1253,6 +2137,11 @@ impl MedicalRecordBackupContract {
             {
                 if a.status != BackupStatus::Archived {
                     active_count = active_count.saturating_add(1);
+                    if let Some(parent_id) = a.parent_artifact_id {
+                        if !live_parents.contains(parent_id) {
+                            live_parents.push_back(parent_id);
+                        }
+                    }
                 }
             }
         }
@@ -1265,7 +2154,14 @@ impl MedicalRecordBackupContract {
                 .persistent()
                 .get::<DataKey, BackupArtifact>(&DataKey::Artifact(id))
             {
-                let expired = now >= artifact.expires_at;
+                if artifact.retention_class == RetentionClass::Permanent
+                    || live_parents.contains(id)
+                {
+                    continue;
+                }
+                let expires_at =
+                    Self::retention_expiry(policy, artifact.retention_class, artifact.created_at);
+                let expired = now >= expires_at;
                 let exceeds_limit = active_count > policy.max_active_backups;
                 if artifact.status != BackupStatus::Archived && (expired || exceeds_limit) {
                     artifact.status = BackupStatus::Archived;
@@ -1275,9 +2171,11 @@ impl MedicalRecordBackupContract {
                         active_count = active_count.saturating_sub(1);
                     }
                     for target_id in artifact.target_ids.iter() {
-                        env.storage()
-                            .persistent()
-                            .remove(&DataKey::Replica(artifact.artifact_id, target_id));
+                        let key = DataKey::Replica(artifact.artifact_id, target_id);
+                        if env.storage().persistent().has(&key) {
+                            env.storage().persistent().remove(&key);
+                            Self::adjust_target_usage(env, target_id, false);
+                        }
                     }
                     env.storage()
                         .persistent()
@@ -1286,6 +2184,13 @@ impl MedicalRecordBackupContract {
             }
         }
 
+        if reclaimed > 0 {
+            let active_cost: u32 = env.storage().instance().get(&ACT_COST).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&ACT_COST, &active_cost.saturating_sub(reclaimed));
+        }
+
         CleanupReport {
             archived_backups: archived,
             reclaimed_cost_weight: reclaimed,
@@ -1293,6 +2198,18 @@ impl MedicalRecordBackupContract {
         }
     }
 
+    /// When an artifact of `class` created at `created_at` expires under
+    /// `policy`; `Permanent` artifacts never do.
+    fn retention_expiry(policy: &BackupPolicy, class: RetentionClass, created_at: u64) -> u64 {
+        let duration = match class {
+            RetentionClass::ShortTerm => policy.short_term_retention_seconds,
+            RetentionClass::Standard => policy.retention_seconds,
+            RetentionClass::LongTerm => policy.long_term_retention_seconds,
+            RetentionClass::Permanent => return u64::MAX,
+        };
+        created_at.saturating_add(duration)
+    }
+
     #[must_use]
     fn require_initialized(env: &Env) -> Result<(), Error> {
         if !env.storage().instance().has(&ADMIN) {
@@ -1323,8 +2240,15 @@ impl MedicalRecordBackupContract {
         Ok(())
     }
 
+    /// Passes if `caller` is the admin, holds `role` in its coarse mask, or
+    /// has been granted `capability` explicitly.
     #[must_use]
-    fn require_role(env: &Env, caller: &Address, role: u32) -> Result<(), Error> {
+    fn require_role(
+        env: &Env,
+        caller: &Address,
+        role: u32,
+        capability: Option<Capability>,
+    ) -> Result<(), Error> {
         Self::require_initialized(env)?;
         let admin: Address = env
             .storage()
@@ -1339,25 +2263,47 @@ impl MedicalRecordBackupContract {
             .persistent()
             .get(&DataKey::Roles(caller.clone()))
             .unwrap_or(0u32);
-        if (mask & role) == 0 {
-            return Err(Error::NotAuthorized);
+        if (mask & role) != 0 {
+            return Ok(());
         }
-        Ok(())
+        if let Some(capability) = capability {
+            let granted: Vec<Capability> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Capabilities(caller.clone()))
+                .unwrap_or(Vec::new(env));
+            if granted.contains(capability) {
+                return Ok(());
+            }
+        }
+        Err(Error::NotAuthorized)
     }
 
     #[must_use]
-    fn require_operator(env: &Env, caller: &Address) -> Result<(), Error> {
-        Self::require_role(env, caller, ROLE_OPERATOR)
+    fn require_operator(
+        env: &Env,
+        caller: &Address,
+        capability: Option<Capability>,
+    ) -> Result<(), Error> {
+        Self::require_role(env, caller, ROLE_OPERATOR, capability)
     }
 
     #[must_use]
-    fn require_auditor(env: &Env, caller: &Address) -> Result<(), Error> {
-        Self::require_role(env, caller, ROLE_AUDITOR)
+    fn require_auditor(
+        env: &Env,
+        caller: &Address,
+        capability: Option<Capability>,
+    ) -> Result<(), Error> {
+        Self::require_role(env, caller, ROLE_AUDITOR, capability)
     }
 
     #[must_use]
-    fn require_recovery(env: &Env, caller: &Address) -> Result<(), Error> {
-        Self::require_role(env, caller, ROLE_RECOVERY)
+    fn require_recovery(
+        env: &Env,
+        caller: &Address,
+        capability: Option<Capability>,
+    ) -> Result<(), Error> {
+        Self::require_role(env, caller, ROLE_RECOVERY, capability)
     }
 
     #[must_use]
@@ -55,6 +55,7 @@ fn backup_run_creates_geo_redundant_artifact() {
         &sample_hash(&env, 7),
         &String::from_str(&env, "ipfs://snapshot-a"),
         &1,
+        &RetentionClass::Standard,
     );
     let artifact = client.get_artifact(&id).unwrap();
     assert_eq!(artifact.target_ids.len(), 2);
@@ -62,6 +63,64 @@ fn backup_run_creates_geo_redundant_artifact() {
     assert!(artifact.encrypted);
 }
 
+#[test]
+fn unencrypted_backup_rejected_by_encrypted_only_target() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let mut policy = client.get_policy();
+    policy.encryption_required = false;
+    client.set_policy(&admin, &policy);
+
+    let err = client.try_run_backup_now(
+        &admin,
+        &sample_hash(&env, 8),
+        &String::from_str(&env, "ipfs://plaintext"),
+        &0,
+        &RetentionClass::Standard,
+    );
+    assert_eq!(err, Err(Ok(Error::EncryptionRequired)));
+    assert!(client.list_artifacts(&true).is_empty());
+}
+
+#[test]
+fn unencrypted_backup_skips_encrypted_only_targets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+    let mut plaintext_ids = soroban_sdk::Vec::new(&env);
+    for (region, seed, cost) in [(GeoRegion::UsWest, 3, 20), (GeoRegion::EuWest, 4, 25)] {
+        plaintext_ids.push_back(client.register_target(
+            &admin,
+            &BackupNetwork::Ipfs,
+            &region,
+            &sample_hash(&env, seed),
+            &false,
+            &cost,
+            &1000,
+        ));
+    }
+
+    let mut policy = client.get_policy();
+    policy.encryption_required = false;
+    client.set_policy(&admin, &policy);
+
+    // The cheaper encrypted-only targets are passed over for plaintext
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 8),
+        &String::from_str(&env, "ipfs://plaintext"),
+        &0,
+        &RetentionClass::Standard,
+    );
+    let artifact = client.get_artifact(&id).unwrap();
+    assert!(!artifact.encrypted);
+    assert_eq!(artifact.target_ids, plaintext_ids);
+}
+
 #[test]
 fn scheduled_backup_respects_interval() {
     let env = Env::default();
@@ -72,6 +131,8 @@ fn scheduled_backup_respects_interval() {
     let policy = BackupPolicy {
         interval_seconds: 1_000,
         retention_seconds: 10_000,
+        short_term_retention_seconds: 10_000,
+        long_term_retention_seconds: 220_752_000,
         max_active_backups: 10,
         min_targets_per_backup: 2,
         min_region_count: 2,
@@ -80,6 +141,12 @@ fn scheduled_backup_respects_interval() {
         encryption_required: true,
         auto_cleanup: false,
         min_restore_approvals: 1,
+        restore_request_ttl: 86_400,
+        max_target_failures: 5,
+        cost_alert_threshold: 10_000,
+        schedule_grace_seconds: 3_600,
+        integrity_quorum: 0,
+        failure_escalation_streak: 5,
     };
     client.set_policy(&admin, &policy);
 
@@ -111,6 +178,7 @@ fn integrity_mismatch_creates_alert() {
         &sample_hash(&env, 3),
         &String::from_str(&env, "ipfs://snapshot-d"),
         &1,
+        &RetentionClass::Standard,
     );
     let ok = client.verify_backup_integrity(&admin, &id, &sample_hash(&env, 255));
     assert!(!ok);
@@ -131,6 +199,7 @@ fn restore_workflow_executes_after_approval() {
         &sample_hash(&env, 4),
         &String::from_str(&env, "ipfs://snapshot-restore"),
         &1,
+        &RetentionClass::Standard,
     );
     let request_id = client.request_restore(&admin, &id, &sample_hash(&env, 11));
     client.approve_restore(&admin, &request_id);
@@ -141,6 +210,42 @@ fn restore_workflow_executes_after_approval() {
     );
 }
 
+#[test]
+fn restore_validation_reports_broken_replica() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 5),
+        &String::from_str(&env, "ipfs://snapshot-drill"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let request_id = client.request_restore(&admin, &id, &sample_hash(&env, 12));
+    client.approve_restore(&admin, &request_id);
+    assert!(client.validate_restore(&admin, &request_id).viable);
+
+    let bad_target = client.get_artifact(&id).unwrap().target_ids.get(0).unwrap();
+    corrupt_replica(&env, &client, id, bad_target);
+    let findings = client.validate_restore(&admin, &request_id);
+    assert!(findings.approved);
+    assert!(findings.artifact_found);
+    assert!(!findings.replicas_intact);
+    assert!(!findings.viable);
+    assert_eq!(
+        client.get_restore_request(&request_id).unwrap().status,
+        RestoreStatus::Approved
+    );
+
+    assert_eq!(
+        client.try_execute_restore(&admin, &request_id),
+        Err(Ok(Error::IntegrityMismatch))
+    );
+}
+
 #[test]
 fn cleanup_archives_old_backups() {
     let env = Env::default();
@@ -153,6 +258,8 @@ fn cleanup_archives_old_backups() {
         &BackupPolicy {
             interval_seconds: 1,
             retention_seconds: 2,
+            short_term_retention_seconds: 2,
+            long_term_retention_seconds: 220_752_000,
             max_active_backups: 1,
             min_targets_per_backup: 2,
             min_region_count: 2,
@@ -161,6 +268,12 @@ fn cleanup_archives_old_backups() {
             encryption_required: true,
             auto_cleanup: false,
             min_restore_approvals: 1,
+            restore_request_ttl: 86_400,
+            max_target_failures: 5,
+            cost_alert_threshold: 10_000,
+            schedule_grace_seconds: 3_600,
+            integrity_quorum: 0,
+            failure_escalation_streak: 5,
         },
     );
 
@@ -170,6 +283,7 @@ fn cleanup_archives_old_backups() {
         &sample_hash(&env, 21),
         &String::from_str(&env, "ipfs://old"),
         &1,
+        &RetentionClass::Standard,
     );
     env.ledger().set_timestamp(2000);
     client.run_backup_now(
@@ -177,8 +291,1027 @@ fn cleanup_archives_old_backups() {
         &sample_hash(&env, 22),
         &String::from_str(&env, "ipfs://new"),
         &1,
+        &RetentionClass::Standard,
     );
     env.ledger().set_timestamp(3000);
     let report = client.optimize_and_cleanup(&admin);
     assert!(report.archived_backups >= 1);
 }
+
+#[test]
+fn permanent_backup_survives_cleanup_of_expired_short_term() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let mut policy = client.get_policy();
+    policy.short_term_retention_seconds = 100;
+    policy.auto_cleanup = false;
+    client.set_policy(&admin, &policy);
+
+    env.ledger().set_timestamp(1_000);
+    let short = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 23),
+        &String::from_str(&env, "ipfs://short"),
+        &1,
+        &RetentionClass::ShortTerm,
+    );
+    let permanent = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 24),
+        &String::from_str(&env, "ipfs://permanent"),
+        &1,
+        &RetentionClass::Permanent,
+    );
+    assert_eq!(client.get_artifact(&short).unwrap().expires_at, 1_100);
+    assert_eq!(
+        client.get_artifact(&permanent).unwrap().expires_at,
+        u64::MAX
+    );
+
+    env.ledger().set_timestamp(u64::MAX);
+    let report = client.optimize_and_cleanup(&admin);
+    assert_eq!(report.archived_backups, 1);
+    assert_eq!(
+        client.get_artifact(&short).unwrap().status,
+        BackupStatus::Archived
+    );
+    assert_ne!(
+        client.get_artifact(&permanent).unwrap().status,
+        BackupStatus::Archived
+    );
+}
+
+#[test]
+fn full_target_is_skipped_until_capacity_is_reclaimed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    let small = client.register_target(
+        &admin,
+        &BackupNetwork::Ipfs,
+        &GeoRegion::UsEast,
+        &sample_hash(&env, 1),
+        &true,
+        &10,
+        &1,
+    );
+    client.register_target(
+        &admin,
+        &BackupNetwork::Arweave,
+        &GeoRegion::EuCentral,
+        &sample_hash(&env, 2),
+        &true,
+        &15,
+        &1000,
+    );
+
+    let first = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 31),
+        &String::from_str(&env, "ipfs://fill"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    assert!(client
+        .get_artifact(&first)
+        .unwrap()
+        .target_ids
+        .contains(small));
+    assert_eq!(client.get_target(&small).unwrap().used_capacity_units, 1);
+
+    let err = client.try_run_backup_now(
+        &admin,
+        &sample_hash(&env, 32),
+        &String::from_str(&env, "ipfs://overflow"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    assert_eq!(err, Err(Ok(Error::InsufficientTargets)));
+
+    let spare = client.register_target(
+        &admin,
+        &BackupNetwork::Filecoin,
+        &GeoRegion::UsEast,
+        &sample_hash(&env, 3),
+        &true,
+        &10,
+        &1000,
+    );
+    let second = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 33),
+        &String::from_str(&env, "ipfs://spare"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let targets = client.get_artifact(&second).unwrap().target_ids;
+    assert!(!targets.contains(small));
+    assert!(targets.contains(spare));
+
+    env.ledger().set_timestamp(u64::MAX);
+    client.optimize_and_cleanup(&admin);
+    assert_eq!(client.get_target(&small).unwrap().used_capacity_units, 0);
+}
+
+#[test]
+fn selection_picks_cheapest_set_meeting_region_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let targets = [
+        (BackupNetwork::Ipfs, GeoRegion::UsEast, 2u32),
+        (BackupNetwork::Filecoin, GeoRegion::UsEast, 3),
+        (BackupNetwork::Arweave, GeoRegion::EuCentral, 30),
+        (BackupNetwork::AwsS3, GeoRegion::EuCentral, 9),
+        (BackupNetwork::GcpStorage, GeoRegion::ApSouth, 20),
+    ];
+    let mut ids = [0u32; 5];
+    for (i, (network, region, cost)) in targets.iter().enumerate() {
+        ids[i] = client.register_target(
+            &admin,
+            network,
+            region,
+            &sample_hash(&env, i as u8 + 1),
+            &true,
+            cost,
+            &1000,
+        );
+    }
+
+    client.set_policy(
+        &admin,
+        &BackupPolicy {
+            interval_seconds: 1,
+            retention_seconds: 10_000,
+            short_term_retention_seconds: 10_000,
+            long_term_retention_seconds: 220_752_000,
+            max_active_backups: 10,
+            min_targets_per_backup: 3,
+            min_region_count: 2,
+            max_total_cost_weight: 1_000,
+            verify_on_write: true,
+            encryption_required: true,
+            auto_cleanup: false,
+            min_restore_approvals: 1,
+            restore_request_ttl: 86_400,
+            max_target_failures: 5,
+            cost_alert_threshold: 10_000,
+            schedule_grace_seconds: 3_600,
+            integrity_quorum: 0,
+            failure_escalation_streak: 5,
+        },
+    );
+
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 40),
+        &String::from_str(&env, "ipfs://weighted"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let artifact = client.get_artifact(&id).unwrap();
+    assert_eq!(artifact.target_ids.len(), 3);
+    assert!(artifact.target_ids.contains(ids[0]));
+    assert!(artifact.target_ids.contains(ids[1]));
+    assert!(artifact.target_ids.contains(ids[3]));
+    assert_eq!(artifact.region_count, 2);
+    assert_eq!(artifact.total_cost_weight, 14);
+}
+
+#[test]
+fn region_coverage_survives_candidate_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    // More cheap UsEast targets than the selection keeps, and one pricier
+    // EuCentral target that is the only way to cover a second region.
+    for i in 0..(MAX_SELECTION_CANDIDATES + 2) {
+        client.register_target(
+            &admin,
+            &BackupNetwork::Ipfs,
+            &GeoRegion::UsEast,
+            &sample_hash(&env, i as u8 + 1),
+            &true,
+            &1,
+            &1000,
+        );
+    }
+    let eu = client.register_target(
+        &admin,
+        &BackupNetwork::Arweave,
+        &GeoRegion::EuCentral,
+        &sample_hash(&env, 200),
+        &true,
+        &50,
+        &1000,
+    );
+
+    let mix = client.suggest_target_mix(&2, &2);
+    assert_eq!(mix.len(), 2);
+    assert!(mix.contains(eu));
+
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 41),
+        &String::from_str(&env, "ipfs://capped"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let artifact = client.get_artifact(&id).unwrap();
+    assert!(artifact.target_ids.contains(eu));
+    assert_eq!(artifact.region_count, 2);
+}
+
+#[test]
+fn suggested_target_mix_is_cost_optimal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let targets = [
+        (BackupNetwork::Ipfs, GeoRegion::UsEast, 2u32, true),
+        (BackupNetwork::Filecoin, GeoRegion::UsEast, 3, true),
+        (BackupNetwork::Arweave, GeoRegion::EuCentral, 30, true),
+        (BackupNetwork::AwsS3, GeoRegion::EuCentral, 9, true),
+        (BackupNetwork::GcpStorage, GeoRegion::ApSouth, 20, true),
+        (BackupNetwork::AzureBlob, GeoRegion::ApSouth, 1, false),
+    ];
+    let mut ids = [0u32; 6];
+    for (i, (network, region, cost, encrypted_only)) in targets.iter().enumerate() {
+        ids[i] = client.register_target(
+            &admin,
+            network,
+            region,
+            &sample_hash(&env, i as u8 + 1),
+            encrypted_only,
+            cost,
+            &1000,
+        );
+    }
+    let inactive = client.register_target(
+        &admin,
+        &BackupNetwork::Polygon,
+        &GeoRegion::SaEast,
+        &sample_hash(&env, 9),
+        &true,
+        &1,
+        &1000,
+    );
+    client.set_target_active(&admin, &inactive, &false);
+
+    // The plaintext-capable AzureBlob target is skipped while the policy
+    // requires encryption.
+    assert_eq!(
+        client.suggest_target_mix(&2, &3),
+        Vec::from_array(&env, [ids[0], ids[3], ids[1]])
+    );
+    assert_eq!(
+        client.suggest_target_mix(&3, &3),
+        Vec::from_array(&env, [ids[0], ids[3], ids[4]])
+    );
+    assert_eq!(client.suggest_target_mix(&4, &4), Vec::new(&env));
+    assert_eq!(client.suggest_target_mix(&1, &6), Vec::new(&env));
+
+    let mut policy = client.get_policy();
+    policy.encryption_required = false;
+    client.set_policy(&admin, &policy);
+    assert_eq!(
+        client.suggest_target_mix(&2, &2),
+        Vec::from_array(&env, [ids[5], ids[0]])
+    );
+    assert!(client.list_artifacts(&true).is_empty());
+}
+
+#[test]
+fn expired_restore_request_cannot_be_approved_or_executed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    env.ledger().set_timestamp(1_000);
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 5),
+        &String::from_str(&env, "ipfs://snapshot-expiry"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let ttl = client.get_policy().restore_request_ttl;
+
+    let unapproved = client.request_restore(&admin, &id, &sample_hash(&env, 12));
+    let approved = client.request_restore(&admin, &id, &sample_hash(&env, 13));
+    client.approve_restore(&admin, &approved);
+
+    env.ledger().set_timestamp(1_000 + ttl);
+    assert_eq!(
+        client.try_approve_restore(&admin, &unapproved),
+        Err(Ok(Error::RestoreRequestExpired))
+    );
+    assert_eq!(
+        client.try_execute_restore(&admin, &approved),
+        Err(Ok(Error::RestoreRequestExpired))
+    );
+
+    client.expire_restore_request(&admin, &approved);
+    let request = client.get_restore_request(&approved).unwrap();
+    assert_eq!(request.status, RestoreStatus::Rejected);
+}
+
+#[test]
+fn incremental_backups_validate_parent_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    client.set_policy(
+        &admin,
+        &BackupPolicy {
+            interval_seconds: 1,
+            retention_seconds: 1_500,
+            short_term_retention_seconds: 1_500,
+            long_term_retention_seconds: 220_752_000,
+            max_active_backups: 10,
+            min_targets_per_backup: 2,
+            min_region_count: 2,
+            max_total_cost_weight: 1_000,
+            verify_on_write: true,
+            encryption_required: true,
+            auto_cleanup: false,
+            min_restore_approvals: 1,
+            restore_request_ttl: 86_400,
+            max_target_failures: 5,
+            cost_alert_threshold: 10_000,
+            schedule_grace_seconds: 3_600,
+            integrity_quorum: 0,
+            failure_escalation_streak: 5,
+        },
+    );
+
+    env.ledger().set_timestamp(1_000);
+    let base = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 50),
+        &String::from_str(&env, "ipfs://base"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    env.ledger().set_timestamp(2_000);
+    let first = client.execute_incremental_backup(
+        &admin,
+        &base,
+        &sample_hash(&env, 51),
+        &String::from_str(&env, "ipfs://delta-1"),
+        &1,
+    );
+    env.ledger().set_timestamp(2_100);
+    let second = client.execute_incremental_backup(
+        &admin,
+        &first,
+        &sample_hash(&env, 52),
+        &String::from_str(&env, "ipfs://delta-2"),
+        &1,
+    );
+
+    let artifact = client.get_artifact(&second).unwrap();
+    assert_eq!(artifact.parent_artifact_id, Some(first));
+    assert_eq!(artifact.source_root, sample_hash(&env, 52));
+    assert!(client.verify_backup_integrity(&admin, &second, &artifact.checksum));
+    let test_id = client.run_recovery_test(&admin, &second, &artifact.checksum);
+    assert!(client.get_recovery_test(&test_id).unwrap().passed);
+    let restore_id = client.request_restore(&admin, &second, &sample_hash(&env, 54));
+    client.approve_restore(&admin, &restore_id);
+    assert!(client.validate_restore(&admin, &restore_id).chain_intact);
+
+    // Only the base has outlived its retention; it is kept for its children.
+    env.ledger().set_timestamp(2_600);
+    assert_eq!(client.optimize_and_cleanup(&admin).archived_backups, 0);
+    assert!(client.verify_backup_integrity(&admin, &second, &artifact.checksum));
+
+    // Losing an ancestor by other means still breaks the chain.
+    env.as_contract(&client.address, || {
+        let key = DataKey::Artifact(base);
+        let mut lost: BackupArtifact = env.storage().persistent().get(&key).unwrap();
+        lost.status = BackupStatus::Archived;
+        env.storage().persistent().set(&key, &lost);
+    });
+    assert_eq!(
+        client.try_verify_backup_integrity(&admin, &second, &artifact.checksum),
+        Err(Ok(Error::BrokenBackupChain))
+    );
+    assert_eq!(
+        client.try_run_recovery_test(&admin, &second, &artifact.checksum),
+        Err(Ok(Error::BrokenBackupChain))
+    );
+    let findings = client.validate_restore(&admin, &restore_id);
+    assert!(findings.replicas_intact);
+    assert!(!findings.chain_intact);
+    assert!(!findings.viable);
+    assert_eq!(
+        client.try_execute_restore(&admin, &restore_id),
+        Err(Ok(Error::BrokenBackupChain))
+    );
+    assert_eq!(
+        client.try_execute_incremental_backup(
+            &admin,
+            &base,
+            &sample_hash(&env, 53),
+            &String::from_str(&env, "ipfs://delta-3"),
+            &1,
+        ),
+        Err(Ok(Error::BrokenBackupChain))
+    );
+}
+
+#[test]
+fn cleanup_archives_incremental_chain_from_the_newest_end() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let mut policy = client.get_policy();
+    policy.short_term_retention_seconds = 100;
+    policy.auto_cleanup = false;
+    client.set_policy(&admin, &policy);
+
+    env.ledger().set_timestamp(1_000);
+    let base = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 60),
+        &String::from_str(&env, "ipfs://base"),
+        &1,
+        &RetentionClass::ShortTerm,
+    );
+    env.ledger().set_timestamp(1_050);
+    let delta = client.execute_incremental_backup(
+        &admin,
+        &base,
+        &sample_hash(&env, 61),
+        &String::from_str(&env, "ipfs://delta"),
+        &1,
+    );
+
+    // The base has expired but its incremental has not
+    env.ledger().set_timestamp(1_120);
+    assert_eq!(client.optimize_and_cleanup(&admin).archived_backups, 0);
+    assert_ne!(
+        client.get_artifact(&base).unwrap().status,
+        BackupStatus::Archived
+    );
+
+    // Both expired: the incremental goes first, then the base is free to go
+    env.ledger().set_timestamp(1_200);
+    assert_eq!(client.optimize_and_cleanup(&admin).archived_backups, 1);
+    assert_eq!(
+        client.get_artifact(&delta).unwrap().status,
+        BackupStatus::Archived
+    );
+    assert_ne!(
+        client.get_artifact(&base).unwrap().status,
+        BackupStatus::Archived
+    );
+    assert_eq!(client.optimize_and_cleanup(&admin).archived_backups, 1);
+    assert_eq!(
+        client.get_artifact(&base).unwrap().status,
+        BackupStatus::Archived
+    );
+}
+
+#[test]
+fn failing_target_is_disabled_at_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+    let backup = client.register_target(
+        &admin,
+        &BackupNetwork::Filecoin,
+        &GeoRegion::UsWest,
+        &sample_hash(&env, 3),
+        &true,
+        &20,
+        &1000,
+    );
+
+    let mut policy = client.get_policy();
+    policy.max_target_failures = 3;
+    client.set_policy(&admin, &policy);
+
+    let flaky = 1u32;
+    for i in 0..3u8 {
+        assert!(client.get_target(&flaky).unwrap().is_active);
+        client.report_target_failure(&admin, &flaky, &sample_hash(&env, 60 + i));
+    }
+    let target = client.get_target(&flaky).unwrap();
+    assert!(!target.is_active);
+    assert_eq!(target.failure_count, 3);
+    let alerts = client.list_alerts(&true);
+    let last = alerts.get(alerts.len() - 1).unwrap();
+    assert_eq!(last.kind, AlertKind::TargetFailure);
+    assert_eq!(last.severity, AlertSeverity::Critical);
+
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 64),
+        &String::from_str(&env, "ipfs://without-flaky"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let targets = client.get_artifact(&id).unwrap().target_ids;
+    assert!(!targets.contains(flaky));
+    assert!(targets.contains(backup));
+
+    client.reset_target_failures(&admin, &flaky);
+    let target = client.get_target(&flaky).unwrap();
+    assert!(target.is_active);
+    assert_eq!(target.failure_count, 0);
+}
+
+#[test]
+fn repaired_replica_restores_verified_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(&env, 70),
+        &String::from_str(&env, "ipfs://snapshot-repair"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    let artifact = client.get_artifact(&id).unwrap();
+    let bad_target = artifact.target_ids.get(0).unwrap();
+
+    env.as_contract(&client.address, || {
+        let key = DataKey::Replica(id, bad_target);
+        let mut replica: BackupReplica = env.storage().persistent().get(&key).unwrap();
+        replica.checksum = sample_hash(&env, 255);
+        env.storage().persistent().set(&key, &replica);
+    });
+    assert!(!client.verify_backup_integrity(&admin, &id, &artifact.checksum));
+    assert_eq!(
+        client.get_artifact(&id).unwrap().status,
+        BackupStatus::Failed
+    );
+
+    // A re-sync that still does not match leaves the bad replica in place
+    assert_eq!(
+        client.try_repair_replica(&admin, &id, &bad_target, &sample_hash(&env, 254)),
+        Err(Ok(Error::IntegrityMismatch))
+    );
+    env.as_contract(&client.address, || {
+        let replica: BackupReplica = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Replica(id, bad_target))
+            .unwrap();
+        assert_eq!(replica.checksum, sample_hash(&env, 255));
+    });
+    assert_eq!(
+        client.get_artifact(&id).unwrap().status,
+        BackupStatus::Failed
+    );
+
+    assert!(client.repair_replica(&admin, &id, &bad_target, &artifact.checksum));
+    assert_eq!(
+        client.get_artifact(&id).unwrap().status,
+        BackupStatus::Verified
+    );
+    assert_eq!(
+        client.try_repair_replica(&admin, &id, &bad_target, &artifact.checksum),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+fn corrupt_replica(
+    env: &Env,
+    client: &MedicalRecordBackupContractClient<'_>,
+    id: u64,
+    target: u32,
+) {
+    env.as_contract(&client.address, || {
+        let key = DataKey::Replica(id, target);
+        let mut replica: BackupReplica = env.storage().persistent().get(&key).unwrap();
+        replica.checksum = sample_hash(env, 255);
+        env.storage().persistent().set(&key, &replica);
+    });
+}
+
+/// Three replicas across three regions, verified under a 2-of-3 quorum.
+fn quorum_backup(env: &Env) -> (MedicalRecordBackupContractClient<'_>, Address, u64) {
+    let (client, admin) = setup(env);
+    register_two_targets(&client, &admin, env);
+    client.register_target(
+        &admin,
+        &BackupNetwork::AwsS3,
+        &GeoRegion::ApSouth,
+        &sample_hash(env, 3),
+        &true,
+        &20,
+        &1000,
+    );
+    let mut policy = client.get_policy();
+    policy.min_targets_per_backup = 3;
+    policy.integrity_quorum = 2;
+    client.set_policy(&admin, &policy);
+
+    let id = client.run_backup_now(
+        &admin,
+        &sample_hash(env, 75),
+        &String::from_str(env, "ipfs://snapshot-quorum"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    (client, admin, id)
+}
+
+#[test]
+fn integrity_quorum_tolerates_one_bad_replica() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, id) = quorum_backup(&env);
+    let artifact = client.get_artifact(&id).unwrap();
+    assert_eq!(artifact.target_ids.len(), 3);
+    let bad_target = artifact.target_ids.get(0).unwrap();
+    corrupt_replica(&env, &client, id, bad_target);
+
+    assert!(client.verify_backup_integrity(&admin, &id, &artifact.checksum));
+    assert_eq!(
+        client.get_artifact(&id).unwrap().status,
+        BackupStatus::Verified
+    );
+    env.as_contract(&client.address, || {
+        let replica: BackupReplica = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Replica(id, bad_target))
+            .unwrap();
+        assert_eq!(replica.status, ReplicaStatus::Failed);
+    });
+
+    // The quorum that verified the artifact is also enough to restore it.
+    let request_id = client.request_restore(&admin, &id, &sample_hash(&env, 13));
+    client.approve_restore(&admin, &request_id);
+    assert!(client.validate_restore(&admin, &request_id).replicas_intact);
+    corrupt_replica(&env, &client, id, artifact.target_ids.get(1).unwrap());
+    assert_eq!(
+        client.try_execute_restore(&admin, &request_id),
+        Err(Ok(Error::QuorumNotMet))
+    );
+    // Repairing one of the two bad replicas brings the quorum back.
+    client.repair_replica(&admin, &id, &bad_target, &artifact.checksum);
+    assert_eq!(
+        client.execute_restore(&admin, &request_id),
+        String::from_str(&env, "ipfs://snapshot-quorum")
+    );
+}
+
+#[test]
+fn set_policy_rejects_quorum_above_min_targets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    let mut policy = client.get_policy();
+    policy.integrity_quorum = policy.min_targets_per_backup + 1;
+    assert_eq!(
+        client.try_set_policy(&admin, &policy),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn integrity_quorum_fails_with_two_bad_replicas() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, id) = quorum_backup(&env);
+    let artifact = client.get_artifact(&id).unwrap();
+    let bad_target = artifact.target_ids.get(0).unwrap();
+    corrupt_replica(&env, &client, id, bad_target);
+    corrupt_replica(&env, &client, id, artifact.target_ids.get(1).unwrap());
+
+    // The failed check is recorded rather than rolled back
+    assert!(!client.verify_backup_integrity(&admin, &id, &artifact.checksum));
+    assert_eq!(
+        client.get_artifact(&id).unwrap().status,
+        BackupStatus::Failed
+    );
+    env.as_contract(&client.address, || {
+        let replica: BackupReplica = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Replica(id, bad_target))
+            .unwrap();
+        assert_eq!(replica.status, ReplicaStatus::Failed);
+    });
+    let alerts = client.list_alerts(&true);
+    let alert = alerts.last().unwrap();
+    assert_eq!(alert.kind, AlertKind::IntegrityCheckFailed);
+    assert_eq!(
+        alert.details_hash,
+        env.as_contract(&client.address, || {
+            MedicalRecordBackupContract::compute_reason_hash(&env, Error::QuorumNotMet as u32, id)
+        })
+    );
+}
+
+#[test]
+fn cost_alert_fires_once_when_threshold_crossed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let mut policy = client.get_policy();
+    policy.cost_alert_threshold = 60;
+    policy.auto_cleanup = false;
+    client.set_policy(&admin, &policy);
+
+    let cost_alerts = |client: &MedicalRecordBackupContractClient<'_>| {
+        client
+            .list_alerts(&false)
+            .iter()
+            .filter(|a| a.kind == AlertKind::CostThresholdExceeded)
+            .count()
+    };
+
+    for i in 0..2u8 {
+        client.run_backup_now(
+            &admin,
+            &sample_hash(&env, 80 + i),
+            &String::from_str(&env, "ipfs://under"),
+            &1,
+            &RetentionClass::Standard,
+        );
+    }
+    assert_eq!(client.get_cost_summary(), (50, 60));
+    assert_eq!(cost_alerts(&client), 0);
+
+    for i in 2..4u8 {
+        client.run_backup_now(
+            &admin,
+            &sample_hash(&env, 80 + i),
+            &String::from_str(&env, "ipfs://over"),
+            &1,
+            &RetentionClass::Standard,
+        );
+    }
+    assert_eq!(client.get_cost_summary(), (100, 60));
+    assert_eq!(cost_alerts(&client), 1);
+}
+
+#[test]
+fn alert_severity_escalates_with_streak() {
+    assert_eq!(get_alert_severity_for_streak(1), AlertSeverity::Low);
+    assert_eq!(get_alert_severity_for_streak(2), AlertSeverity::Medium);
+    assert_eq!(get_alert_severity_for_streak(3), AlertSeverity::High);
+    assert_eq!(get_alert_severity_for_streak(4), AlertSeverity::Critical);
+    assert_eq!(get_alert_severity_for_streak(40), AlertSeverity::Critical);
+}
+
+#[test]
+fn consecutive_failures_escalate_backup_alerts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    let mut policy = client.get_policy();
+    policy.failure_escalation_streak = 3;
+    client.set_policy(&admin, &policy);
+
+    // With no targets every run fails; the failed call itself writes
+    // nothing, so each failure is logged with record_backup_failure.
+    for _ in 0..3 {
+        let err = client.try_run_backup_now(
+            &admin,
+            &sample_hash(&env, 60),
+            &String::from_str(&env, "ipfs://no-targets"),
+            &1,
+            &RetentionClass::Standard,
+        );
+        assert_eq!(err, Err(Ok(Error::InsufficientTargets)));
+        let execution = client.record_backup_failure(&admin, &1, &false);
+        assert_eq!(execution.artifact_id, None);
+        assert_eq!(
+            execution.error_code,
+            Some(Error::InsufficientTargets as u32)
+        );
+    }
+
+    let alerts = client.list_alerts(&true);
+    let mut severities = Vec::new(&env);
+    for alert in alerts.iter() {
+        if alert.kind == AlertKind::BackupFailure {
+            severities.push_back(alert.severity);
+        }
+    }
+    assert_eq!(
+        severities,
+        Vec::from_array(
+            &env,
+            [
+                AlertSeverity::Low,
+                AlertSeverity::Medium,
+                AlertSeverity::High
+            ]
+        )
+    );
+    assert_eq!(
+        alerts
+            .iter()
+            .filter(|a| a.kind == AlertKind::FailureStreak)
+            .count(),
+        1
+    );
+    assert_eq!(client.get_health().consecutive_failures, 3);
+
+    // Once a backup can be placed there is no failure to record.
+    register_two_targets(&client, &admin, &env);
+    assert_eq!(
+        client.try_record_backup_failure(&admin, &1, &false),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn missed_schedule_raises_alert_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let missed_alerts = |client: &MedicalRecordBackupContractClient<'_>| {
+        client
+            .list_alerts(&true)
+            .iter()
+            .filter(|a| a.kind == AlertKind::ScheduleMissed)
+            .count()
+    };
+
+    assert!(!client.check_schedule_health(&admin));
+    env.ledger().set_timestamp(100_000);
+    assert!(client.check_schedule_health(&admin));
+    assert!(client.check_schedule_health(&admin));
+    assert_eq!(missed_alerts(&client), 1);
+    assert_eq!(client.get_health().consecutive_failures, 1);
+
+    client.run_scheduled_backup(
+        &admin,
+        &sample_hash(&env, 90),
+        &String::from_str(&env, "ipfs://catch-up"),
+        &1,
+    );
+    assert!(!client.check_schedule_health(&admin));
+    assert_eq!(client.get_health().consecutive_failures, 0);
+}
+
+#[test]
+fn artifact_and_alert_listings_paginate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let mut policy = client.get_policy();
+    policy.max_active_backups = 1_000;
+    policy.auto_cleanup = false;
+    policy.cost_alert_threshold = 100_000;
+    client.set_policy(&admin, &policy);
+
+    for i in 0..120u32 {
+        client.run_backup_now(
+            &admin,
+            &sample_hash(&env, i as u8),
+            &String::from_str(&env, "ipfs://paged"),
+            &1,
+            &RetentionClass::Standard,
+        );
+    }
+
+    let (first, more) = client.list_artifacts_paged(&false, &0, &50);
+    assert_eq!(first.len(), 50);
+    assert!(more);
+    assert_eq!(first.get(0).unwrap().artifact_id, 1);
+    assert_eq!(first.get(49).unwrap().artifact_id, 50);
+
+    let (second, more) = client.list_artifacts_paged(&false, &1, &50);
+    assert_eq!(second.len(), 50);
+    assert!(more);
+    assert_eq!(second.get(0).unwrap().artifact_id, 51);
+
+    let (last, more) = client.list_artifacts_paged(&false, &2, &50);
+    assert_eq!(last.len(), 20);
+    assert!(!more);
+    assert_eq!(last.get(19).unwrap().artifact_id, 120);
+
+    let (empty, more) = client.list_artifacts_paged(&false, &3, &50);
+    assert!(empty.is_empty());
+    assert!(!more);
+
+    let (capped, _) = client.list_artifacts_paged(&true, &0, &500);
+    assert_eq!(capped.len(), 50);
+
+    // Unfiltered pages are sliced straight from the id list
+    let (tail, more) = client.list_artifacts_paged(&true, &2, &50);
+    assert_eq!(tail.len(), 20);
+    assert!(!more);
+    assert_eq!(tail.get(0).unwrap().artifact_id, 101);
+    let (past_end, more) = client.list_artifacts_paged(&true, &9, &50);
+    assert!(past_end.is_empty());
+    assert!(!more);
+
+    for i in 0..3u8 {
+        client.report_target_failure(&admin, &1, &sample_hash(&env, 200 + i));
+    }
+    let (alerts, more) = client.list_alerts_paged(&true, &0, &2);
+    assert_eq!(alerts.len(), 2);
+    assert!(more);
+    let (alerts, more) = client.list_alerts_paged(&true, &1, &2);
+    assert_eq!(alerts.len(), 1);
+    assert!(!more);
+    let (all, more) = client.list_alerts_paged(&false, &0, &50);
+    assert_eq!(all.len(), client.list_alerts(&false).len());
+    assert!(!more);
+}
+
+#[test]
+fn run_backup_capability_does_not_grant_target_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let runner = Address::generate(&env);
+    client.grant_capability(&admin, &runner, &Capability::RunBackup);
+
+    let id = client.run_backup_now(
+        &runner,
+        &sample_hash(&env, 95),
+        &String::from_str(&env, "ipfs://granted"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    assert!(client.get_artifact(&id).is_some());
+
+    let err = client.try_register_target(
+        &runner,
+        &BackupNetwork::Filecoin,
+        &GeoRegion::UsWest,
+        &sample_hash(&env, 96),
+        &true,
+        &10,
+        &1000,
+    );
+    assert_eq!(err, Err(Ok(Error::NotAuthorized)));
+
+    client.revoke_capability(&admin, &runner, &Capability::RunBackup);
+    let err = client.try_run_backup_now(
+        &runner,
+        &sample_hash(&env, 97),
+        &String::from_str(&env, "ipfs://revoked"),
+        &1,
+        &RetentionClass::Standard,
+    );
+    assert_eq!(err, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn key_rotation_flags_older_artifacts_as_stale() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+    register_two_targets(&client, &admin, &env);
+
+    let mut ids = [0u64; 3];
+    for (i, version) in [1u32, 2, 3].iter().enumerate() {
+        ids[i] = client.run_backup_now(
+            &admin,
+            &sample_hash(&env, 100 + i as u8),
+            &String::from_str(&env, "ipfs://keyed"),
+            version,
+            &RetentionClass::Standard,
+        );
+    }
+
+    client.set_current_key_version(&admin, &3);
+    assert_eq!(client.get_current_key_version(), 3);
+    assert_eq!(
+        client.try_set_current_key_version(&admin, &2),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let (stale, more) = client.get_stale_encryption_artifacts(&0, &10);
+    assert!(!more);
+    assert_eq!(stale.len(), 2);
+    assert_eq!(stale.get(0).unwrap().artifact_id, ids[0]);
+    assert_eq!(stale.get(1).unwrap().artifact_id, ids[1]);
+    assert!(stale.iter().all(|a| a.artifact_id != ids[2]));
+}
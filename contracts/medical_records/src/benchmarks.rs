@@ -356,3 +356,35 @@ fn bench_write_record_with_count(existing: u64) {
         cost
     );
 }
+
+#[test]
+fn bench_record_reads_touch_own_slot_200_records() {
+    let env = Env::default();
+    let (client, _admin, doctor, patient) = setup_contract(&env);
+
+    populate_records(&env, &client, &doctor, &patient, 200);
+
+    env.as_contract(&client.address, || {
+        let baseline = measure_cpu(&env, || {
+            Records::load(&env, 1).unwrap();
+        });
+
+        for id in 1..=200u64 {
+            let mut loaded = None;
+            let cost = measure_cpu(&env, || loaded = Records::load(&env, id));
+            let record = loaded.unwrap();
+            assert_eq!(
+                record.data_ref,
+                String::from_str(&env, &format!("ipfs://record{}", id - 1))
+            );
+            // A read loads a single slot, so its cost does not grow with the
+            // number of stored records.
+            assert!(cost <= baseline + baseline / 10);
+        }
+
+        std::println!(
+            "[STORAGE-BENCH] record_read records=200 cpu_cost={}",
+            baseline
+        );
+    });
+}
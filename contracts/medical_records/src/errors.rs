@@ -11,69 +11,82 @@ pub enum Error {
     EmergencyAccessExpired = 160,
     RecordRetentionExpired = 170,
 
-    // --- Input Validation (1100–1199) ---
-    InvalidPagination = 1202,
-    InputTooLong = 1201,
-    BatchTooLarge = 1208,
-    InvalidSignature = 1207,
-    InvalidDataRefLength = 1250,
-    InvalidDataRefCharset = 1251,
-    InvalidDiagnosisLength = 1252,
-    InvalidTreatmentLength = 1253,
-    InvalidPurposeLength = 1254,
-    InvalidTagLength = 1255,
-    InvalidModelVersionLength = 1256,
-    InvalidExplanationLength = 1257,
-    InvalidTreatmentTypeLength = 1258,
-    InvalidAddress = 1290,
-    SameAddress = 1291,
-    InvalidBatch = 1292,
-    NumberOutOfBounds = 1293,
-    InvalidCategory = 1280,
-    EmptyTreatment = 1281,
-    EmptyDiagnosis = 1282,
-    EmptyTag = 1283,
-    EmptyDataRef = 1284,
+    // --- Input Validation (200–299) ---
+    InvalidInput = 200,
+    InvalidPagination = 202,
+    InputTooLong = 201,
+    BatchTooLarge = 208,
+    InvalidSignature = 207,
+    InvalidDataRefLength = 250,
+    InvalidDataRefCharset = 251,
+    InvalidDiagnosisLength = 252,
+    InvalidTreatmentLength = 253,
+    InvalidPurposeLength = 254,
+    InvalidTagLength = 255,
+    InvalidModelVersionLength = 256,
+    InvalidExplanationLength = 257,
+    InvalidTreatmentTypeLength = 258,
+    InvalidAddress = 290,
+    SameAddress = 291,
+    InvalidBatch = 292,
+    NumberOutOfBounds = 293,
+    InvalidCategory = 280,
+    EmptyTreatment = 281,
+    EmptyDiagnosis = 282,
+    EmptyTag = 283,
+    EmptyDataRef = 284,
 
-    // --- Lifecycle & State (1200–1299) ---
-    ProposalAlreadyExecuted = 1320,
-    TimelockNotElapsed = 1321,
-    NotEnoughApproval = 1322,
-    CryptoRegistryNotSet = 1340,
-    EncryptionRequired = 1341,
-    IdentityRegistryNotSet = 1342,
-    InvalidVersion = 1350,
-    VersionNotFound = 1351,
+    // --- Lifecycle & State (300–399) ---
+    NotInitialized = 300,
+    AlreadyInitialized = 301,
+    ContractPaused = 302,
+    DeadlineExceeded = 306,
+    RateLimitExceeded = 307,
+    ReentrantCall = 308,
+    NoChange = 309,
+    ProposalAlreadyExecuted = 320,
+    TimelockNotElapsed = 321,
+    NotEnoughApproval = 322,
+    CryptoRegistryNotSet = 340,
+    EncryptionRequired = 341,
+    IdentityRegistryNotSet = 342,
+    InvalidVersion = 350,
+    VersionNotFound = 351,
 
-    // --- Entity Existence (1300–1399) ---
-    RecordNotFound = 1403,
-    EmergencyAccessNotFound = 1460,
-    DIDNotFound = 1470,
-    DIDNotActive = 1471,
-    RecordAlreadySynced = 1480,
+    // --- Entity Existence (400–499) ---
+    RecordNotFound = 403,
+    UserNotFound = 404,
+    ActionNotFound = 405,
+    EmergencyAccessNotFound = 460,
+    DIDNotFound = 470,
+    DIDNotActive = 471,
+    RecordAlreadySynced = 480,
 
-    // --- Financial & Resource (1400–1499) ---
-    StorageFull = 1502,
+    // --- Financial & Resource (500–599) ---
+    InsufficientFunds = 500,
+    TransferFailed = 501,
+    StorageFull = 502,
+    Overflow = 503,
 
-    // --- Cryptography & ZK (1500–1599) ---
-    InvalidCredential = 1640,
-    MissingRequiredCredential = 1641,
-    CredentialExpired = 1605,
-    CredentialRevoked = 1606,
+    // --- Cryptography & ZK (600–699) ---
+    InvalidCredential = 640,
+    MissingRequiredCredential = 641,
+    CredentialExpired = 605,
+    CredentialRevoked = 606,
 
-    // --- Cross-Chain & Integration (1600–1699) ---
-    CrossChainAccessDenied = 1700,
-    CrossChainTimeout = 1702,
-    InvalidChain = 1703,
-    CrossChainNotEnabled = 1710,
-    CrossChainContractsNotSet = 1711,
+    // --- Cross-Chain & Integration (700–799) ---
+    CrossChainAccessDenied = 700,
+    CrossChainTimeout = 702,
+    InvalidChain = 703,
+    CrossChainNotEnabled = 710,
+    CrossChainContractsNotSet = 711,
 
-    // --- Domain-Specific: AI/Medical (1700–1799) ---
-    AIConfigNotSet = 1830,
-    InvalidAIScore = 1831,
-    InvalidScore = 1832,
-    InvalidDPEpsilon = 1833,
-    InvalidParticipantCount = 1834,
+    // --- Domain-Specific: AI/Medical (800–899) ---
+    AIConfigNotSet = 830,
+    InvalidAIScore = 831,
+    InvalidScore = 832,
+    InvalidDPEpsilon = 833,
+    InvalidParticipantCount = 834,
 }
 
 impl core::fmt::Display for Error {
@@ -82,12 +95,17 @@ impl core::fmt::Display for Error {
             Error::Unauthorized => write!(f, "unauthorized"),
             Error::InvalidInput => write!(f, "invalid input"),
             Error::NotInitialized => write!(f, "not initialized"),
+            Error::AlreadyInitialized => write!(f, "already initialized"),
             Error::ContractPaused => write!(f, "contract paused"),
             Error::DeadlineExceeded => write!(f, "deadline exceeded"),
             Error::RateLimitExceeded => write!(f, "rate limit exceeded"),
             Error::InsufficientFunds => write!(f, "insufficient funds"),
+            Error::TransferFailed => write!(f, "transfer failed"),
+            Error::ReentrantCall => write!(f, "reentrant call"),
+            Error::NoChange => write!(f, "requested state equals current state"),
             Error::NotAICoordinator => write!(f, "not a i coordinator"),
             Error::EmergencyAccessExpired => write!(f, "emergency access expired"),
+            Error::RecordRetentionExpired => write!(f, "record retention expired"),
             Error::InvalidPagination => write!(f, "invalid pagination"),
             Error::InputTooLong => write!(f, "input too long"),
             Error::BatchTooLarge => write!(f, "batch too large"),
@@ -117,11 +135,14 @@ impl core::fmt::Display for Error {
             Error::EncryptionRequired => write!(f, "encryption required"),
             Error::IdentityRegistryNotSet => write!(f, "identity registry not set"),
             Error::RecordNotFound => write!(f, "record not found"),
+            Error::UserNotFound => write!(f, "user not found"),
+            Error::ActionNotFound => write!(f, "admin action not found"),
             Error::EmergencyAccessNotFound => write!(f, "emergency access not found"),
             Error::DIDNotFound => write!(f, "d i d not found"),
             Error::DIDNotActive => write!(f, "d i d not active"),
             Error::RecordAlreadySynced => write!(f, "record already synced"),
             Error::StorageFull => write!(f, "storage full"),
+            Error::Overflow => write!(f, "arithmetic overflow"),
             Error::InvalidCredential => write!(f, "invalid credential"),
             Error::MissingRequiredCredential => write!(f, "missing required credential"),
             Error::CredentialExpired => write!(f, "credential expired"),
@@ -154,7 +175,13 @@ pub fn get_suggestion(error: Error) -> Symbol {
         Error::InvalidCategory => symbol_short!("FIX_CAT"),
         Error::InvalidBatch => symbol_short!("CHK_DATA"),
         Error::NotInitialized => symbol_short!("INIT_CTR"),
-        Error::RecordNotFound | Error::DIDNotFound => symbol_short!("CHK_ID"),
+        Error::AlreadyInitialized => symbol_short!("ALREADY"),
+        Error::RecordNotFound
+        | Error::UserNotFound
+        | Error::ActionNotFound
+        | Error::DIDNotFound => {
+            symbol_short!("CHK_ID")
+        },
         Error::InsufficientFunds => symbol_short!("ADD_FUND"),
         Error::StorageFull => symbol_short!("CLN_OLD"),
         _ => symbol_short!("CONTACT"),
@@ -1,5 +1,13 @@
 use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
+/// Schema version carried in every `EventEnvelope`.
+///
+/// Each logical event is published exactly once, under a single canonical
+/// topic (`symbol_short!` name, actor, patient) with an envelope as data.
+/// Bump this when any event body changes shape so indexers can dispatch on
+/// `version` instead of guessing from the payload.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
 #[contracttype]
 pub struct EventEnvelope<T> {
     pub contract: Address,
@@ -30,12 +38,32 @@ pub struct UserRoleUpdatedEvent {
     pub previous_role: Option<String>,
 }
 
+#[contracttype]
+pub struct AdminTransferInitiatedEvent {
+    pub audit: AuditContext,
+    pub current_admin: Address,
+    pub new_admin: Address,
+}
+
+#[contracttype]
+pub struct AdminTransferAcceptedEvent {
+    pub audit: AuditContext,
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
 #[contracttype]
 pub struct UserDeactivatedEvent {
     pub audit: AuditContext,
     pub user: Address,
 }
 
+#[contracttype]
+pub struct UserReactivatedEvent {
+    pub audit: AuditContext,
+    pub user: Address,
+}
+
 #[contracttype]
 pub struct RecordCreatedEvent {
     pub audit: AuditContext,
@@ -85,6 +113,15 @@ pub struct EmergencyAccessGrantedEvent {
     pub expires_at: u64,
 }
 
+#[contracttype]
+pub struct EmergencyAccessUsedEvent {
+    pub audit: AuditContext,
+    pub log_id: u64,
+    pub record_id: u64,
+    pub patient: Address,
+    pub justification: String,
+}
+
 #[contracttype]
 pub struct ContractPausedEvent {
     pub audit: AuditContext,
@@ -110,6 +147,13 @@ pub struct RecoveryApprovedEvent {
     pub proposal_id: u64,
 }
 
+#[contracttype]
+pub struct RecoveryParamsUpdatedEvent {
+    pub audit: AuditContext,
+    pub timelock_secs: u64,
+    pub approval_threshold: u32,
+}
+
 #[contracttype]
 pub struct RecoveryExecutedEvent {
     pub audit: AuditContext,
@@ -119,6 +163,27 @@ pub struct RecoveryExecutedEvent {
     pub amount: i128,
 }
 
+#[contracttype]
+pub struct AdminThresholdUpdatedEvent {
+    pub audit: AuditContext,
+    pub admins: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+pub struct AdminActionProposedEvent {
+    pub audit: AuditContext,
+    pub action_id: u64,
+}
+
+#[contracttype]
+pub struct AdminActionApprovedEvent {
+    pub audit: AuditContext,
+    pub action_id: u64,
+    pub approvals: u32,
+    pub executed: bool,
+}
+
 #[contracttype]
 pub struct AiConfigUpdatedEvent {
     pub audit: AuditContext,
@@ -183,4 +248,36 @@ pub struct TraditionalRecordAddedEvent {
     pub record_id: u64,
     pub patient: Address,
     pub practice_type: String,
-}
\ No newline at end of file
+}
+
+#[contracttype]
+pub struct RecordUpdatedEvent {
+    pub audit: AuditContext,
+    pub record_id: u64,
+    pub patient: Address,
+    pub new_version: u32,
+}
+
+#[contracttype]
+pub struct RecordTombstonedEvent {
+    pub audit: AuditContext,
+    pub record_id: u64,
+    pub patient: Address,
+    pub reason: String,
+}
+
+#[contracttype]
+pub struct RecordConsentGrantedEvent {
+    pub audit: AuditContext,
+    pub record_id: u64,
+    pub patient: Address,
+    pub doctor: Address,
+}
+
+#[contracttype]
+pub struct RecordConsentRevokedEvent {
+    pub audit: AuditContext,
+    pub record_id: u64,
+    pub patient: Address,
+    pub doctor: Address,
+}
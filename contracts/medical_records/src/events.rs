@@ -1,10 +1,14 @@
 use super::event_schema::{
-    AccessGrantedEvent, AccessRequestedEvent, AiAnalysisTriggeredEvent, AiConfigUpdatedEvent,
-    AnomalyScoreSubmittedEvent, AuditContext, ContractPausedEvent, ContractUnpausedEvent,
-    EmergencyAccessGrantedEvent, EventEnvelope, HealthCheckEvent, MetadataUpdatedEvent,
-    RecordAccessedEvent, RecordCreatedEvent, RecordRolledBackEvent, RecoveryApprovedEvent,
-    RecoveryExecutedEvent, RecoveryProposedEvent, RiskScoreSubmittedEvent,
-    TraditionalRecordAddedEvent, UserCreatedEvent, UserDeactivatedEvent, UserRoleUpdatedEvent,
+    AccessGrantedEvent, AccessRequestedEvent, AdminActionApprovedEvent, AdminActionProposedEvent,
+    AdminThresholdUpdatedEvent, AdminTransferAcceptedEvent, AdminTransferInitiatedEvent,
+    AiAnalysisTriggeredEvent, AiConfigUpdatedEvent, AnomalyScoreSubmittedEvent, AuditContext,
+    ContractPausedEvent, ContractUnpausedEvent, EmergencyAccessGrantedEvent,
+    EmergencyAccessUsedEvent, EventEnvelope, HealthCheckEvent, MetadataUpdatedEvent,
+    RecordAccessedEvent, RecordConsentGrantedEvent, RecordConsentRevokedEvent, RecordCreatedEvent,
+    RecordRolledBackEvent, RecordTombstonedEvent, RecordUpdatedEvent, RecoveryApprovedEvent,
+    RecoveryExecutedEvent, RecoveryParamsUpdatedEvent, RecoveryProposedEvent,
+    RiskScoreSubmittedEvent, TraditionalRecordAddedEvent, UserCreatedEvent, UserDeactivatedEvent,
+    UserReactivatedEvent, UserRoleUpdatedEvent, EVENT_SCHEMA_VERSION,
 };
 use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Vec};
 
@@ -20,7 +24,7 @@ pub fn emit_user_created(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "user_created"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: UserCreatedEvent {
             audit: AuditContext {
                 actor: admin.clone(),
@@ -45,7 +49,7 @@ pub fn emit_user_role_updated(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "user_role_updated"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: UserRoleUpdatedEvent {
             audit: AuditContext {
                 actor: admin.clone(),
@@ -61,11 +65,49 @@ pub fn emit_user_role_updated(
         .publish((symbol_short!("ROLE_UPD"), admin, target_user), event);
 }
 
+pub fn emit_admin_transfer_initiated(env: &Env, current_admin: Address, new_admin: Address) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "admin_transfer_initiated"),
+        version: EVENT_SCHEMA_VERSION,
+        body: AdminTransferInitiatedEvent {
+            audit: AuditContext {
+                actor: current_admin.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            current_admin: current_admin.clone(),
+            new_admin: new_admin.clone(),
+        },
+    };
+    env.events()
+        .publish((symbol_short!("ADM_INIT"), current_admin, new_admin), event);
+}
+
+pub fn emit_admin_transfer_accepted(env: &Env, previous_admin: Address, new_admin: Address) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "admin_transfer_accepted"),
+        version: EVENT_SCHEMA_VERSION,
+        body: AdminTransferAcceptedEvent {
+            audit: AuditContext {
+                actor: new_admin.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            previous_admin: previous_admin.clone(),
+            new_admin: new_admin.clone(),
+        },
+    };
+    env.events()
+        .publish((symbol_short!("ADM_ACPT"), previous_admin, new_admin), event);
+}
+
 pub fn emit_user_deactivated(env: &Env, admin: Address, target_user: Address) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "user_deactivated"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: UserDeactivatedEvent {
             audit: AuditContext {
                 actor: admin.clone(),
@@ -79,6 +121,24 @@ pub fn emit_user_deactivated(env: &Env, admin: Address, target_user: Address) {
         .publish((symbol_short!("USR_DEACT"), admin, target_user), event);
 }
 
+pub fn emit_user_reactivated(env: &Env, admin: Address, target_user: Address) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "user_reactivated"),
+        version: EVENT_SCHEMA_VERSION,
+        body: UserReactivatedEvent {
+            audit: AuditContext {
+                actor: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            user: target_user.clone(),
+        },
+    };
+    env.events()
+        .publish((symbol_short!("USR_REACT"), admin, target_user), event);
+}
+
 pub fn emit_record_created(
     env: &Env,
     doctor: Address,
@@ -91,7 +151,7 @@ pub fn emit_record_created(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "record_created"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RecordCreatedEvent {
             audit: AuditContext {
                 actor: doctor.clone(),
@@ -110,11 +170,63 @@ pub fn emit_record_created(
         .publish((symbol_short!("REC_NEW"), doctor, patient), event);
 }
 
+pub fn emit_record_updated(
+    env: &Env,
+    caller: Address,
+    record_id: u64,
+    patient: Address,
+    new_version: u32,
+) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "record_updated"),
+        version: EVENT_SCHEMA_VERSION,
+        body: RecordUpdatedEvent {
+            audit: AuditContext {
+                actor: caller.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            record_id,
+            patient: patient.clone(),
+            new_version,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("REC_UPD"), caller, patient), event);
+}
+
+pub fn emit_record_tombstoned(
+    env: &Env,
+    caller: Address,
+    record_id: u64,
+    patient: Address,
+    reason: String,
+) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "record_tombstoned"),
+        version: EVENT_SCHEMA_VERSION,
+        body: RecordTombstonedEvent {
+            audit: AuditContext {
+                actor: caller.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            record_id,
+            patient: patient.clone(),
+            reason,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("REC_TOMB"), caller, patient), event);
+}
+
 pub fn emit_record_accessed(env: &Env, accessor: Address, record_id: u64, patient: Address) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "record_accessed"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RecordAccessedEvent {
             audit: AuditContext {
                 actor: accessor.clone(),
@@ -129,6 +241,46 @@ pub fn emit_record_accessed(env: &Env, accessor: Address, record_id: u64, patien
         .publish((symbol_short!("REC_ACC"), accessor, patient), event);
 }
 
+pub fn emit_record_consent_granted(env: &Env, patient: Address, doctor: Address, record_id: u64) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "record_consent_granted"),
+        version: EVENT_SCHEMA_VERSION,
+        body: RecordConsentGrantedEvent {
+            audit: AuditContext {
+                actor: patient.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            record_id,
+            patient: patient.clone(),
+            doctor: doctor.clone(),
+        },
+    };
+    env.events()
+        .publish((symbol_short!("CONS_GRT"), patient, doctor), event);
+}
+
+pub fn emit_record_consent_revoked(env: &Env, patient: Address, doctor: Address, record_id: u64) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "record_consent_revoked"),
+        version: EVENT_SCHEMA_VERSION,
+        body: RecordConsentRevokedEvent {
+            audit: AuditContext {
+                actor: patient.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            record_id,
+            patient: patient.clone(),
+            doctor: doctor.clone(),
+        },
+    };
+    env.events()
+        .publish((symbol_short!("CONS_REV"), patient, doctor), event);
+}
+
 pub fn emit_access_requested(
     env: &Env,
     requester: Address,
@@ -140,7 +292,7 @@ pub fn emit_access_requested(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "access_requested"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: AccessRequestedEvent {
             audit: AuditContext {
                 actor: requester.clone(),
@@ -170,7 +322,7 @@ pub fn emit_access_granted(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "access_granted"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: AccessGrantedEvent {
             audit: AuditContext {
                 actor: granter.clone(),
@@ -200,7 +352,7 @@ pub fn emit_emergency_access_granted(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "emergency_access_granted"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: EmergencyAccessGrantedEvent {
             audit: AuditContext {
                 actor: granter.clone(),
@@ -218,11 +370,39 @@ pub fn emit_emergency_access_granted(
         .publish((symbol_short!("EM_GRANT"), granter, grantee), event);
 }
 
+pub fn emit_emergency_access_used(
+    env: &Env,
+    caller: Address,
+    log_id: u64,
+    record_id: u64,
+    patient: Address,
+    justification: String,
+) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "emergency_access_used"),
+        version: EVENT_SCHEMA_VERSION,
+        body: EmergencyAccessUsedEvent {
+            audit: AuditContext {
+                actor: caller.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            log_id,
+            record_id,
+            patient: patient.clone(),
+            justification,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("EM_USED"), caller, patient), event);
+}
+
 pub fn emit_contract_paused(env: &Env, admin: Address) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "contract_paused"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: ContractPausedEvent {
             audit: AuditContext {
                 actor: admin.clone(),
@@ -238,7 +418,7 @@ pub fn emit_contract_unpaused(env: &Env, admin: Address) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "contract_unpaused"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: ContractUnpausedEvent {
             audit: AuditContext {
                 actor: admin.clone(),
@@ -262,7 +442,7 @@ pub fn emit_recovery_proposed(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "recovery_proposed"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RecoveryProposedEvent {
             audit: AuditContext {
                 actor: proposer.clone(),
@@ -283,7 +463,7 @@ pub fn emit_recovery_approved(env: &Env, approver: Address, proposal_id: u64) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "recovery_approved"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RecoveryApprovedEvent {
             audit: AuditContext {
                 actor: approver.clone(),
@@ -308,7 +488,7 @@ pub fn emit_recovery_executed(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "recovery_executed"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RecoveryExecutedEvent {
             audit: AuditContext {
                 actor: executor.clone(),
@@ -325,11 +505,103 @@ pub fn emit_recovery_executed(
         .publish((symbol_short!("REC_EXEC"), executor), event);
 }
 
+pub fn emit_recovery_params_updated(
+    env: &Env,
+    admin: Address,
+    timelock_secs: u64,
+    approval_threshold: u32,
+) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "recovery_params_updated"),
+        version: EVENT_SCHEMA_VERSION,
+        body: RecoveryParamsUpdatedEvent {
+            audit: AuditContext {
+                actor: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            timelock_secs,
+            approval_threshold,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("REC_PRM"), admin), event);
+}
+
+pub fn emit_admin_threshold_updated(
+    env: &Env,
+    admin: Address,
+    admins: Vec<Address>,
+    threshold: u32,
+) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "admin_threshold_updated"),
+        version: EVENT_SCHEMA_VERSION,
+        body: AdminThresholdUpdatedEvent {
+            audit: AuditContext {
+                actor: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            admins,
+            threshold,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("ADM_THR"), admin), event);
+}
+
+pub fn emit_admin_action_proposed(env: &Env, proposer: Address, action_id: u64) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "admin_action_proposed"),
+        version: EVENT_SCHEMA_VERSION,
+        body: AdminActionProposedEvent {
+            audit: AuditContext {
+                actor: proposer.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            action_id,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("ADM_PROP"), proposer), event);
+}
+
+pub fn emit_admin_action_approved(
+    env: &Env,
+    approver: Address,
+    action_id: u64,
+    approvals: u32,
+    executed: bool,
+) {
+    let event = EventEnvelope {
+        contract: env.current_contract_address(),
+        name: String::from_str(env, "admin_action_approved"),
+        version: EVENT_SCHEMA_VERSION,
+        body: AdminActionApprovedEvent {
+            audit: AuditContext {
+                actor: approver.clone(),
+                timestamp: env.ledger().timestamp(),
+                block_height: env.ledger().sequence() as u64,
+            },
+            action_id,
+            approvals,
+            executed,
+        },
+    };
+    env.events()
+        .publish((symbol_short!("ADM_APPR"), approver), event);
+}
+
 pub fn emit_ai_config_updated(env: &Env, admin: Address, ai_coordinator: Address) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "ai_config_updated"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: AiConfigUpdatedEvent {
             audit: AuditContext {
                 actor: admin.clone(),
@@ -355,7 +627,7 @@ pub fn emit_anomaly_score_submitted(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "anomaly_score_submitted"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: AnomalyScoreSubmittedEvent {
             audit: AuditContext {
                 actor: ai_coordinator.clone(),
@@ -384,7 +656,7 @@ pub fn emit_risk_score_submitted(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "risk_score_submitted"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RiskScoreSubmittedEvent {
             audit: AuditContext {
                 actor: ai_coordinator.clone(),
@@ -405,7 +677,7 @@ pub fn emit_ai_analysis_triggered(env: &Env, record_id: u64, patient: Address) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "ai_analysis_triggered"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: AiAnalysisTriggeredEvent {
             audit: AuditContext {
                 actor: patient.clone(),
@@ -424,7 +696,7 @@ pub fn emit_health_check(env: &Env, status: String, gas_used: u64) {
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "health_check"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: HealthCheckEvent {
             audit: AuditContext {
                 actor: env.current_contract_address(),
@@ -451,7 +723,7 @@ pub fn emit_metadata_updated(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "metadata_updated"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: MetadataUpdatedEvent {
             audit: AuditContext {
                 actor: caller.clone(),
@@ -480,7 +752,7 @@ pub fn emit_record_rolled_back(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "record_rolled_back"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: RecordRolledBackEvent {
             audit: AuditContext {
                 actor: caller.clone(),
@@ -507,7 +779,7 @@ pub fn emit_traditional_record_added(
     let event = EventEnvelope {
         contract: env.current_contract_address(),
         name: String::from_str(env, "traditional_record_added"),
-        version: 1,
+        version: EVENT_SCHEMA_VERSION,
         body: TraditionalRecordAddedEvent {
             audit: AuditContext {
                 actor: caller.clone(),
@@ -520,5 +792,5 @@ pub fn emit_traditional_record_added(
         },
     };
     env.events()
-        .publish((symbol_short!("TRAD_REC"), caller, patient), event);
+        .publish((symbol_short!("TRAD_NEW"), caller, patient), event);
 }
\ No newline at end of file
@@ -134,13 +134,7 @@ pub fn require_initialized(env: &Env) -> PolicyDecision {
 ///
 /// Paused contracts reject all mutating operations.
 pub fn require_not_paused(env: &Env) -> PolicyDecision {
-    use crate::DataKey;
-    let paused: bool = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Paused)
-        .unwrap_or(false);
-    if paused {
+    if crate::PAUSE.is_paused(env) {
         system_denied(Error::ContractPaused, env, "Contract is currently paused")
     } else {
         PolicyDecision::Allowed
@@ -184,7 +178,11 @@ pub fn check_consent(
         }
         Some(addr) => {
             let client = PatientConsentManagementClient::new(env, &addr);
-            let has_consent = client.check_consent(patient, provider);
+            // A re-entrant check counts as no consent.
+            let has_consent = crate::MedicalRecordsContract::guarded_call(env, || {
+                client.check_consent(patient, provider)
+            })
+            .unwrap_or(false);
             if has_consent {
                 (PolicyDecision::Allowed, true)
             } else {
@@ -215,21 +213,24 @@ pub fn check_patient_not_forgotten(env: &Env, patient: &Address) -> PolicyDecisi
     match compliance_addr {
         None => PolicyDecision::Allowed,
         Some(addr) => {
-            // Attempt the cross-contract call; if it fails, deny access
-            // to be conservative.
-            let result: Result<bool, _> = env.invoke_contract(
-                &addr,
-                &soroban_sdk::symbol_short!("is_forgotten"),
-                (patient.clone(),).into_val(env),
-            );
-            match result {
-                Ok(true) => lifecycle_denied(
+            // Attempt the cross-contract call under the reentrancy lock; if
+            // it fails or the lock is held, deny access to be conservative.
+            let guarded = crate::MedicalRecordsContract::guarded_call(env, || {
+                let result: Result<bool, _> = env.invoke_contract(
+                    &addr,
+                    &soroban_sdk::symbol_short!("is_forgotten"),
+                    (patient.clone(),).into_val(env),
+                );
+                result
+            });
+            match guarded {
+                Ok(Ok(true)) => lifecycle_denied(
                     Error::Unauthorized,
                     env,
                     "Patient data has been erased under regulatory compliance",
                 ),
-                Ok(false) => PolicyDecision::Allowed,
-                Err(_) => lifecycle_denied(
+                Ok(Ok(false)) => PolicyDecision::Allowed,
+                _ => lifecycle_denied(
                     Error::Unauthorized,
                     env,
                     "Could not verify regulatory compliance status",
@@ -7,6 +7,8 @@
 
 use soroban_sdk::Env;
 
+use crate::{DataKey, MedicalRecord};
+
 /// TTL threshold and extension target for persistent data.
 const PERSISTENT_TTL_THRESHOLD: u32 = 100;
 const PERSISTENT_TTL_EXTEND_TO: u32 = 10_000;
@@ -18,3 +20,29 @@ pub fn extend_persistent_ttl(env: &Env, key: &crate::DataKey) {
         .persistent()
         .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
 }
+
+/// Persistence backend for `MedicalRecord`s.
+pub trait RecordStore {
+    fn load(env: &Env, record_id: u64) -> Option<MedicalRecord>;
+    fn save(env: &Env, record_id: u64, record: &MedicalRecord);
+}
+
+/// Default backend: every record lives in its own persistent slot under
+/// `DataKey::Record(id)`, so reading or writing one record never loads the
+/// others.
+pub struct PerIdRecordStore;
+
+impl RecordStore for PerIdRecordStore {
+    fn load(env: &Env, record_id: u64) -> Option<MedicalRecord> {
+        env.storage().persistent().get(&DataKey::Record(record_id))
+    }
+
+    fn save(env: &Env, record_id: u64, record: &MedicalRecord) {
+        let key = DataKey::Record(record_id);
+        env.storage().persistent().set(&key, record);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Backend used by the contract.
+pub(crate) type Records = PerIdRecordStore;
@@ -101,6 +101,61 @@ fn test_add_and_get_record() {
     assert_eq!(access_events_count, 1);
 }
 
+#[test]
+fn test_add_record_emits_single_versioned_event() {
+    use crate::event_schema::{EventEnvelope, RecordCreatedEvent, EVENT_SCHEMA_VERSION};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let mut record_ids = Vec::new(&env);
+    for diagnosis in ["Common cold", "Migraine"] {
+        let record_id = client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, diagnosis),
+            &String::from_str(&env, "Rest"),
+            &false,
+            &vec![&env, String::from_str(&env, "general")],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "Medication"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        );
+        record_ids.push_back(record_id);
+
+        // Match on the body rather than the topic so a second emit of the
+        // same insert under any other name is caught too.
+        let mut created = 0;
+        let mut envelope = None;
+        for (_, topics, data) in env.events().all().iter() {
+            let Ok(body) = EventEnvelope::<RecordCreatedEvent>::try_from_val(&env, &data) else {
+                continue;
+            };
+            if body.body.record_id == record_id {
+                let topic = topics
+                    .get(0)
+                    .and_then(|t| Symbol::try_from_val(&env, &t).ok());
+                assert_eq!(topic, Some(symbol_short!("REC_NEW")));
+                created += 1;
+                envelope = Some(body);
+            }
+        }
+        assert_eq!(created, 1, "exactly one RecordAdded event per insert");
+
+        let envelope = envelope.unwrap();
+        assert_eq!(envelope.version, EVENT_SCHEMA_VERSION);
+        assert_eq!(envelope.name, String::from_str(&env, "record_created"));
+        assert_eq!(envelope.body.patient, patient);
+    }
+    assert_eq!(record_ids.len(), 2);
+}
+
 #[test]
 fn test_get_record_denied_after_consent_expiry() {
     let env = Env::default();
@@ -646,12 +701,40 @@ fn test_record_ordering() {
     }
 }
 
-/*
 #[test]
 fn test_record_counter_isolation() {
-    // ...
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let add = |diagnosis: &str| {
+        client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, diagnosis),
+            &String::from_str(&env, "Treatment"),
+            &false,
+            &vec![&env, String::from_str(&env, "tag")],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "Medication"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        )
+    };
+
+    let token = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    assert_eq!(add("Diagnosis 1"), 1);
+    assert_eq!(client.propose_recovery(&admin, &token, &to, &100), 1);
+    assert_eq!(add("Diagnosis 2"), 2);
+    assert_eq!(client.propose_recovery(&admin, &token, &to, &100), 2);
+    assert_eq!(add("Diagnosis 3"), 3);
 }
-*/
 
 /*
 #[test]
@@ -837,6 +920,49 @@ fn test_patient_record_index_lookup_efficiency() {
     assert!(elapsed < 100_000_000);
 }
 
+#[test]
+fn test_get_history_paginates_patient_index_across_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let mut ids: Vec<u64> = Vec::new(&env);
+    for _i in 0..3 {
+        let id = client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, "Diag"),
+            &String::from_str(&env, "Treat"),
+            &false,
+            &vec![&env, String::from_str(&env, "tag")],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "General"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        );
+        ids.push_back(id);
+    }
+
+    // add_record must populate the same persistent index get_history reads.
+    assert_eq!(client.get_patient_record_count(&patient), 3);
+
+    let first = client.get_history(&patient, &patient, &0u32, &2u32);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().0, ids.get(0).unwrap());
+    assert_eq!(first.get(1).unwrap().0, ids.get(1).unwrap());
+
+    let second = client.get_history(&patient, &patient, &1u32, &2u32);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.get(0).unwrap().0, ids.get(2).unwrap());
+
+    let beyond = client.get_history(&patient, &patient, &2u32, &2u32);
+    assert_eq!(beyond.len(), 0);
+}
+
 // ============================================================================
 // Rate Limiting Tests
 // ============================================================================
@@ -998,7 +1124,7 @@ fn test_rate_limit_different_roles() {
     );
 
     // Admin can manage 2 users
-    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &doctor, &Role::Patient);
     client.manage_user(&admin, &other_doctor, &Role::Doctor);
 
     // 3rd time fails for admin
@@ -1049,6 +1175,124 @@ fn test_rate_limit_admin_bypass() {
     }
 }
 
+#[test]
+fn test_rate_limit_confidential_reads() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    client.set_rate_limit_config(
+        &admin,
+        &3u32, // OP_READ_CONFIDENTIAL
+        &RateLimitConfig {
+            doctor_max_calls: 3,
+            patient_max_calls: 3,
+            admin_max_calls: 3,
+            window_secs: 3600,
+        },
+    );
+
+    env.ledger().set_timestamp(1000);
+    let add = |confidential: bool| {
+        client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, "D"),
+            &String::from_str(&env, "T"),
+            &confidential,
+            &soroban_sdk::vec![&env],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "Med"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        )
+    };
+    let confidential = add(true);
+    let routine = add(false);
+
+    for _ in 0..3 {
+        client.get_record(&doctor, &confidential);
+    }
+    assert_eq!(
+        client.try_get_record(&doctor, &confidential),
+        Err(Ok(Error::RateLimitExceeded))
+    );
+
+    // Only confidential reads count, and the patient and admins are exempt.
+    client.get_record(&doctor, &routine);
+    for _ in 0..5 {
+        client.get_record(&patient, &confidential);
+        client.get_record(&admin, &confidential);
+    }
+
+    env.ledger().set_timestamp(1000 + 3600);
+    client.get_record(&doctor, &confidential);
+
+    // Minimum-necessary reads draw from the same budget.
+    let categories = soroban_sdk::vec![&env, DataCategory::Diagnosis];
+    client.get_record_filtered(&doctor, &confidential, &categories);
+    client.get_record_filtered(&doctor, &confidential, &categories);
+    assert_eq!(
+        client.try_get_record_filtered(&doctor, &confidential, &categories),
+        Err(Ok(Error::RateLimitExceeded))
+    );
+}
+
+#[test]
+fn test_history_charges_one_unit_per_confidential_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    client.set_rate_limit_config(
+        &admin,
+        &3u32, // OP_READ_CONFIDENTIAL
+        &RateLimitConfig {
+            doctor_max_calls: 3,
+            patient_max_calls: 3,
+            admin_max_calls: 3,
+            window_secs: 3600,
+        },
+    );
+
+    env.ledger().set_timestamp(1000);
+    for confidential in [true, true, false] {
+        client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, "D"),
+            &String::from_str(&env, "T"),
+            &confidential,
+            &soroban_sdk::vec![&env],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "Med"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        );
+    }
+
+    // Two confidential records listed: two units of the doctor's three.
+    assert_eq!(client.get_history(&doctor, &patient, &0u32, &3u32).len(), 3);
+    assert_eq!(
+        client.try_get_history(&doctor, &patient, &0u32, &3u32),
+        Err(Ok(Error::RateLimitExceeded))
+    );
+
+    // The patient and admins are not charged.
+    for _ in 0..3 {
+        client.get_history(&patient, &patient, &0u32, &3u32);
+        client.get_history(&admin, &patient, &0u32, &3u32);
+    }
+}
+
 // ==================== Metadata Enhancement Tests ====================
 
 #[cfg(test)]
@@ -1426,6 +1670,48 @@ fn test_error_codes_are_stable() {
     assert_eq!(Error::CrossChainAccessDenied as u32, 700);
     assert_eq!(Error::AIConfigNotSet as u32, 830);
     assert_eq!(Error::InvalidAIScore as u32, 831);
+
+    // Variants added later take the next free code in their section.
+    assert_eq!(Error::AlreadyInitialized as u32, 301);
+    assert_eq!(Error::ReentrantCall as u32, 308);
+    assert_eq!(Error::NoChange as u32, 309);
+    assert_eq!(Error::UserNotFound as u32, 404);
+    assert_eq!(Error::TransferFailed as u32, 501);
+    assert_eq!(Error::Overflow as u32, 503);
+}
+
+#[test]
+fn test_lifecycle_and_user_errors_are_typed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let rbac_id = env.register_contract(None, MockRbac);
+    assert_eq!(
+        client.try_initialize(&admin, &rbac_id),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_get_user_role(&stranger),
+        Err(Ok(Error::UserNotFound))
+    );
+    assert_eq!(
+        client.try_set_user_qkd_status(&admin, &stranger, &true),
+        Err(Ok(Error::UserNotFound))
+    );
+
+    let doctor = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.deactivate_user(&admin, &doctor);
+    assert_eq!(
+        client.try_get_user_role(&doctor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Deactivating an unknown user is not an error, just a no-op.
+    assert!(!client.deactivate_user(&admin, &stranger));
 }
 
 #[test]
@@ -1865,3 +2151,1241 @@ fn proptest_record_attributes_persistence() {
             "Timestamp must be set");
     });
 }
+
+// ==================== Record Versioning ====================
+
+#[test]
+fn test_update_record_bumps_version_and_keeps_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Bronchitus"),
+        &String::from_str(&env, "Rest"),
+        &false,
+        &vec![&env, String::from_str(&env, "respiratory")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+    let original = client.get_record(&patient, &record_id);
+    assert_eq!(original.version, 1);
+
+    let new_version = client.update_record(
+        &doctor,
+        &record_id,
+        &String::from_str(&env, "Bronchitis"),
+        &String::from_str(&env, "Rest and amoxicillin 500mg"),
+        &vec![&env, String::from_str(&env, "respiratory")],
+    );
+    assert_eq!(new_version, 2);
+
+    // Latest read returns the new content; immutable fields are untouched.
+    let latest = client.get_record(&patient, &record_id);
+    assert_eq!(latest.version, 2);
+    assert_eq!(latest.diagnosis, String::from_str(&env, "Bronchitis"));
+    assert_eq!(latest.patient_id, original.patient_id);
+    assert_eq!(latest.doctor_id, original.doctor_id);
+    assert_eq!(latest.timestamp, original.timestamp);
+
+    // Prior version remains reconstructable.
+    let v1 = client.get_record_version(&admin, &record_id, &1);
+    assert_eq!(v1.diagnosis, String::from_str(&env, "Bronchitus"));
+    assert_eq!(
+        client.try_get_record_version(&admin, &record_id, &3),
+        Err(Ok(Error::InvalidVersion))
+    );
+}
+
+#[test]
+fn test_get_record_version_hides_tombstoned_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Bronchitus"),
+        &String::from_str(&env, "Rest"),
+        &false,
+        &vec![&env, String::from_str(&env, "respiratory")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+    client.update_record(
+        &doctor,
+        &record_id,
+        &String::from_str(&env, "Bronchitis"),
+        &String::from_str(&env, "Rest and amoxicillin 500mg"),
+        &vec![&env, String::from_str(&env, "respiratory")],
+    );
+    let reason = String::from_str(&env, "wrong patient");
+    client.tombstone_record(&doctor, &record_id, &reason);
+
+    for version in [1u32, 2] {
+        assert_eq!(
+            client.try_get_record_version(&patient, &record_id, &version),
+            Err(Ok(Error::RecordNotFound))
+        );
+        assert_eq!(
+            client.try_get_record_version(&doctor, &record_id, &version),
+            Err(Ok(Error::RecordNotFound))
+        );
+    }
+
+    // Admins keep audit access to the tombstoned history.
+    let v1 = client.get_record_version(&admin, &record_id, &1);
+    assert_eq!(v1.diagnosis, String::from_str(&env, "Bronchitus"));
+}
+
+#[test]
+fn test_update_record_rejects_other_doctor_and_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let other_doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &other_doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Diagnosis"),
+        &String::from_str(&env, "Treatment"),
+        &false,
+        &vec![&env, String::from_str(&env, "tag")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+
+    let result = client.try_update_record(
+        &other_doctor,
+        &record_id,
+        &String::from_str(&env, "Edited"),
+        &String::from_str(&env, "Edited"),
+        &vec![&env, String::from_str(&env, "tag")],
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.pause(&admin);
+    let result = client.try_update_record(
+        &doctor,
+        &record_id,
+        &String::from_str(&env, "Edited"),
+        &String::from_str(&env, "Edited"),
+        &vec![&env, String::from_str(&env, "tag")],
+    );
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+
+    // Admin may edit once unpaused.
+    client.unpause(&admin);
+    assert_eq!(
+        client.update_record(
+            &admin,
+            &record_id,
+            &String::from_str(&env, "Edited"),
+            &String::from_str(&env, "Edited"),
+            &vec![&env, String::from_str(&env, "tag")],
+        ),
+        2
+    );
+}
+
+// ==================== Per-Record Consent ====================
+
+#[test]
+fn test_record_consent_grant_use_revoke() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let consulting_doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &consulting_doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Confidential diagnosis"),
+        &String::from_str(&env, "Confidential treatment"),
+        &true,
+        &vec![&env, String::from_str(&env, "psych")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Therapy"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+
+    // No consent yet: the consulting doctor is denied.
+    assert!(!client.has_consent(&patient, &consulting_doctor, &record_id));
+    assert_eq!(
+        client.try_get_record(&consulting_doctor, &record_id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Grant and use.
+    assert!(client.grant_record_access(&patient, &consulting_doctor, &record_id));
+    assert!(client.has_consent(&patient, &consulting_doctor, &record_id));
+    let record = client.get_record(&consulting_doctor, &record_id);
+    assert_eq!(record.patient_id, patient);
+
+    // Revoke and confirm denial.
+    assert!(client.revoke_record_access(&patient, &consulting_doctor, &record_id));
+    assert!(!client.has_consent(&patient, &consulting_doctor, &record_id));
+    assert_eq!(
+        client.try_get_record(&consulting_doctor, &record_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(!client.revoke_record_access(&patient, &consulting_doctor, &record_id));
+
+    // Admin and patient keep unconditional access.
+    client.get_record(&admin, &record_id);
+    client.get_record(&patient, &record_id);
+}
+
+#[test]
+fn test_record_consent_only_owner_can_grant() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    let other_patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+    client.manage_user(&admin, &other_patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Diagnosis"),
+        &String::from_str(&env, "Treatment"),
+        &true,
+        &vec![&env, String::from_str(&env, "tag")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+
+    assert_eq!(
+        client.try_grant_record_access(&other_patient, &doctor, &record_id),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_add_record_returns_overflow_at_counter_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    env.as_contract(&client.address, || {
+        MedicalRecordsContract::set_next_id_for_test(&env, u64::MAX);
+    });
+
+    assert_eq!(
+        client.try_add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, "Diagnosis"),
+            &String::from_str(&env, "Treatment"),
+            &false,
+            &vec![&env, String::from_str(&env, "tag")],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "Medication"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        ),
+        Err(Ok(Error::Overflow))
+    );
+}
+
+// ==================== Storage Consistency Tests ====================
+
+#[test]
+fn test_add_record_visible_through_get_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Diagnosis"),
+        &String::from_str(&env, "Treatment"),
+        &false,
+        &vec![&env, String::from_str(&env, "tag")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+
+    let history = client.get_history(&patient, &patient, &0u32, &10u32);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0u32).unwrap().0, record_id);
+    assert_eq!(client.get_record(&patient, &record_id).patient_id, patient);
+}
+
+#[test]
+fn test_migrate_storage_moves_instance_attributes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &patient, &Role::Patient);
+    client.issue_access_attribute(
+        &admin,
+        &patient,
+        &String::from_str(&env, "dept"),
+        &String::from_str(&env, "cardiology"),
+        &(env.ledger().timestamp() + 86_400),
+        &true,
+    );
+
+    // Recreate the layout written by earlier versions.
+    env.as_contract(&client.address, || {
+        let key = Symbol::new(&env, "adv_access");
+        let state: AdvancedAccessState = env.storage().persistent().get(&key).unwrap();
+        env.storage().persistent().remove(&key);
+        env.storage().instance().set(&key, &state);
+    });
+    assert_eq!(client.get_user_access_attributes(&patient).len(), 0);
+
+    assert!(client.migrate_storage(&admin));
+    assert_eq!(client.get_user_access_attributes(&patient).len(), 1);
+    assert!(!client.migrate_storage(&admin));
+
+    // A stale instance copy next to live persistent state is merged: new
+    // entries move over, conflicting ones stay behind instead of being lost.
+    let other = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let key = Symbol::new(&env, "adv_access");
+        let mut state: AdvancedAccessState = env.storage().persistent().get(&key).unwrap();
+        let attributes = state.user_attributes.get(patient.clone()).unwrap();
+        state.user_attributes.set(other.clone(), attributes);
+        state.user_attributes.set(patient.clone(), Vec::new(&env));
+        env.storage().instance().set(&key, &state);
+    });
+    assert!(client.migrate_storage(&admin));
+    assert_eq!(client.get_user_access_attributes(&patient).len(), 1);
+    assert_eq!(client.get_user_access_attributes(&other).len(), 1);
+    env.as_contract(&client.address, || {
+        let key = Symbol::new(&env, "adv_access");
+        let left: AdvancedAccessState = env.storage().instance().get(&key).unwrap();
+        assert_eq!(left.user_attributes.len(), 1);
+        assert_eq!(left.user_attributes.get(patient.clone()), Some(Vec::new(&env)));
+    });
+    assert!(!client.migrate_storage(&admin));
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_migrate_storage(&outsider),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+// ==================== Recovery Transfer Tests ====================
+
+#[test]
+fn test_execute_recovery_transfers_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let admin2 = Address::generate(&env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let recipient = Address::generate(&env);
+    let amount: i128 = 1_000;
+    soroban_sdk::token::StellarAssetClient::new(&env, &token_id).mint(&client.address, &amount);
+
+    let proposal_id = client.propose_recovery(&admin, &token_id, &recipient, &amount);
+    client.approve_recovery(&admin2, &proposal_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+    assert!(client.execute_recovery(&admin, &proposal_id));
+
+    let token = soroban_sdk::token::Client::new(&env, &token_id);
+    assert_eq!(token.balance(&recipient), amount);
+    assert_eq!(token.balance(&client.address), 0);
+
+    assert_eq!(
+        client.try_execute_recovery(&admin, &proposal_id),
+        Err(Ok(Error::ProposalAlreadyExecuted))
+    );
+}
+
+#[test]
+fn test_execute_recovery_fails_without_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let admin2 = Address::generate(&env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let recipient = Address::generate(&env);
+
+    let proposal_id = client.propose_recovery(&admin, &token_id, &recipient, &500);
+    client.approve_recovery(&admin2, &proposal_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+    assert_eq!(
+        client.try_execute_recovery(&admin, &proposal_id),
+        Err(Ok(Error::TransferFailed))
+    );
+
+    // The proposal stays pending so it can be retried once funded.
+    soroban_sdk::token::StellarAssetClient::new(&env, &token_id).mint(&client.address, &500);
+    assert!(client.execute_recovery(&admin, &proposal_id));
+}
+
+/// Token whose `transfer` succeeds without moving funds.
+#[contract]
+struct NoopToken;
+
+#[contractimpl]
+impl NoopToken {
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+#[test]
+fn test_execute_recovery_blocks_reentrant_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let admin2 = Address::generate(&env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+
+    let token_id = env.register_contract(None, NoopToken);
+    let recipient = Address::generate(&env);
+
+    let proposal_id = client.propose_recovery(&admin, &token_id, &recipient, &1_000);
+    client.approve_recovery(&admin2, &proposal_id);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+
+    // The host already rejects a token calling straight back into this
+    // contract, so exercise the lock itself: while it is held, execution
+    // and every other guarded external call are refused.
+    env.as_contract(&client.address, || {
+        REENTRANCY.enter(&env).unwrap();
+        assert_eq!(
+            MedicalRecordsContract::execute_recovery(env.clone(), admin.clone(), proposal_id),
+            Err(Error::ReentrantCall)
+        );
+        assert_eq!(
+            MedicalRecordsContract::guarded_call(&env, || ()),
+            Err(Error::ReentrantCall)
+        );
+        REENTRANCY.exit(&env);
+    });
+
+    assert!(client.execute_recovery(&admin, &proposal_id));
+    env.as_contract(&client.address, || assert!(!REENTRANCY.is_locked(&env)));
+    assert_eq!(
+        client.try_execute_recovery(&admin, &proposal_id),
+        Err(Ok(Error::ProposalAlreadyExecuted))
+    );
+}
+
+// ==================== Batch Import Tests ====================
+
+fn batch_input(env: &Env, patient: &Address, category: &str) -> MedicalRecordInput {
+    MedicalRecordInput {
+        patient: patient.clone(),
+        diagnosis: String::from_str(env, "Diagnosis"),
+        treatment: String::from_str(env, "Treatment"),
+        is_confidential: false,
+        tags: vec![env, String::from_str(env, "archive")],
+        category: String::from_str(env, category),
+        treatment_type: String::from_str(env, "Medication"),
+        data_ref: String::from_str(env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    }
+}
+
+#[test]
+fn test_add_records_batch_assigns_sequential_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let mut inputs = Vec::new(&env);
+    for _ in 0..3 {
+        inputs.push_back(batch_input(&env, &patient, "Modern"));
+    }
+    let ids = client.add_records_batch(&doctor, &inputs);
+    assert_eq!(ids, vec![&env, 1u64, 2u64, 3u64]);
+    assert_eq!(client.get_record_count(), 3);
+}
+
+#[test]
+fn test_add_records_batch_is_all_or_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let mut inputs = Vec::new(&env);
+    inputs.push_back(batch_input(&env, &patient, "Modern"));
+    inputs.push_back(batch_input(&env, &patient, "NotACategory"));
+    assert_eq!(
+        client.try_add_records_batch(&doctor, &inputs),
+        Err(Ok(Error::InvalidCategory))
+    );
+    assert_eq!(client.get_record_count(), 0);
+
+    // Only doctors may import.
+    let single = vec![&env, batch_input(&env, &patient, "Modern")];
+    assert_eq!(
+        client.try_add_records_batch(&admin, &single),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let mut oversized = Vec::new(&env);
+    for _ in 0..51 {
+        oversized.push_back(batch_input(&env, &patient, "Modern"));
+    }
+    assert_eq!(
+        client.try_add_records_batch(&doctor, &oversized),
+        Err(Ok(Error::BatchTooLarge))
+    );
+    assert_eq!(
+        client.try_add_records_batch(&doctor, &Vec::new(&env)),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+// ==================== Tombstone Tests ====================
+
+#[test]
+fn test_tombstoned_record_hidden_from_patient_but_visible_to_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Wrong patient"),
+        &String::from_str(&env, "Treatment"),
+        &false,
+        &vec![&env, String::from_str(&env, "tag")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+
+    let reason = String::from_str(&env, "Created for the wrong patient");
+    assert!(client.tombstone_record(&doctor, &record_id, &reason));
+    assert!(!client.tombstone_record(&doctor, &record_id, &reason));
+
+    assert_eq!(
+        client.try_get_record(&patient, &record_id),
+        Err(Ok(Error::RecordNotFound))
+    );
+    assert_eq!(client.get_history(&patient, &patient, &0u32, &10u32).len(), 0);
+    assert_eq!(
+        client.get_record_with_did(&patient, &record_id, &String::from_str(&env, "treatment")),
+        None
+    );
+    assert_eq!(
+        client.try_get_record_filtered(&patient, &record_id, &vec![&env, DataCategory::Diagnosis]),
+        Err(Ok(Error::RecordNotFound))
+    );
+
+    // Only admins may ask for deleted records.
+    assert_eq!(
+        client.try_get_record_including_deleted(&patient, &record_id, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    let audited = client.get_record_including_deleted(&admin, &record_id, &true);
+    assert!(audited.is_deleted);
+    assert_eq!(audited.deletion_reason, Some(reason));
+    assert_eq!(
+        client
+            .get_history_filtered(&admin, &patient, &0u32, &10u32, &true)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_tombstone_requires_creator_or_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let other_doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &other_doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Diagnosis"),
+        &String::from_str(&env, "Treatment"),
+        &false,
+        &vec![&env, String::from_str(&env, "tag")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+
+    let reason = String::from_str(&env, "Duplicate entry");
+    assert_eq!(
+        client.try_tombstone_record(&other_doctor, &record_id, &reason),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(client.tombstone_record(&admin, &record_id, &reason));
+}
+
+// ==================== Admin Handover Tests ====================
+
+#[test]
+fn test_admin_handover_happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let new_admin = Address::generate(&env);
+    let doctor = Address::generate(&env);
+
+    client.transfer_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    // Nothing changes until the nominee accepts.
+    assert_eq!(
+        client.try_manage_user(&new_admin, &doctor, &Role::Doctor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.get_user_role(&new_admin), Role::Admin);
+    assert_eq!(client.get_user_role(&admin), Role::None);
+
+    client.manage_user(&new_admin, &doctor, &Role::Doctor);
+    assert_eq!(
+        client.try_manage_user(&admin, &doctor, &Role::Patient),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_pending_admin_transfer_is_overwritten() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.transfer_admin(&admin, &first);
+    client.transfer_admin(&admin, &second);
+    assert_eq!(client.get_pending_admin(), Some(second.clone()));
+
+    assert_eq!(client.try_accept_admin(&first), Err(Ok(Error::Unauthorized)));
+    client.accept_admin(&second);
+    assert_eq!(client.get_user_role(&second), Role::Admin);
+}
+
+#[test]
+fn test_admin_transfer_blocked_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let new_admin = Address::generate(&env);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_transfer_admin(&admin, &new_admin),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+// ==================== Multi-Signature Admin Tests ====================
+
+fn setup_admin_council(env: &Env) -> (MedicalRecordsContractClient<'_>, Address, Address, Address) {
+    let (client, admin) = create_contract(env);
+    let admin2 = Address::generate(env);
+    let admin3 = Address::generate(env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+    client.manage_user(&admin, &admin3, &Role::Admin);
+    client.set_admin_threshold(
+        &admin,
+        &vec![env, admin.clone(), admin2.clone(), admin3.clone()],
+        &2,
+    );
+    (client, admin, admin2, admin3)
+}
+
+#[test]
+fn test_two_of_three_pause_requires_two_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, admin2, admin3) = setup_admin_council(&env);
+    assert_eq!(client.get_admin_council().threshold, 2);
+    assert_eq!(client.try_pause(&admin), Err(Ok(Error::NotEnoughApproval)));
+
+    let action_id = client.propose_admin_action(&admin, &AdminAction::Pause);
+    assert_eq!(client.health_check().0, symbol_short!("OK"));
+
+    // Re-approving your own proposal does not count twice.
+    assert!(!client.approve_admin_action(&admin, &action_id));
+    assert_eq!(client.health_check().0, symbol_short!("OK"));
+
+    assert!(client.approve_admin_action(&admin2, &action_id));
+    assert_eq!(client.health_check().0, symbol_short!("PAUSED"));
+    assert!(client.get_admin_action(&action_id).unwrap().executed);
+    assert_eq!(
+        client.try_approve_admin_action(&admin3, &action_id),
+        Err(Ok(Error::ProposalAlreadyExecuted))
+    );
+
+    let action_id = client.propose_admin_action(&admin3, &AdminAction::Unpause);
+    assert!(client.approve_admin_action(&admin, &action_id));
+    assert_eq!(client.health_check().0, symbol_short!("OK"));
+}
+
+#[test]
+fn test_admin_council_gates_manage_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, admin2, _) = setup_admin_council(&env);
+    let doctor = Address::generate(&env);
+    assert_eq!(
+        client.try_manage_user(&admin, &doctor, &Role::Doctor),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+
+    let outsider = Address::generate(&env);
+    let action = AdminAction::ManageUser(doctor.clone(), Role::Doctor);
+    assert_eq!(
+        client.try_propose_admin_action(&outsider, &action),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let action_id = client.propose_admin_action(&admin, &action);
+    assert_eq!(
+        client.try_get_user_role(&doctor),
+        Err(Ok(Error::UserNotFound))
+    );
+    client.approve_admin_action(&admin2, &action_id);
+    assert_eq!(client.get_user_role(&doctor), Role::Doctor);
+}
+
+#[test]
+fn test_admin_council_gates_user_status_handover_and_recovery_params() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, admin2, _) = setup_admin_council(&env);
+    let doctor = Address::generate(&env);
+    let nominee = Address::generate(&env);
+    let action_id = client.propose_admin_action(
+        &admin,
+        &AdminAction::ManageUser(doctor.clone(), Role::Doctor),
+    );
+    client.approve_admin_action(&admin2, &action_id);
+
+    assert_eq!(
+        client.try_deactivate_user(&admin, &doctor),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+    assert_eq!(
+        client.try_reactivate_user(&admin, &doctor),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+    assert_eq!(
+        client.try_transfer_admin(&admin, &nominee),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+    assert_eq!(
+        client.try_set_recovery_params(&admin, &7_200, &2),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+
+    let action_id =
+        client.propose_admin_action(&admin, &AdminAction::DeactivateUser(doctor.clone()));
+    assert_eq!(client.get_user_role(&doctor), Role::Doctor);
+    client.approve_admin_action(&admin2, &action_id);
+    assert_eq!(
+        client.try_get_user_role(&doctor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let action_id =
+        client.propose_admin_action(&admin, &AdminAction::ReactivateUser(doctor.clone()));
+    client.approve_admin_action(&admin2, &action_id);
+    assert_eq!(client.get_user_role(&doctor), Role::Doctor);
+
+    let action_id =
+        client.propose_admin_action(&admin, &AdminAction::TransferAdmin(nominee.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    client.approve_admin_action(&admin2, &action_id);
+    assert_eq!(client.get_pending_admin(), Some(nominee));
+
+    assert_eq!(
+        client.try_propose_admin_action(&admin, &AdminAction::SetRecoveryParams(60, 2)),
+        Err(Ok(Error::InvalidInput))
+    );
+    let action_id = client.propose_admin_action(&admin, &AdminAction::SetRecoveryParams(7_200, 2));
+    client.approve_admin_action(&admin2, &action_id);
+    assert_eq!(client.get_recovery_params().timelock_secs, 7_200);
+
+    // Action IDs run on their own counter and unknown IDs are reported as such.
+    assert_eq!(action_id, 5);
+    assert_eq!(
+        client.try_approve_admin_action(&admin2, &99),
+        Err(Ok(Error::ActionNotFound))
+    );
+}
+
+#[test]
+fn test_set_admin_threshold_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let admin2 = Address::generate(&env);
+    let not_admin = Address::generate(&env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+
+    for (admins, threshold) in [
+        (vec![&env, admin.clone(), admin2.clone()], 0),
+        (vec![&env, admin.clone(), admin2.clone()], 3),
+        (vec![&env, admin.clone(), admin.clone()], 2),
+        (vec![&env, admin.clone(), not_admin.clone()], 2),
+    ] {
+        assert_eq!(
+            client.try_set_admin_threshold(&admin, &admins, &threshold),
+            Err(Ok(Error::InvalidInput))
+        );
+    }
+
+    // Single-admin deployments keep acting directly.
+    assert_eq!(client.get_admin_council().threshold, 1);
+    assert!(client.pause(&admin));
+
+    // Once above 1, lowering the threshold needs the council too.
+    client.unpause(&admin);
+    client.set_admin_threshold(&admin, &vec![&env, admin.clone(), admin2.clone()], &2);
+    let council = vec![&env, admin.clone(), admin2.clone()];
+    assert_eq!(
+        client.try_set_admin_threshold(&admin, &council, &1),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+    let action_id =
+        client.propose_admin_action(&admin, &AdminAction::SetAdminThreshold(council, 1));
+    client.approve_admin_action(&admin2, &action_id);
+    assert_eq!(client.get_admin_council().threshold, 1);
+}
+
+// ==================== Tag Query Tests ====================
+
+#[test]
+fn test_get_records_by_tag_filters_confidential_for_unrelated_doctor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let unrelated_doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &unrelated_doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let add = |confidential: bool, tag: &str| {
+        client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, "Diagnosis"),
+            &String::from_str(&env, "Treatment"),
+            &confidential,
+            &vec![&env, String::from_str(&env, tag)],
+            &String::from_str(&env, "Modern"),
+            &String::from_str(&env, "Medication"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        )
+    };
+    let open_id = add(false, "respiratory");
+    add(true, "respiratory");
+    add(false, "cardiac");
+
+    let tag = String::from_str(&env, "respiratory");
+    assert_eq!(client.get_records_by_tag(&patient, &patient, &tag, &0u32, &10u32).len(), 2);
+
+    let visible = client.get_records_by_tag(&unrelated_doctor, &patient, &tag, &0u32, &10u32);
+    assert_eq!(visible.len(), 1);
+    assert_eq!(visible.get(0).unwrap().0, open_id);
+
+    let none = String::from_str(&env, "oncology");
+    assert_eq!(client.get_records_by_tag(&patient, &patient, &none, &0u32, &10u32).len(), 0);
+}
+
+// ==================== Break-Glass Tests ====================
+
+#[test]
+fn test_emergency_access_reads_confidential_record_and_logs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let responder = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &responder, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let record_id = client.add_record(
+        &doctor,
+        &patient,
+        &String::from_str(&env, "Allergy: penicillin"),
+        &String::from_str(&env, "Avoid beta-lactams"),
+        &true,
+        &vec![&env, String::from_str(&env, "allergy")],
+        &String::from_str(&env, "Modern"),
+        &String::from_str(&env, "Medication"),
+        &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+    );
+    assert_eq!(
+        client.try_get_record(&responder, &record_id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let justification = String::from_str(&env, "Unconscious patient in ER");
+    let record = client.emergency_access(&responder, &record_id, &justification);
+    assert_eq!(record.patient_id, patient);
+
+    let logs = client.get_emergency_logs(&admin, &0u32, &10u32);
+    assert_eq!(logs.len(), 1);
+    let entry = logs.get(0).unwrap();
+    assert_eq!(entry.caller, responder);
+    assert_eq!(entry.record_id, record_id);
+    assert_eq!(entry.justification, justification);
+
+    assert_eq!(
+        client.try_get_emergency_logs(&responder, &0u32, &10u32),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_emergency_access(&patient, &record_id, &justification),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_recovery_threshold_change_applies_to_existing_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let admin2 = Address::generate(&env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+
+    let token = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let proposal_id = client.propose_recovery(&admin, &token, &recipient, &100);
+    client.approve_recovery(&admin2, &proposal_id);
+
+    client.set_recovery_params(&admin, &3_600, &3);
+    assert_eq!(
+        client.get_recovery_params(),
+        RecoveryParams {
+            timelock_secs: 3_600,
+            approval_threshold: 3,
+        }
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3_601);
+    assert_eq!(
+        client.try_execute_recovery(&admin, &proposal_id),
+        Err(Ok(Error::NotEnoughApproval))
+    );
+
+    // Out-of-bounds values are rejected.
+    assert_eq!(
+        client.try_set_recovery_params(&admin, &3_599, &2),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_recovery_params(&admin, &3_600, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn test_get_pending_proposals_excludes_executed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let admin2 = Address::generate(&env);
+    client.manage_user(&admin, &admin2, &Role::Admin);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &token_id).mint(&client.address, &300);
+    let recipient = Address::generate(&env);
+
+    let first = client.propose_recovery(&admin, &token_id, &recipient, &100);
+    let second = client.propose_recovery(&admin, &token_id, &recipient, &100);
+    client.approve_recovery(&admin2, &first);
+    client.approve_recovery(&admin2, &second);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+    let third = client.propose_recovery(&admin, &token_id, &recipient, &100);
+    assert!(client.execute_recovery(&admin, &first));
+
+    let pending = client.get_pending_proposals(&admin, &0, &10);
+    assert_eq!(pending.len(), 2);
+
+    let listed = pending.get_unchecked(0);
+    assert_eq!(listed.proposal.proposal_id, second);
+    assert_eq!(listed.approval_count, 2);
+    assert!(listed.timelock_elapsed);
+
+    let listed = pending.get_unchecked(1);
+    assert_eq!(listed.proposal.proposal_id, third);
+    assert_eq!(listed.approval_count, 1);
+    assert!(!listed.timelock_elapsed);
+
+    assert_eq!(client.get_pending_proposals(&admin, &1, &1).len(), 1);
+    assert_eq!(
+        client.try_get_pending_proposals(&recipient, &0, &10),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_propose_recovery_caps_pending_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let token_id = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    for _ in 0..MAX_PENDING_PROPOSALS {
+        client.propose_recovery(&admin, &token_id, &recipient, &100);
+    }
+    assert_eq!(
+        client.try_propose_recovery(&admin, &token_id, &recipient, &100),
+        Err(Ok(Error::StorageFull))
+    );
+}
+
+// ==================== Stats Tests ====================
+
+#[test]
+fn test_get_stats_aggregates_counts_and_excludes_tombstoned() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    let patient = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    let add = |confidential: bool, category: &str| {
+        client.add_record(
+            &doctor,
+            &patient,
+            &String::from_str(&env, "Diagnosis"),
+            &String::from_str(&env, "Treatment"),
+            &confidential,
+            &vec![&env, String::from_str(&env, "tag")],
+            &String::from_str(&env, category),
+            &String::from_str(&env, "Medication"),
+            &String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhXXXXXx"),
+        )
+    };
+    add(false, "Modern");
+    let confidential_modern = add(true, "Modern");
+    add(true, "Traditional");
+
+    let modern = String::from_str(&env, "Modern");
+    let traditional = String::from_str(&env, "Traditional");
+
+    let stats = client.get_stats();
+    assert_eq!(stats.total_records, 3);
+    assert_eq!(stats.total_users, 3); // admin, doctor, patient
+    assert_eq!(stats.confidential_count, 2);
+    assert_eq!(stats.records_per_category.get(modern.clone()), Some(2));
+    assert_eq!(stats.records_per_category.get(traditional.clone()), Some(1));
+
+    client.tombstone_record(
+        &doctor,
+        &confidential_modern,
+        &String::from_str(&env, "Entered in error"),
+    );
+    let stats = client.get_stats();
+    assert_eq!(stats.total_records, 2);
+    assert_eq!(stats.confidential_count, 1);
+    assert_eq!(stats.records_per_category.get(modern), Some(1));
+    assert_eq!(stats.records_per_category.get(traditional), Some(1));
+}
+
+// ==================== User Lifecycle Tests ====================
+
+#[test]
+fn test_manage_user_does_not_reactivate_deactivated_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let doctor = Address::generate(&env);
+    client.manage_user(&admin, &doctor, &Role::Doctor);
+    client.deactivate_user(&admin, &doctor);
+
+    assert_eq!(
+        client.try_manage_user(&admin, &doctor, &Role::Doctor),
+        Err(Ok(Error::NoChange))
+    );
+    assert_eq!(client.try_get_user_role(&doctor), Err(Ok(Error::Unauthorized)));
+
+    // A real role change keeps the user inactive as well.
+    client.manage_user(&admin, &doctor, &Role::Patient);
+    assert_eq!(client.try_get_user_role(&doctor), Err(Ok(Error::Unauthorized)));
+
+    client.reactivate_user(&admin, &doctor);
+    assert_eq!(client.get_user_role(&doctor), Role::Patient);
+    assert_eq!(
+        client.try_reactivate_user(&admin, &doctor),
+        Err(Ok(Error::NoChange))
+    );
+}
+
+#[test]
+fn test_get_doctors_by_specialty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let cardiology = String::from_str(&env, "cardiology");
+    let oncology = String::from_str(&env, "oncology");
+    let license = Bytes::from_array(&env, &[9u8; 32]);
+
+    let cardiologist_a = Address::generate(&env);
+    let cardiologist_b = Address::generate(&env);
+    let oncologist = Address::generate(&env);
+    let patient = Address::generate(&env);
+    for doctor in [&cardiologist_a, &cardiologist_b, &oncologist] {
+        client.manage_user(&admin, doctor, &Role::Doctor);
+    }
+    client.manage_user(&admin, &patient, &Role::Patient);
+
+    client.set_doctor_metadata(
+        &admin,
+        &cardiologist_a,
+        &Some(cardiology.clone()),
+        &Some(license.clone()),
+    );
+    assert_eq!(
+        client.try_set_doctor_metadata(
+            &admin,
+            &cardiologist_a,
+            &Some(cardiology.clone()),
+            &Some(license.slice(0..16)),
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_doctor_metadata(&admin, &cardiologist_b, &Some(cardiology.clone()), &None);
+    client.set_doctor_metadata(&admin, &oncologist, &Some(oncology.clone()), &None);
+    assert_eq!(
+        client.try_set_doctor_metadata(&admin, &patient, &Some(oncology.clone()), &None),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    assert_eq!(
+        client.get_doctors_by_specialty(&cardiology, &0, &10),
+        vec![&env, cardiologist_a.clone(), cardiologist_b.clone()]
+    );
+    assert_eq!(
+        client.get_doctors_by_specialty(&oncology, &0, &10),
+        vec![&env, oncologist.clone()]
+    );
+    assert_eq!(
+        client.get_doctors_by_specialty(&cardiology, &1, &1),
+        vec![&env, cardiologist_b.clone()]
+    );
+
+    // Changing specialty moves the doctor to the other index.
+    client.set_doctor_metadata(&admin, &cardiologist_a, &Some(oncology.clone()), &None);
+    assert_eq!(
+        client.get_doctors_by_specialty(&cardiology, &0, &10),
+        vec![&env, cardiologist_b]
+    );
+    assert_eq!(
+        client.get_doctors_by_specialty(&oncology, &0, &10),
+        vec![&env, oncologist, cardiologist_a]
+    );
+}
@@ -69,6 +69,9 @@ pub const MIN_PURPOSE_LENGTH: u32 = 5;
 /// Maximum length for purpose string
 pub const MAX_PURPOSE_LENGTH: u32 = 256;
 
+/// Maximum length for a doctor's specialty name
+pub const MAX_SPECIALTY_LENGTH: u32 = 64;
+
 /// Minimum length for explanation summary
 
 pub const MIN_EXPLANATION_LENGTH: u32 = 10;
@@ -313,6 +316,17 @@ pub fn validate_attribute_value(value: &String) -> Result<(), Error> {
     )
 }
 
+#[must_use]
+pub fn validate_specialty(specialty: &String) -> Result<(), Error> {
+    validate_string_length(
+        specialty,
+        1,
+        MAX_SPECIALTY_LENGTH,
+        Error::InvalidInput,
+        Error::InputTooLong,
+    )
+}
+
 /// Validates a single tag
 ///
 /// # Arguments
@@ -678,6 +692,9 @@ pub fn validate_user_profile(profile: &UserProfile) -> Result<(), Error> {
     if let Some(ref did) = profile.did_reference {
         validate_did_reference(did)?;
     }
+    if let Some(ref specialty) = profile.specialty {
+        validate_specialty(specialty)?;
+    }
 
     // Role and active flag are enums/booleans, so they're inherently valid
 
@@ -1760,6 +1777,9 @@ mod tests {
                 env,
                 "did:stellar:GABCDEFGHIJKLMNOPQRSTUVWXYZ",
             )),
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         }
     }
 
@@ -1799,6 +1819,9 @@ mod tests {
             treatment_type: String::from_str(&env, ""),
             data_ref: String::from_str(&env, ""),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
         let completeness = assess_field_completeness(&record);
 
@@ -1854,6 +1877,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Medication"),
             data_ref: String::from_str(&env, "short"),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let (score, issues) = compute_quality_score(&env, &record);
@@ -1970,6 +1996,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Medication"),
             data_ref: String::from_str(&env, "short"),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let report = validate_record_with_report(&env, 42, &record);
@@ -2000,6 +2029,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Medication"),
             data_ref: String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx"),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let report = validate_record_with_report(&env, 5, &record);
@@ -2046,6 +2078,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Antibiotic"),
             data_ref: String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx"),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let result = auto_cleanse_record(&env, &record);
@@ -2073,6 +2108,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Antibiotic"),
             data_ref: String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx"),
             doctor_did: Some(String::from_str(&env, "")), // empty DID
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let result = auto_cleanse_record(&env, &record);
@@ -2099,6 +2137,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Conservative"),
             data_ref: String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx"),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let (cleanse_result, report, workflow) = validate_cleanse_and_report(&env, 10, &record);
@@ -2139,6 +2180,9 @@ mod tests {
             treatment_type: String::from_str(&env, "Medication"),
             data_ref: String::from_str(&env, "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx"),
             doctor_did: None,
+            version: 1,
+            is_deleted: false,
+            deletion_reason: None,
         };
 
         let (score, issues) = compute_quality_score(&env, &record);
@@ -11,12 +11,18 @@ pub enum Error {
     // --- Input Validation (200–299) ---
     BatchTooLarge = 208,
     RecipientsEmpty = 209,
+    SummariesEmpty = 210,
     TitleTooLong = 221,
     MessageTooLong = 222,
     NameTooLong = 223,
     LocaleTooLong = 224,
     InvalidNotifType = 241,
     TooManyEnabledTypes = 242,
+    InvalidQuietHours = 243,
+    InvalidTimeRange = 244,
+    InvalidRateLimit = 245,
+    TooManyChannels = 246,
+    TooManyTemplateParams = 247,
 
     // --- Lifecycle (300–399) ---
     NotInitialized = 300,
@@ -24,6 +30,9 @@ pub enum Error {
     RateLimitExceeded = 307,
     AlreadyRead = 330,
     AlreadyArchived = 331,
+    AlreadyDelivered = 332,
+    NotificationDeferred = 333,
+    NotificationSnoozed = 334,
 
     // --- Entity Existence (400–499) ---
     NotificationNotFound = 450,
@@ -45,17 +54,26 @@ impl core::fmt::Display for Error {
             Error::SenderNotAuthorized => write!(f, "sender not authorized"),
             Error::BatchTooLarge => write!(f, "batch too large"),
             Error::RecipientsEmpty => write!(f, "recipients empty"),
+            Error::SummariesEmpty => write!(f, "summaries empty"),
             Error::TitleTooLong => write!(f, "title too long"),
             Error::MessageTooLong => write!(f, "message too long"),
             Error::NameTooLong => write!(f, "name too long"),
             Error::LocaleTooLong => write!(f, "locale too long"),
             Error::InvalidNotifType => write!(f, "invalid notif type"),
             Error::TooManyEnabledTypes => write!(f, "too many enabled types"),
+            Error::InvalidQuietHours => write!(f, "invalid quiet hours"),
+            Error::InvalidTimeRange => write!(f, "invalid time range"),
+            Error::InvalidRateLimit => write!(f, "invalid rate limit"),
+            Error::TooManyChannels => write!(f, "too many channels"),
+            Error::TooManyTemplateParams => write!(f, "too many template params"),
             Error::NotInitialized => write!(f, "not initialized"),
             Error::AlreadyInitialized => write!(f, "already initialized"),
             Error::RateLimitExceeded => write!(f, "rate limit exceeded"),
             Error::AlreadyRead => write!(f, "already read"),
             Error::AlreadyArchived => write!(f, "already archived"),
+            Error::AlreadyDelivered => write!(f, "already delivered"),
+            Error::NotificationDeferred => write!(f, "notification deferred"),
+            Error::NotificationSnoozed => write!(f, "notification snoozed"),
             Error::NotificationNotFound => write!(f, "notification not found"),
             Error::AlertRuleNotFound => write!(f, "alert rule not found"),
             Error::TemplateNotFound => write!(f, "template not found"),
@@ -84,12 +102,17 @@ pub fn get_suggestion(error: Error) -> soroban_sdk::Symbol {
         | Error::MaxRulesReached
         | Error::MaxNotificationsReached
         | Error::MaxTemplatesReached => soroban_sdk::symbol_short!("CLN_OLD"),
-        Error::BatchTooLarge | Error::TooManyEnabledTypes => soroban_sdk::symbol_short!("REDUCE"),
+        Error::BatchTooLarge | Error::TooManyEnabledTypes | Error::TooManyChannels => {
+            soroban_sdk::symbol_short!("REDUCE")
+        },
         Error::NotInitialized => soroban_sdk::symbol_short!("INIT_CTR"),
-        Error::AlreadyInitialized | Error::AlreadyRead | Error::AlreadyArchived => {
+        Error::AlreadyInitialized
+        | Error::AlreadyRead
+        | Error::AlreadyArchived
+        | Error::AlreadyDelivered => {
             soroban_sdk::symbol_short!("ALREADY")
         },
-        Error::RecipientsEmpty => soroban_sdk::symbol_short!("ADD_TEXT"),
+        Error::RecipientsEmpty | Error::SummariesEmpty => soroban_sdk::symbol_short!("ADD_TEXT"),
         Error::LocaleTooLong => soroban_sdk::symbol_short!("FIX_LANG"),
         _ => soroban_sdk::symbol_short!("CONTACT"),
     }
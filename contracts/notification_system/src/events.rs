@@ -26,6 +26,15 @@ pub struct NotifStatusEvent {
     pub timestamp: u64,
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct NotifSnoozedEvent {
+    pub notif_id: u64,
+    pub user: Address,
+    pub until: u64,
+    pub timestamp: u64,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct AlertRuleEvent {
@@ -69,6 +78,16 @@ pub struct SenderAuthEvent {
     pub timestamp: u64,
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct SenderRateLimitSetEvent {
+    pub sender: Address,
+    pub max_calls: u32,
+    pub window_secs: u64,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct TemplateSetEvent {
@@ -126,6 +145,30 @@ pub fn emit_notification_archived(env: &Env, notif_id: u64, user: Address) {
     );
 }
 
+/// `user` is the sender or admin that reported delivery.
+pub fn emit_notification_delivered(env: &Env, notif_id: u64, user: Address) {
+    env.events().publish(
+        ("NOTIF", symbol_short!("NOTIF_DLV")),
+        NotifStatusEvent {
+            notif_id,
+            user,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+pub fn emit_notification_snoozed(env: &Env, notif_id: u64, user: Address, until: u64) {
+    env.events().publish(
+        ("NOTIF", symbol_short!("NOTIF_SNZ")),
+        NotifSnoozedEvent {
+            notif_id,
+            user,
+            until,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
 pub fn emit_alert_rule_created(
     env: &Env,
     rule_id: u64,
@@ -236,6 +279,25 @@ pub fn emit_sender_revoked(env: &Env, sender: Address, admin: Address) {
     );
 }
 
+pub fn emit_sender_rate_limit_set(
+    env: &Env,
+    sender: Address,
+    max_calls: u32,
+    window_secs: u64,
+    admin: Address,
+) {
+    env.events().publish(
+        ("NOTIF", symbol_short!("SNDR_RATE")),
+        SenderRateLimitSetEvent {
+            sender,
+            max_calls,
+            window_secs,
+            admin,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
 pub fn emit_template_set(env: &Env, notif_type: u32, locale: String, admin: Address) {
     env.events().publish(
         ("NOTIF", symbol_short!("TMPL_SET")),
@@ -11,11 +11,12 @@ mod types;
 
 pub use errors::Error;
 pub use types::{
-    AlertPriority, AlertRule, Notification, NotificationAnalytics, NotificationChannel,
+    AlertPriority, AlertRule, BulkResult, Notification, NotificationAnalytics, NotificationChannel,
     NotificationFilter, NotificationPage, NotificationPreferences, NotificationStatus,
     NotificationTemplate, NotificationType,
 };
 
+use access_control_commons::{AdminStore, RoleGuard, StorageTier};
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map, String, Vec};
 
 // ==================== Storage Keys ====================
@@ -30,7 +31,8 @@ pub enum DataKey {
     AuthorizedSenders, // Vec<Address>, bounded by MAX_SENDERS
 
     // Per-sender rate limiting — persistent
-    SenderRate(Address), // SenderRateLimit
+    SenderRate(Address),       // SenderRateLimit
+    SenderRateConfig(Address), // SenderRateConfig — admin override of the defaults
 
     // User preferences — persistent
     UserPrefs(Address), // NotificationPreferences
@@ -49,6 +51,7 @@ pub enum DataKey {
     // Localised templates — persistent
     // Key: (notif_type_repr, locale) → NotificationTemplate
     Template(u32, String),
+    DefaultLocale, // String — instance storage; template fallback locale
 
     // Analytics counters — persistent
     TotalSent,           // u64
@@ -56,6 +59,7 @@ pub enum DataKey {
     TotalPending,        // u64
     ByTypeSent(u32),     // u64 — keyed by NotificationType repr
     ByPrioritySent(u32), // u64 — keyed by AlertPriority repr
+    DailySent(u64),      // u64 — keyed by ledger day (timestamp / 86400)
 }
 
 // ==================== Constants ====================
@@ -75,6 +79,8 @@ const MAX_BULK_RECIPIENTS: u32 = 20;
 /// Maximum number of localised templates stored per notification type.
 /// Maximum enabled-type entries in NotificationPreferences.
 const MAX_ENABLED_TYPES: u32 = 14;
+/// Number of `NotificationChannel` variants; bounds per-notification channel sets.
+const MAX_CHANNELS: u32 = 2;
 
 // String byte-length ceilings
 const MAX_TITLE_LEN: u32 = 100;
@@ -82,10 +88,22 @@ const MAX_MESSAGE_LEN: u32 = 500;
 const MAX_RULE_NAME_LEN: u32 = 50;
 const MAX_LOCALE_LEN: u32 = 10;
 
+/// Maximum positional parameters accepted by create_from_template.
+const MAX_TEMPLATE_PARAMS: u32 = 10;
+/// Maximum summaries collapsed into one digest notification.
+const MAX_DIGEST_ITEMS: u32 = 20;
+
 // Sender rate-limit: MAX_SENDER_CALLS notifications per RATE_WINDOW_SECS.
 const MAX_SENDER_CALLS: u32 = 100;
 const RATE_WINDOW_SECS: u64 = 3_600; // 1 hour
 
+/// Length of the day used for quiet-hours windows.
+const SECS_PER_DAY: u32 = 86_400;
+/// Maximum days returned by a single get_analytics_range query.
+const MAX_ANALYTICS_DAYS: u64 = 90;
+
+const ADMIN: AdminStore<DataKey> = AdminStore::new(DataKey::Admin, StorageTier::Instance);
+
 // ==================== Contract ====================
 
 #[contract]
@@ -102,7 +120,7 @@ impl NotificationContract {
         governance_commons::try_init_guard(&env).map_err(|_| Error::AlreadyInitialized)?;
         admin.require_auth();
         env.storage().instance().set(&DataKey::Initialized, &true);
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        ADMIN.set(&env, &admin);
         env.storage()
             .instance()
             .set(&DataKey::AuthorizedSenders, &Vec::<Address>::new(&env));
@@ -172,6 +190,34 @@ impl NotificationContract {
         Ok(())
     }
 
+    /// Override the default rate limit (MAX_SENDER_CALLS per RATE_WINDOW_SECS)
+    /// for a single sender. Both values must be non-zero.
+    pub fn set_sender_rate_limit(
+        env: Env,
+        caller: Address,
+        sender: Address,
+        max_calls: u32,
+        window_secs: u64,
+    ) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if max_calls == 0 || window_secs == 0 {
+            return Err(Error::InvalidRateLimit);
+        }
+        env.storage().persistent().set(
+            &DataKey::SenderRateConfig(sender.clone()),
+            &types::SenderRateConfig {
+                max_calls,
+                window_secs,
+            },
+        );
+
+        events::emit_sender_rate_limit_set(&env, sender, max_calls, window_secs, caller);
+        Ok(())
+    }
+
     /// Returns the list of all currently authorised sender addresses.
     pub fn get_authorized_senders(env: Env) -> Result<Vec<Address>, Error> {
         Self::require_initialized(&env)?;
@@ -194,12 +240,21 @@ impl NotificationContract {
         if prefs.enabled_types.len() > MAX_ENABLED_TYPES {
             return Err(Error::TooManyEnabledTypes);
         }
+        if prefs.channels.len() > MAX_CHANNELS {
+            return Err(Error::TooManyChannels);
+        }
+        if prefs.quiet_start_secs >= SECS_PER_DAY || prefs.quiet_end_secs >= SECS_PER_DAY {
+            return Err(Error::InvalidQuietHours);
+        }
 
         let stored = NotificationPreferences {
             enabled: prefs.enabled,
             min_priority: prefs.min_priority,
             channel: prefs.channel,
+            channels: prefs.channels,
             enabled_types: prefs.enabled_types,
+            quiet_start_secs: prefs.quiet_start_secs,
+            quiet_end_secs: prefs.quiet_end_secs,
             updated_at: env.ledger().timestamp(),
         };
         env.storage()
@@ -245,37 +300,113 @@ impl NotificationContract {
         Self::validate_title(&title)?;
         Self::validate_message(&message)?;
 
-        let notif_id = Self::next_notif_id(&env);
-        let status = Self::resolve_status(&env, &recipient, notif_type, priority);
-
-        let notif = Notification {
-            id: notif_id,
-            recipient: recipient.clone(),
-            sender: sender.clone(),
+        Ok(Self::insert_notification(
+            &env,
+            sender,
+            recipient,
             notif_type,
             priority,
-            status,
             title,
             message,
             reference_id,
-            created_at: env.ledger().timestamp(),
-            read_at: None,
             expires_at,
-        };
+            1,
+        ))
+    }
 
-        Self::store_notification(&env, notif.clone());
-        Self::increment_analytics(&env, notif_type, priority, status);
+    /// Create a notification from the `(notif_type, locale)` template, replacing
+    /// positional placeholders `{0}`, `{1}`, … in its title and message with
+    /// `params`. Falls back to the default locale when `locale` has no template.
+    /// The template's `default_priority` is used.
+    pub fn create_from_template(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        notif_type: NotificationType,
+        locale: String,
+        params: Vec<String>,
+    ) -> Result<u64, Error> {
+        Self::require_initialized(&env)?;
+        sender.require_auth();
+        Self::require_authorized(&env, &sender)?;
+        Self::check_and_update_sender_rate(&env, &sender)?;
 
-        events::emit_notification_created(
+        if params.len() > MAX_TEMPLATE_PARAMS {
+            return Err(Error::TooManyTemplateParams);
+        }
+        let template = Self::resolve_template(&env, notif_type as u32, locale)?;
+        let title = Self::interpolate(&env, &template.title, &params, MAX_TITLE_LEN)
+            .ok_or(Error::TitleTooLong)?;
+        let message = Self::interpolate(&env, &template.message, &params, MAX_MESSAGE_LEN)
+            .ok_or(Error::MessageTooLong)?;
+
+        Ok(Self::insert_notification(
             &env,
-            notif_id,
+            sender,
             recipient,
+            notif_type,
+            template.default_priority,
+            title,
+            message,
+            None,
+            None,
+            1,
+        ))
+    }
+
+    /// Collapse up to MAX_DIGEST_ITEMS event summaries into a single
+    /// notification for `recipient`. Summaries are joined with newlines and
+    /// the result must fit in MAX_MESSAGE_LEN. The digest counts once towards
+    /// unread and analytics, and records the number of items it represents.
+    pub fn create_digest(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        notif_type: NotificationType,
+        priority: AlertPriority,
+        summaries: Vec<String>,
+    ) -> Result<u64, Error> {
+        Self::require_initialized(&env)?;
+        sender.require_auth();
+        Self::require_authorized(&env, &sender)?;
+        Self::check_and_update_sender_rate(&env, &sender)?;
+
+        if summaries.is_empty() {
+            return Err(Error::SummariesEmpty);
+        }
+        if summaries.len() > MAX_DIGEST_ITEMS {
+            return Err(Error::BatchTooLarge);
+        }
+
+        let mut buf = [0u8; MAX_MESSAGE_LEN as usize];
+        let mut len = 0usize;
+        for (i, summary) in summaries.iter().enumerate() {
+            let sep = if i > 0 { 1 } else { 0 };
+            let part = summary.len() as usize;
+            if len + sep + part > MAX_MESSAGE_LEN as usize {
+                return Err(Error::MessageTooLong);
+            }
+            if sep == 1 {
+                buf[len] = b'\n';
+                len += 1;
+            }
+            summary.copy_into_slice(&mut buf[len..len + part]);
+            len += part;
+        }
+        let message = String::from_bytes(&env, &buf[..len]);
+
+        Ok(Self::insert_notification(
+            &env,
             sender,
-            notif_type as u32,
-            priority as u32,
-            reference_id,
-        );
-        Ok(notif_id)
+            recipient,
+            notif_type,
+            priority,
+            String::from_str(&env, "Digest"),
+            message,
+            None,
+            None,
+            summaries.len(),
+        ))
     }
 
     /// Create one notification per recipient in `recipients`.
@@ -290,7 +421,7 @@ impl NotificationContract {
         message: String,
         reference_id: Option<u64>,
         expires_at: Option<u64>,
-    ) -> Result<Vec<u64>, Error> {
+    ) -> Result<BulkResult, Error> {
         Self::require_initialized(&env)?;
         sender.require_auth();
         Self::require_authorized(&env, &sender)?;
@@ -307,11 +438,18 @@ impl NotificationContract {
         Self::validate_message(&message)?;
 
         let mut ids = Vec::new(&env);
+        let mut delivered: u32 = 0;
+        let mut suppressed: u32 = 0;
         let timestamp = env.ledger().timestamp();
 
         for recipient in recipients.iter() {
             let notif_id = Self::next_notif_id(&env);
             let status = Self::resolve_status(&env, &recipient, notif_type, priority);
+            if status == NotificationStatus::Archived {
+                suppressed = suppressed.saturating_add(1);
+            } else {
+                delivered = delivered.saturating_add(1);
+            }
             let notif = Notification {
                 id: notif_id,
                 recipient: recipient.clone(),
@@ -324,7 +462,11 @@ impl NotificationContract {
                 reference_id,
                 created_at: timestamp,
                 read_at: None,
+                delivered_at: None,
                 expires_at,
+                item_count: 1,
+                channels: Self::resolve_channels(&env, &recipient, priority),
+                snoozed_until: None,
             };
             Self::store_notification(&env, notif.clone());
             Self::increment_analytics(&env, notif_type, priority, status);
@@ -339,7 +481,11 @@ impl NotificationContract {
             );
             ids.push_back(notif_id);
         }
-        Ok(ids)
+        Ok(BulkResult {
+            ids,
+            delivered,
+            suppressed,
+        })
     }
 
     // ------------------------------------------------------------------
@@ -385,6 +531,7 @@ impl NotificationContract {
         let mut matched: Vec<Notification> = Vec::new(&env);
         let mut total_matched: u32 = 0;
         let mut skipped: u32 = 0;
+        let now = env.ledger().timestamp();
 
         // Iterate newest-first (push_back → last element is newest).
         let mut idx = total_ids;
@@ -398,7 +545,7 @@ impl NotificationContract {
                 Some(id) => id,
                 None => break,
             };
-            let notif = match env
+            let mut notif = match env
                 .storage()
                 .persistent()
                 .get::<DataKey, Notification>(&DataKey::Notif(notif_id))
@@ -407,10 +554,23 @@ impl NotificationContract {
                 None => continue, // Evicted or missing — skip
             };
 
-            // Apply filter predicates (u32::MAX = sentinel "no filter").
-            if filter.status != u32::MAX && (notif.status as u32) != filter.status {
+            // Snoozed notifications are hidden whatever the status filter.
+            // Expired notifications are treated as Archived: hidden from the
+            // default page and only matched by an explicit Archived filter.
+            if Self::is_snoozed(&notif, now) {
+                continue;
+            }
+            let status = Self::effective_status(&notif, now);
+            if filter.status == u32::MAX {
+                if Self::is_expired_unread(&notif, now) {
+                    continue;
+                }
+            } else if (status as u32) != filter.status {
                 continue;
             }
+            notif.status = status;
+
+            // Apply filter predicates (u32::MAX = sentinel "no filter").
             if filter.notif_type != u32::MAX && (notif.notif_type as u32) != filter.notif_type {
                 continue;
             }
@@ -448,10 +608,117 @@ impl NotificationContract {
         })
     }
 
+    /// Returns a user's notifications carrying `reference_id`, newest first.
+    /// Snoozed and expired notifications are left out, as on the default
+    /// `get_notifications` page.
+    /// `page_size` is capped at MAX_PAGE_SIZE; `page` is zero-based.
+    pub fn get_notifications_by_reference(
+        env: Env,
+        caller: Address,
+        user: Address,
+        reference_id: u64,
+        page: u32,
+        page_size: u32,
+    ) -> Result<NotificationPage, Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if caller != user && !Self::is_admin(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        let limit = page_size.min(MAX_PAGE_SIZE);
+        let offset = page.saturating_mul(limit);
+        let ids = Self::read_user_notif_ids(&env, &user);
+        let now = env.ledger().timestamp();
+
+        let mut matched: Vec<Notification> = Vec::new(&env);
+        let mut total_matched: u32 = 0;
+        let mut idx = ids.len();
+        while idx > 0 {
+            idx = idx.saturating_sub(1);
+            let notif_id = match ids.get(idx) {
+                Some(id) => id,
+                None => break,
+            };
+            let mut notif = match env
+                .storage()
+                .persistent()
+                .get::<DataKey, Notification>(&DataKey::Notif(notif_id))
+            {
+                Some(n) => n,
+                None => continue,
+            };
+            // Same visibility as the default `get_notifications` page.
+            if notif.reference_id != Some(reference_id)
+                || Self::is_snoozed(&notif, now)
+                || Self::is_expired_unread(&notif, now)
+            {
+                continue;
+            }
+            notif.status = Self::effective_status(&notif, now);
+
+            total_matched = total_matched.saturating_add(1);
+            if total_matched > offset && matched.len() < limit {
+                matched.push_back(notif);
+            }
+        }
+
+        let has_more = total_matched > offset.saturating_add(matched.len());
+        Ok(NotificationPage {
+            notifications: matched,
+            total: total_matched,
+            offset,
+            has_more,
+        })
+    }
+
+    /// Returns the caller's notifications that are currently snoozed, newest first.
+    pub fn get_snoozed(env: Env, caller: Address) -> Result<Vec<Notification>, Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let now = env.ledger().timestamp();
+        let ids = Self::read_user_notif_ids(&env, &caller);
+        let mut snoozed = Vec::new(&env);
+        let mut idx = ids.len();
+        while idx > 0 {
+            idx = idx.saturating_sub(1);
+            let notif_id = match ids.get(idx) {
+                Some(id) => id,
+                None => break,
+            };
+            if let Some(notif) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Notification>(&DataKey::Notif(notif_id))
+            {
+                if Self::is_snoozed(&notif, now) {
+                    snoozed.push_back(notif);
+                }
+            }
+        }
+        Ok(snoozed)
+    }
+
     /// Returns the number of unread (Pending + Delivered) notifications for a user.
+    /// Notifications past their `expires_at` are not counted, even before
+    /// `sweep_expired` archives them.
     pub fn get_unread_count(env: Env, user: Address) -> Result<u32, Error> {
         Self::require_initialized(&env)?;
-        Ok(Self::read_unread_count(&env, &user))
+        let now = env.ledger().timestamp();
+        let mut expired: u32 = 0;
+        for notif_id in Self::read_user_notif_ids(&env, &user).iter() {
+            if let Some(notif) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Notification>(&DataKey::Notif(notif_id))
+            {
+                if Self::is_expired_unread(&notif, now) {
+                    expired = expired.saturating_add(1);
+                }
+            }
+        }
+        Ok(Self::read_unread_count(&env, &user).saturating_sub(expired))
     }
 
     // ------------------------------------------------------------------
@@ -496,6 +763,130 @@ impl NotificationContract {
         Ok(())
     }
 
+    /// Record that an off-chain channel delivered a Pending notification.
+    /// Only the original sender or admin may call this. The notification
+    /// stays unread until the recipient marks it Read. Snoozed notifications
+    /// cannot be delivered until they wake, and a notification that woke
+    /// from a snooze after delivery is not delivered again.
+    pub fn mark_delivered(env: Env, sender: Address, notif_id: u64) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        sender.require_auth();
+
+        let mut notif = Self::load_notification(&env, notif_id)?;
+        if notif.sender != sender && !Self::is_admin(&env, &sender) {
+            return Err(Error::Unauthorized);
+        }
+        match notif.status {
+            NotificationStatus::Pending => {},
+            NotificationStatus::Delivered => return Err(Error::AlreadyDelivered),
+            NotificationStatus::Read => return Err(Error::AlreadyRead),
+            NotificationStatus::Archived => return Err(Error::AlreadyArchived),
+            NotificationStatus::Deferred => return Err(Error::NotificationDeferred),
+        }
+        if Self::is_snoozed(&notif, env.ledger().timestamp()) {
+            return Err(Error::NotificationSnoozed);
+        }
+
+        notif.status = NotificationStatus::Delivered;
+        notif.delivered_at = Some(env.ledger().timestamp());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Notif(notif_id), &notif);
+
+        events::emit_notification_delivered(&env, notif_id, sender);
+        Ok(())
+    }
+
+    /// Hide an unread notification from queries until `until`, when it
+    /// reappears as Pending. Its stored status is left alone, so a delivered
+    /// notification keeps its delivery record and only reads as Pending. It
+    /// stays unread and counted meanwhile. Only the recipient may call this.
+    pub fn snooze_notification(
+        env: Env,
+        caller: Address,
+        notif_id: u64,
+        until: u64,
+    ) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+
+        let mut notif = Self::load_notification(&env, notif_id)?;
+        if notif.recipient != caller {
+            return Err(Error::Unauthorized);
+        }
+        let now = env.ledger().timestamp();
+        match Self::effective_status(&notif, now) {
+            NotificationStatus::Pending | NotificationStatus::Delivered => {},
+            NotificationStatus::Read => return Err(Error::AlreadyRead),
+            NotificationStatus::Archived => return Err(Error::AlreadyArchived),
+            NotificationStatus::Deferred => return Err(Error::NotificationDeferred),
+        }
+        if until <= now {
+            return Err(Error::InvalidTimeRange);
+        }
+
+        notif.snoozed_until = Some(until);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Notif(notif_id), &notif);
+
+        events::emit_notification_snoozed(&env, notif_id, caller, until);
+        Ok(())
+    }
+
+    /// Promote `user`'s Deferred notifications to Pending once their quiet
+    /// window has ended. Returns the number released; nothing is released
+    /// while the window is still active.
+    pub fn release_deferred(env: Env, user: Address) -> Result<u32, Error> {
+        Self::require_initialized(&env)?;
+        user.require_auth();
+
+        let prefs: Option<NotificationPreferences> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserPrefs(user.clone()));
+        if let Some(p) = prefs {
+            if Self::in_quiet_hours(&p, env.ledger().timestamp()) {
+                return Ok(0);
+            }
+        }
+
+        let mut released: u32 = 0;
+        for notif_id in Self::read_user_notif_ids(&env, &user).iter() {
+            let notif_opt = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Notification>(&DataKey::Notif(notif_id));
+            if let Some(mut notif) = notif_opt {
+                if notif.status == NotificationStatus::Deferred {
+                    notif.status = NotificationStatus::Pending;
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::Notif(notif_id), &notif);
+                    released = released.saturating_add(1);
+                }
+            }
+        }
+
+        if released > 0 {
+            let current = Self::read_unread_count(&env, &user);
+            env.storage().persistent().set(
+                &DataKey::UserUnreadCount(user.clone()),
+                &current.saturating_add(released),
+            );
+            let pending: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::TotalPending)
+                .unwrap_or(0u64)
+                .saturating_add(released as u64);
+            env.storage()
+                .persistent()
+                .set(&DataKey::TotalPending, &pending);
+        }
+        Ok(released)
+    }
+
     /// Mark all Pending / Delivered notifications for the caller as Read.
     /// Returns the count of newly-read notifications.
     pub fn mark_all_read(env: Env, caller: Address) -> Result<u32, Error> {
@@ -538,6 +929,51 @@ impl NotificationContract {
         Ok(newly_read)
     }
 
+    /// Mark a specific set of the caller's notifications as Read.
+    /// Already-read and archived entries are skipped; returns the number
+    /// actually transitioned. Fails if any ID belongs to another user.
+    pub fn mark_read_batch(env: Env, caller: Address, notif_ids: Vec<u64>) -> Result<u32, Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if notif_ids.len() > MAX_PAGE_SIZE {
+            return Err(Error::BatchTooLarge);
+        }
+
+        let timestamp = env.ledger().timestamp();
+        let mut newly_read: u32 = 0;
+        for notif_id in notif_ids.iter() {
+            let mut notif = Self::load_notification(&env, notif_id)?;
+            if notif.recipient != caller {
+                return Err(Error::Unauthorized);
+            }
+            if !matches!(
+                notif.status,
+                NotificationStatus::Pending | NotificationStatus::Delivered
+            ) {
+                continue;
+            }
+
+            notif.status = NotificationStatus::Read;
+            notif.read_at = Some(timestamp);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Notif(notif_id), &notif);
+            newly_read = newly_read.saturating_add(1);
+            events::emit_notification_read(&env, notif_id, caller.clone());
+        }
+
+        if newly_read > 0 {
+            let remaining = Self::read_unread_count(&env, &caller).saturating_sub(newly_read);
+            env.storage()
+                .persistent()
+                .set(&DataKey::UserUnreadCount(caller.clone()), &remaining);
+            Self::add_to_total_read(&env, newly_read as u64);
+            Self::sub_from_total_pending(&env, newly_read as u64);
+        }
+
+        Ok(newly_read)
+    }
+
     /// Archive a notification so it no longer appears in default queries.
     /// Caller must be the recipient or admin.
     pub fn archive_notification(env: Env, caller: Address, notif_id: u64) -> Result<(), Error> {
@@ -570,6 +1006,46 @@ impl NotificationContract {
         Ok(())
     }
 
+    /// Archive every expired Pending / Delivered notification for `user` and
+    /// correct the unread counter. Caller must be the user or admin.
+    /// Returns the number of notifications archived.
+    pub fn sweep_expired(env: Env, caller: Address, user: Address) -> Result<u32, Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        if caller != user && !Self::is_admin(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut swept: u32 = 0;
+        for notif_id in Self::read_user_notif_ids(&env, &user).iter() {
+            let notif_opt = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Notification>(&DataKey::Notif(notif_id));
+            if let Some(mut notif) = notif_opt {
+                if Self::is_expired_unread(&notif, now) {
+                    notif.status = NotificationStatus::Archived;
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::Notif(notif_id), &notif);
+                    events::emit_notification_archived(&env, notif_id, caller.clone());
+                    swept = swept.saturating_add(1);
+                }
+            }
+        }
+
+        if swept > 0 {
+            let current = Self::read_unread_count(&env, &user);
+            env.storage().persistent().set(
+                &DataKey::UserUnreadCount(user.clone()),
+                &current.saturating_sub(swept),
+            );
+            Self::sub_from_total_pending(&env, swept as u64);
+        }
+        Ok(swept)
+    }
+
     // ------------------------------------------------------------------
     // Alert Rules
     // ------------------------------------------------------------------
@@ -740,44 +1216,45 @@ impl NotificationContract {
         let message = custom_message.unwrap_or_else(|| rule.name.clone());
         Self::validate_message(&message)?;
 
-        let mut ids = Vec::new(&env);
-        let timestamp = env.ledger().timestamp();
-        let recipient_count = rule.recipients.len();
+        Ok(Self::fire_rule(&env, &sender, &rule, reference_id, message))
+    }
 
-        for recipient in rule.recipients.iter() {
-            let notif_id = Self::next_notif_id(&env);
-            let status =
-                Self::resolve_status(&env, &recipient, NotificationType::Custom, rule.priority);
-            let notif = Notification {
-                id: notif_id,
-                recipient: recipient.clone(),
-                sender: sender.clone(),
-                notif_type: NotificationType::Custom,
-                priority: rule.priority,
-                status,
-                title: rule.name.clone(),
-                message: message.clone(),
-                reference_id,
-                created_at: timestamp,
-                read_at: None,
-                expires_at: None,
+    /// Evaluate all active rules against an incoming event and trigger each
+    /// rule that watches `notif_type` and whose priority is at or below the
+    /// event `priority`. Caller must be admin or an authorised sender.
+    /// Returns the IDs of the rules that fired.
+    pub fn evaluate_and_trigger(
+        env: Env,
+        sender: Address,
+        notif_type: u32,
+        priority: AlertPriority,
+        reference_id: Option<u64>,
+    ) -> Result<Vec<u64>, Error> {
+        Self::require_initialized(&env)?;
+        sender.require_auth();
+        Self::require_authorized(&env, &sender)?;
+        Self::check_and_update_sender_rate(&env, &sender)?;
+
+        let mut triggered = Vec::new(&env);
+        for rule_id in Self::read_active_rule_ids(&env).iter() {
+            let rule = match env
+                .storage()
+                .persistent()
+                .get::<DataKey, AlertRule>(&DataKey::AlertRule(rule_id))
+            {
+                Some(r) => r,
+                None => continue,
             };
-            Self::store_notification(&env, notif.clone());
-            Self::increment_analytics(&env, NotificationType::Custom, rule.priority, status);
-            events::emit_notification_created(
-                &env,
-                notif_id,
-                recipient,
-                sender.clone(),
-                NotificationType::Custom as u32,
-                rule.priority as u32,
-                reference_id,
-            );
-            ids.push_back(notif_id);
+            if !rule.is_active
+                || rule.watches_type != notif_type
+                || (rule.priority as u32) > (priority as u32)
+            {
+                continue;
+            }
+            Self::fire_rule(&env, &sender, &rule, reference_id, rule.name.clone());
+            triggered.push_back(rule_id);
         }
-
-        events::emit_alert_triggered(&env, rule_id, sender, recipient_count, reference_id);
-        Ok(ids)
+        Ok(triggered)
     }
 
     // ------------------------------------------------------------------
@@ -822,6 +1299,22 @@ impl NotificationContract {
         Ok(())
     }
 
+    /// Set the locale used by `create_from_template` when the requested locale
+    /// has no template. Admin only.
+    pub fn set_default_locale(env: Env, caller: Address, locale: String) -> Result<(), Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if locale.len() > MAX_LOCALE_LEN {
+            return Err(Error::LocaleTooLong);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultLocale, &locale);
+        Ok(())
+    }
+
     /// Retrieve a template by notification type and locale.
     pub fn get_template(
         env: Env,
@@ -897,6 +1390,35 @@ impl NotificationContract {
         })
     }
 
+    /// Per-day send counts for `start_day..=end_day`, where a day is
+    /// `timestamp / 86400`. Days with no sends are returned as zero.
+    /// The range may span at most MAX_ANALYTICS_DAYS.
+    pub fn get_analytics_range(
+        env: Env,
+        caller: Address,
+        start_day: u64,
+        end_day: u64,
+    ) -> Result<Vec<(u64, u64)>, Error> {
+        Self::require_initialized(&env)?;
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if start_day > end_day || end_day - start_day >= MAX_ANALYTICS_DAYS {
+            return Err(Error::InvalidTimeRange);
+        }
+
+        let mut buckets: Vec<(u64, u64)> = Vec::new(&env);
+        for day in start_day..=end_day {
+            let count: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::DailySent(day))
+                .unwrap_or(0);
+            buckets.push_back((day, count));
+        }
+        Ok(buckets)
+    }
+
     // ------------------------------------------------------------------
     // Private helpers
     // ------------------------------------------------------------------
@@ -911,33 +1433,23 @@ impl NotificationContract {
 
     #[must_use]
     fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
-        if !Self::is_admin(env, caller) {
-            return Err(Error::Unauthorized);
-        }
-        Ok(())
+        ADMIN
+            .require_admin(env, caller)
+            .map_err(|_| Error::Unauthorized)
     }
 
     /// Caller must be admin OR in the authorised-senders list.
     #[must_use]
     fn require_authorized(env: &Env, caller: &Address) -> Result<(), Error> {
-        if Self::is_admin(env, caller) {
-            return Ok(());
-        }
-        if Self::is_authorized_sender(env, caller) {
-            return Ok(());
-        }
-        Err(Error::SenderNotAuthorized)
+        RoleGuard::new(|env: &Env, addr: &Address| {
+            Self::is_admin(env, addr) || Self::is_authorized_sender(env, addr)
+        })
+        .require(env, caller)
+        .map_err(|_| Error::SenderNotAuthorized)
     }
 
     fn is_admin(env: &Env, addr: &Address) -> bool {
-        match env
-            .storage()
-            .instance()
-            .get::<DataKey, Address>(&DataKey::Admin)
-        {
-            Some(admin) => admin == *addr,
-            None => false,
-        }
+        ADMIN.is_admin(env, addr)
     }
 
     fn is_authorized_sender(env: &Env, addr: &Address) -> bool {
@@ -946,10 +1458,7 @@ impl NotificationContract {
 
     #[must_use]
     fn read_admin(env: &Env) -> Result<Address, Error> {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)
+        ADMIN.read(env).map_err(|_| Error::NotInitialized)
     }
 
     fn read_authorized_senders(env: &Env) -> Vec<Address> {
@@ -987,6 +1496,176 @@ impl NotificationContract {
         id
     }
 
+    // ------ Notification creation ------
+
+    /// Assign an ID, resolve the initial status, store, count, and announce a
+    /// notification. Inputs must already be validated.
+    fn insert_notification(
+        env: &Env,
+        sender: Address,
+        recipient: Address,
+        notif_type: NotificationType,
+        priority: AlertPriority,
+        title: String,
+        message: String,
+        reference_id: Option<u64>,
+        expires_at: Option<u64>,
+        item_count: u32,
+    ) -> u64 {
+        let notif_id = Self::next_notif_id(env);
+        let status = Self::resolve_status(env, &recipient, notif_type, priority);
+
+        let notif = Notification {
+            id: notif_id,
+            recipient: recipient.clone(),
+            sender: sender.clone(),
+            notif_type,
+            priority,
+            status,
+            title,
+            message,
+            reference_id,
+            created_at: env.ledger().timestamp(),
+            read_at: None,
+            delivered_at: None,
+            expires_at,
+            item_count,
+            channels: Self::resolve_channels(env, &recipient, priority),
+            snoozed_until: None,
+        };
+
+        Self::store_notification(env, notif);
+        Self::increment_analytics(env, notif_type, priority, status);
+
+        events::emit_notification_created(
+            env,
+            notif_id,
+            recipient,
+            sender,
+            notif_type as u32,
+            priority as u32,
+            reference_id,
+        );
+        notif_id
+    }
+
+    // ------ Alert rules ------
+
+    /// Create one Custom notification per rule recipient and emit the
+    /// rule-triggered event. Returns the created notification IDs.
+    fn fire_rule(
+        env: &Env,
+        sender: &Address,
+        rule: &AlertRule,
+        reference_id: Option<u64>,
+        message: String,
+    ) -> Vec<u64> {
+        let mut ids = Vec::new(env);
+        for recipient in rule.recipients.iter() {
+            let notif_id = Self::insert_notification(
+                env,
+                sender.clone(),
+                recipient,
+                NotificationType::Custom,
+                rule.priority,
+                rule.name.clone(),
+                message.clone(),
+                reference_id,
+                None,
+                1,
+            );
+            ids.push_back(notif_id);
+        }
+        events::emit_alert_triggered(
+            env,
+            rule.id,
+            sender.clone(),
+            rule.recipients.len(),
+            reference_id,
+        );
+        ids
+    }
+
+    // ------ Templates ------
+
+    /// Load the template for `locale`, falling back to the default locale.
+    fn resolve_template(
+        env: &Env,
+        notif_type: u32,
+        locale: String,
+    ) -> Result<NotificationTemplate, Error> {
+        if let Some(t) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Template(notif_type, locale.clone()))
+        {
+            return Ok(t);
+        }
+        let fallback: Option<String> = env.storage().instance().get(&DataKey::DefaultLocale);
+        match fallback {
+            Some(default) if default != locale => env
+                .storage()
+                .persistent()
+                .get(&DataKey::Template(notif_type, default))
+                .ok_or(Error::TemplateNotFound),
+            _ => Err(Error::TemplateNotFound),
+        }
+    }
+
+    /// Substitute `{i}` placeholders in `pattern` with `params[i]`.
+    /// Placeholders without a matching param are kept verbatim.
+    /// Returns `None` if the result would exceed `max_len` bytes.
+    fn interpolate(
+        env: &Env,
+        pattern: &String,
+        params: &Vec<String>,
+        max_len: u32,
+    ) -> Option<String> {
+        let max = max_len.min(MAX_MESSAGE_LEN) as usize;
+        let pattern_len = pattern.len() as usize;
+        if pattern_len > MAX_MESSAGE_LEN as usize {
+            return None;
+        }
+        let mut src = [0u8; MAX_MESSAGE_LEN as usize];
+        pattern.copy_into_slice(&mut src[..pattern_len]);
+
+        let mut out = [0u8; MAX_MESSAGE_LEN as usize];
+        let mut out_len = 0usize;
+        let mut i = 0usize;
+        while i < pattern_len {
+            if src[i] == b'{' {
+                // Parse `{digits}`.
+                let mut j = i + 1;
+                let mut index: u32 = 0;
+                while j < pattern_len && src[j].is_ascii_digit() {
+                    index = index
+                        .saturating_mul(10)
+                        .saturating_add((src[j] - b'0') as u32);
+                    j += 1;
+                }
+                if j > i + 1 && j < pattern_len && src[j] == b'}' {
+                    if let Some(param) = params.get(index) {
+                        let param_len = param.len() as usize;
+                        if out_len + param_len > max {
+                            return None;
+                        }
+                        param.copy_into_slice(&mut out[out_len..out_len + param_len]);
+                        out_len += param_len;
+                        i = j + 1;
+                        continue;
+                    }
+                }
+            }
+            if out_len >= max {
+                return None;
+            }
+            out[out_len] = src[i];
+            out_len += 1;
+            i += 1;
+        }
+        Some(String::from_bytes(env, &out[..out_len]))
+    }
+
     // ------ Notification storage (ring-buffer eviction) ------
 
     fn store_notification(env: &Env, notif: Notification) {
@@ -1079,6 +1758,38 @@ impl NotificationContract {
             .unwrap_or_else(|| Vec::new(env))
     }
 
+    // ------ Expiry ------
+
+    /// True if `notif` is still unread but its `expires_at` has passed.
+    fn is_expired_unread(notif: &Notification, now: u64) -> bool {
+        matches!(
+            notif.status,
+            NotificationStatus::Pending | NotificationStatus::Delivered
+        ) && notif.expires_at.is_some_and(|t| now >= t)
+    }
+
+    /// True if `notif` is unread and still inside its snooze window.
+    fn is_snoozed(notif: &Notification, now: u64) -> bool {
+        matches!(
+            notif.status,
+            NotificationStatus::Pending | NotificationStatus::Delivered
+        ) && notif.snoozed_until.is_some_and(|t| now < t)
+    }
+
+    /// Status as seen by queries: expired unread notifications read as
+    /// Archived, and delivered ones that woke from a snooze read as Pending.
+    fn effective_status(notif: &Notification, now: u64) -> NotificationStatus {
+        if Self::is_expired_unread(notif, now) {
+            NotificationStatus::Archived
+        } else if notif.status == NotificationStatus::Delivered
+            && notif.snoozed_until.is_some_and(|t| now >= t)
+        {
+            NotificationStatus::Pending
+        } else {
+            notif.status
+        }
+    }
+
     // ------ Delivery resolution ------
 
     /// Determines the initial `NotificationStatus` for a new notification
@@ -1087,6 +1798,7 @@ impl NotificationContract {
     /// - Critical priority → always `Pending` (bypasses all filters).
     /// - No preferences stored → `Pending` (opt-in by default).
     /// - Filtered by preferences → `Archived` (kept for history but hidden).
+    /// - Inside the recipient's quiet hours → `Deferred`.
     fn resolve_status(
         env: &Env,
         recipient: &Address,
@@ -1125,11 +1837,64 @@ impl NotificationContract {
                         return NotificationStatus::Archived;
                     }
                 }
+                if Self::in_quiet_hours(&prefs, env.ledger().timestamp()) {
+                    return NotificationStatus::Deferred;
+                }
                 NotificationStatus::Pending
             },
         }
     }
 
+    /// Channels a new notification should be dispatched to.
+    ///
+    /// - Critical priority → every channel.
+    /// - No preferences stored → `OnChain` only.
+    /// - Otherwise the preferred `channel`, then any extra `channels`.
+    fn resolve_channels(
+        env: &Env,
+        recipient: &Address,
+        priority: AlertPriority,
+    ) -> Vec<NotificationChannel> {
+        let mut channels = Vec::new(env);
+        if priority == AlertPriority::Critical {
+            channels.push_back(NotificationChannel::OnChain);
+            channels.push_back(NotificationChannel::External);
+            return channels;
+        }
+        let prefs_opt: Option<NotificationPreferences> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserPrefs(recipient.clone()));
+
+        match prefs_opt {
+            None => channels.push_back(NotificationChannel::OnChain),
+            Some(prefs) => {
+                channels.push_back(prefs.channel);
+                for channel in prefs.channels.iter() {
+                    if !channels.contains(channel) {
+                        channels.push_back(channel);
+                    }
+                }
+            },
+        }
+        channels
+    }
+
+    /// True if `now` falls inside the preferences' daily quiet window.
+    fn in_quiet_hours(prefs: &NotificationPreferences, now: u64) -> bool {
+        let start = prefs.quiet_start_secs;
+        let end = prefs.quiet_end_secs;
+        if start == end {
+            return false;
+        }
+        let time_of_day = (now % SECS_PER_DAY as u64) as u32;
+        if start < end {
+            time_of_day >= start && time_of_day < end
+        } else {
+            time_of_day >= start || time_of_day < end
+        }
+    }
+
     // ------ Analytics counters ------
 
     fn increment_analytics(
@@ -1165,6 +1930,15 @@ impl NotificationContract {
             .saturating_add(1);
         env.storage().persistent().set(&DataKey::TotalSent, &total);
 
+        let day_key = DataKey::DailySent(env.ledger().timestamp() / SECS_PER_DAY as u64);
+        let day_count: u64 = env
+            .storage()
+            .persistent()
+            .get(&day_key)
+            .unwrap_or(0u64)
+            .saturating_add(1);
+        env.storage().persistent().set(&day_key, &day_count);
+
         if matches!(
             status,
             NotificationStatus::Pending | NotificationStatus::Delivered
@@ -1238,14 +2012,23 @@ impl NotificationContract {
                     window_start: now,
                 });
 
-        let (count, window_start) = if now.saturating_sub(entry.window_start) >= RATE_WINDOW_SECS {
+        let (max_calls, window_secs) = match env
+            .storage()
+            .persistent()
+            .get::<DataKey, types::SenderRateConfig>(&DataKey::SenderRateConfig(sender.clone()))
+        {
+            Some(cfg) => (cfg.max_calls, cfg.window_secs),
+            None => (MAX_SENDER_CALLS, RATE_WINDOW_SECS),
+        };
+
+        let (count, window_start) = if now.saturating_sub(entry.window_start) >= window_secs {
             // Window expired — reset counter.
             (0u32, now)
         } else {
             (entry.count, entry.window_start)
         };
 
-        if count >= MAX_SENDER_CALLS {
+        if count >= max_calls {
             return Err(Error::RateLimitExceeded);
         }
 
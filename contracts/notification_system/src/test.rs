@@ -1,7 +1,7 @@
 #![allow(clippy::unwrap_used)]
 
 use soroban_sdk::{
-    testutils::{Address as _, Events},
+    testutils::{Address as _, Events, Ledger},
     Address, Env, String, Vec,
 };
 
@@ -32,7 +32,10 @@ fn make_prefs(env: &Env, enabled: bool, min_priority: AlertPriority) -> Notifica
         enabled,
         min_priority,
         channel: NotificationChannel::OnChain,
+        channels: Vec::new(env),
         enabled_types: Vec::new(env),
+        quiet_start_secs: 0,
+        quiet_end_secs: 0,
         updated_at: 0,
     }
 }
@@ -325,7 +328,7 @@ fn test_bulk_creates_one_per_recipient() {
     recipients.push_back(r2.clone());
     recipients.push_back(r3.clone());
 
-    let ids = client.create_bulk_notifications(
+    let result = client.create_bulk_notifications(
         &admin,
         &recipients,
         &NotificationType::SystemAlert,
@@ -335,12 +338,48 @@ fn test_bulk_creates_one_per_recipient() {
         &None,
         &None,
     );
-    assert_eq!(ids.len(), 3);
+    assert_eq!(result.ids.len(), 3);
+    assert_eq!(result.delivered, 3);
+    assert_eq!(result.suppressed, 0);
     assert_eq!(client.get_unread_count(&r1), 1);
     assert_eq!(client.get_unread_count(&r2), 1);
     assert_eq!(client.get_unread_count(&r3), 1);
 }
 
+#[test]
+fn test_bulk_reports_preference_suppression() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    env.mock_all_auths();
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let mut prefs = make_prefs(&env, true, AlertPriority::Low);
+    prefs
+        .enabled_types
+        .push_back(NotificationType::RecordCreated as u32);
+    client.set_preferences(&r2, &prefs);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(r1.clone());
+    recipients.push_back(r2.clone());
+    let result = client.create_bulk_notifications(
+        &admin,
+        &recipients,
+        &NotificationType::SystemAlert,
+        &AlertPriority::Medium,
+        &s(&env, "Alert"),
+        &s(&env, "Maintenance"),
+        &None,
+        &None,
+    );
+    assert_eq!(result.ids.len(), 2);
+    assert_eq!(result.delivered, 1);
+    assert_eq!(result.suppressed, 1);
+    assert_eq!(client.get_unread_count(&r1), 1);
+    assert_eq!(client.get_unread_count(&r2), 0);
+}
+
 #[test]
 fn test_bulk_empty_recipients_fails() {
     let env = Env::default();
@@ -361,6 +400,71 @@ fn test_bulk_empty_recipients_fails() {
     ));
 }
 
+// ==================== Digests ====================
+
+#[test]
+fn test_digest_counts_once_and_keeps_item_count() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut summaries = Vec::new(&env);
+    summaries.push_back(s(&env, "Record 1 updated"));
+    summaries.push_back(s(&env, "Record 2 updated"));
+    summaries.push_back(s(&env, "Record 3 updated"));
+    let id = client.create_digest(
+        &admin,
+        &user,
+        &NotificationType::RecordUpdated,
+        &AlertPriority::Low,
+        &summaries,
+    );
+
+    assert_eq!(client.get_unread_count(&user), 1);
+    let notif = client.get_notification(&user, &id);
+    assert_eq!(notif.item_count, 3);
+    assert_eq!(
+        notif.message,
+        s(&env, "Record 1 updated\nRecord 2 updated\nRecord 3 updated")
+    );
+    assert_eq!(client.get_analytics(&admin).total_sent, 1);
+}
+
+#[test]
+fn test_digest_rejects_empty_and_oversized_batches() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    assert!(matches!(
+        client.try_create_digest(
+            &admin,
+            &user,
+            &NotificationType::RecordUpdated,
+            &AlertPriority::Low,
+            &Vec::new(&env),
+        ),
+        Err(Ok(Error::SummariesEmpty))
+    ));
+
+    let mut summaries = Vec::new(&env);
+    for _ in 0..21u32 {
+        summaries.push_back(s(&env, "x"));
+    }
+    assert!(matches!(
+        client.try_create_digest(
+            &admin,
+            &user,
+            &NotificationType::RecordUpdated,
+            &AlertPriority::Low,
+            &summaries,
+        ),
+        Err(Ok(Error::BatchTooLarge))
+    ));
+}
+
 // ==================== Notification Retrieval ====================
 
 #[test]
@@ -503,6 +607,71 @@ fn test_get_notifications_second_page() {
     assert!(!page.has_more);
 }
 
+#[test]
+fn test_get_notifications_by_reference_isolates_reference() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    for reference_id in [42u64, 7, 42, 7, 42] {
+        client.create_notification(
+            &admin,
+            &user,
+            &NotificationType::RecordUpdated,
+            &AlertPriority::Medium,
+            &s(&env, "Record"),
+            &s(&env, "Updated"),
+            &Some(reference_id),
+            &None,
+        );
+    }
+    client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::RecordCreated,
+        &AlertPriority::Low,
+        &s(&env, "Unrelated"),
+        &s(&env, "No reference"),
+        &None,
+        &None,
+    );
+
+    let page = client.get_notifications_by_reference(&user, &user, &42, &0, &10);
+    assert_eq!(page.total, 3);
+    assert_eq!(page.notifications.len(), 3);
+    assert!(!page.has_more);
+    for n in page.notifications.iter() {
+        assert_eq!(n.reference_id, Some(42));
+    }
+
+    let page = client.get_notifications_by_reference(&user, &user, &7, &0, &1);
+    assert_eq!(page.total, 2);
+    assert_eq!(page.notifications.len(), 1);
+    assert!(page.has_more);
+    let second = client.get_notifications_by_reference(&admin, &user, &7, &1, &1);
+    assert_eq!(second.notifications.len(), 1);
+    assert!(!second.has_more);
+    assert!(second.notifications.get(0).unwrap().id < page.notifications.get(0).unwrap().id);
+
+    // Snoozed notifications drop out of the reference listing too.
+    let newest = client.get_notifications_by_reference(&user, &user, &42, &0, &1);
+    let until = env.ledger().timestamp() + 100;
+    client.snooze_notification(&user, &newest.notifications.get(0).unwrap().id, &until);
+    assert_eq!(
+        client
+            .get_notifications_by_reference(&user, &user, &42, &0, &10)
+            .total,
+        2
+    );
+
+    let stranger = Address::generate(&env);
+    assert!(matches!(
+        client.try_get_notifications_by_reference(&stranger, &user, &42, &0, &10),
+        Err(Ok(Error::Unauthorized))
+    ));
+}
+
 // ==================== Read / Archive ====================
 
 #[test]
@@ -647,7 +816,307 @@ fn test_non_recipient_cannot_archive() {
         &None,
     );
     assert!(matches!(
-        client.try_archive_notification(&stranger, &id),
+        client.try_archive_notification(&stranger, &id),
+        Err(Ok(Error::Unauthorized))
+    ));
+}
+
+#[test]
+fn test_delivery_receipt_lifecycle() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+    client.add_authorized_sender(&admin, &sender);
+
+    let id = client.create_notification(
+        &sender,
+        &user,
+        &NotificationType::AccessGranted,
+        &AlertPriority::Medium,
+        &s(&env, "Access"),
+        &s(&env, "Granted"),
+        &None,
+        &None,
+    );
+    assert!(matches!(
+        client.try_mark_delivered(&Address::generate(&env), &id),
+        Err(Ok(Error::Unauthorized))
+    ));
+
+    client.mark_delivered(&sender, &id);
+    let notif = client.get_notification(&user, &id);
+    assert_eq!(notif.status, NotificationStatus::Delivered);
+    assert!(notif.delivered_at.is_some());
+    assert!(notif.read_at.is_none());
+    assert_eq!(client.get_unread_count(&user), 1);
+
+    client.mark_read(&user, &id);
+    assert_eq!(client.get_unread_count(&user), 0);
+    assert!(matches!(
+        client.try_mark_delivered(&sender, &id),
+        Err(Ok(Error::AlreadyRead))
+    ));
+}
+
+#[test]
+fn test_mark_read_batch_skips_already_read() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..4u32 {
+        ids.push_back(client.create_notification(
+            &admin,
+            &user,
+            &NotificationType::RecordCreated,
+            &AlertPriority::Low,
+            &s(&env, "T"),
+            &s(&env, "B"),
+            &None,
+            &None,
+        ));
+    }
+    client.mark_read(&user, &ids.get(0).unwrap());
+    client.archive_notification(&user, &ids.get(1).unwrap());
+    assert_eq!(client.get_unread_count(&user), 2);
+
+    let mut batch = Vec::new(&env);
+    batch.push_back(ids.get(0).unwrap());
+    batch.push_back(ids.get(1).unwrap());
+    batch.push_back(ids.get(2).unwrap());
+    assert_eq!(client.mark_read_batch(&user, &batch), 1);
+    assert_eq!(client.get_unread_count(&user), 1);
+    assert_eq!(
+        client.get_notification(&user, &ids.get(2).unwrap()).status,
+        NotificationStatus::Read
+    );
+    assert_eq!(
+        client.get_notification(&user, &ids.get(3).unwrap()).status,
+        NotificationStatus::Pending
+    );
+}
+
+#[test]
+fn test_mark_read_batch_rejects_foreign_ids() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    env.mock_all_auths();
+
+    let own = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::RecordCreated,
+        &AlertPriority::Low,
+        &s(&env, "T"),
+        &s(&env, "B"),
+        &None,
+        &None,
+    );
+    let foreign = client.create_notification(
+        &admin,
+        &other,
+        &NotificationType::RecordCreated,
+        &AlertPriority::Low,
+        &s(&env, "T"),
+        &s(&env, "B"),
+        &None,
+        &None,
+    );
+    let mut batch = Vec::new(&env);
+    batch.push_back(own);
+    batch.push_back(foreign);
+    assert!(matches!(
+        client.try_mark_read_batch(&user, &batch),
+        Err(Ok(Error::Unauthorized))
+    ));
+    assert_eq!(client.get_unread_count(&user), 1);
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..51u32 {
+        too_many.push_back(own);
+    }
+    assert!(matches!(
+        client.try_mark_read_batch(&user, &too_many),
+        Err(Ok(Error::BatchTooLarge))
+    ));
+}
+
+// ==================== Snooze ====================
+
+#[test]
+fn test_snoozed_notification_hidden_until_wake() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let id = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::RecordCreated,
+        &AlertPriority::Medium,
+        &s(&env, "Later"),
+        &s(&env, "Remind me later"),
+        &None,
+        &None,
+    );
+    client.mark_delivered(&admin, &id);
+    client.snooze_notification(&user, &id, &2_000);
+
+    assert_eq!(
+        client
+            .get_notifications(&user, &user, &all_filter(10, 0))
+            .total,
+        0
+    );
+    for status in [NotificationStatus::Pending, NotificationStatus::Delivered] {
+        assert_eq!(
+            client
+                .get_notifications(&user, &user, &status_filter(status, 10))
+                .total,
+            0
+        );
+    }
+    assert_eq!(
+        client.try_mark_delivered(&admin, &id),
+        Err(Ok(Error::AlreadyDelivered))
+    );
+    assert_eq!(client.get_unread_count(&user), 1);
+    let snoozed = client.get_snoozed(&user);
+    assert_eq!(snoozed.len(), 1);
+    assert_eq!(snoozed.get(0).unwrap().id, id);
+
+    env.ledger().set_timestamp(2_000);
+    let page = client.get_notifications(&user, &user, &all_filter(10, 0));
+    assert_eq!(page.total, 1);
+    let woken = page.notifications.get(0).unwrap();
+    assert_eq!(woken.status, NotificationStatus::Pending);
+    assert!(woken.delivered_at.is_some());
+    assert!(client.get_snoozed(&user).is_empty());
+    assert_eq!(
+        client.try_mark_delivered(&admin, &id),
+        Err(Ok(Error::AlreadyDelivered))
+    );
+}
+
+#[test]
+fn test_snooze_rejects_read_and_archived() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut ids = [0u64; 3];
+    for id in ids.iter_mut() {
+        *id = client.create_notification(
+            &admin,
+            &user,
+            &NotificationType::RecordUpdated,
+            &AlertPriority::Low,
+            &s(&env, "T"),
+            &s(&env, "B"),
+            &None,
+            &None,
+        );
+    }
+    let [read_id, archived_id, pending_id] = ids;
+    client.mark_read(&user, &read_id);
+    client.archive_notification(&user, &archived_id);
+
+    let until = env.ledger().timestamp() + 100;
+    assert_eq!(
+        client.try_snooze_notification(&user, &read_id, &until),
+        Err(Ok(Error::AlreadyRead))
+    );
+    assert_eq!(
+        client.try_snooze_notification(&user, &archived_id, &until),
+        Err(Ok(Error::AlreadyArchived))
+    );
+    assert_eq!(
+        client.try_snooze_notification(&admin, &pending_id, &until),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_snooze_notification(&user, &pending_id, &env.ledger().timestamp()),
+        Err(Ok(Error::InvalidTimeRange))
+    );
+
+    // A snoozed Pending notification cannot be delivered until it wakes.
+    client.snooze_notification(&user, &pending_id, &until);
+    assert_eq!(
+        client.try_mark_delivered(&admin, &pending_id),
+        Err(Ok(Error::NotificationSnoozed))
+    );
+}
+
+// ==================== Expiry ====================
+
+#[test]
+fn test_expired_notification_drops_out_of_unread() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.ledger().set_timestamp(1_000);
+    let short = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::AccessRequested,
+        &AlertPriority::Medium,
+        &s(&env, "Access"),
+        &s(&env, "Expires soon"),
+        &None,
+        &Some(1_060),
+    );
+    client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::AccessRequested,
+        &AlertPriority::Medium,
+        &s(&env, "Access"),
+        &s(&env, "No expiry"),
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_unread_count(&user), 2);
+
+    env.ledger().set_timestamp(1_061);
+    assert_eq!(client.get_unread_count(&user), 1);
+    let page = client.get_notifications(&user, &user, &all_filter(10, 0));
+    assert_eq!(page.total, 1);
+    let archived = client.get_notifications(
+        &user,
+        &user,
+        &status_filter(NotificationStatus::Archived, 10),
+    );
+    assert_eq!(archived.notifications.get(0).unwrap().id, short);
+
+    assert_eq!(client.sweep_expired(&user, &user), 1);
+    assert_eq!(
+        client.get_notification(&user, &short).status,
+        NotificationStatus::Archived
+    );
+    assert_eq!(client.get_unread_count(&user), 1);
+    assert_eq!(client.sweep_expired(&user, &user), 0);
+}
+
+#[test]
+fn test_sweep_expired_by_stranger_fails() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    assert!(matches!(
+        client.try_sweep_expired(&Address::generate(&env), &user),
         Err(Ok(Error::Unauthorized))
     ));
 }
@@ -680,6 +1149,73 @@ fn test_low_priority_filtered_below_threshold() {
     assert_eq!(client.get_unread_count(&user), 0);
 }
 
+#[test]
+fn test_critical_fans_out_to_all_channels() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut prefs = make_prefs(&env, true, AlertPriority::Low);
+    prefs.channel = NotificationChannel::External;
+    client.set_preferences(&user, &prefs);
+
+    let low = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::RecordUpdated,
+        &AlertPriority::Low,
+        &s(&env, "Update"),
+        &s(&env, "Record updated"),
+        &None,
+        &None,
+    );
+    let critical = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::EmergencyAccessGranted,
+        &AlertPriority::Critical,
+        &s(&env, "Emergency"),
+        &s(&env, "Emergency access"),
+        &None,
+        &None,
+    );
+
+    assert_eq!(
+        client.get_notification(&user, &low).channels,
+        Vec::from_array(&env, [NotificationChannel::External])
+    );
+    assert_eq!(
+        client.get_notification(&user, &critical).channels,
+        Vec::from_array(
+            &env,
+            [NotificationChannel::OnChain, NotificationChannel::External]
+        )
+    );
+}
+
+#[test]
+fn test_preferences_reject_too_many_channels() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut prefs = make_prefs(&env, true, AlertPriority::Low);
+    prefs.channels = Vec::from_array(
+        &env,
+        [
+            NotificationChannel::OnChain,
+            NotificationChannel::External,
+            NotificationChannel::OnChain,
+        ],
+    );
+    assert_eq!(
+        client.try_set_preferences(&user, &prefs),
+        Err(Ok(Error::TooManyChannels))
+    );
+}
+
 #[test]
 fn test_critical_bypasses_disabled_preferences() {
     let env = Env::default();
@@ -719,7 +1255,10 @@ fn test_type_filter_allows_opted_types_only() {
         enabled: true,
         min_priority: AlertPriority::Low,
         channel: NotificationChannel::OnChain,
+        channels: Vec::new(&env),
         enabled_types,
+        quiet_start_secs: 0,
+        quiet_end_secs: 0,
         updated_at: 0,
     };
     client.set_preferences(&user, &prefs);
@@ -757,6 +1296,75 @@ fn test_type_filter_allows_opted_types_only() {
     );
 }
 
+#[test]
+fn test_quiet_hours_defer_non_critical_notifications() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    // Quiet from 22:00 to 06:00 UTC.
+    let mut prefs = make_prefs(&env, true, AlertPriority::Low);
+    prefs.quiet_start_secs = 22 * 3_600;
+    prefs.quiet_end_secs = 6 * 3_600;
+    client.set_preferences(&user, &prefs);
+
+    env.ledger().set_timestamp(23 * 3_600);
+    let high = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::RiskScoreAlert,
+        &AlertPriority::High,
+        &s(&env, "Risk"),
+        &s(&env, "Score changed"),
+        &None,
+        &None,
+    );
+    let critical = client.create_notification(
+        &admin,
+        &user,
+        &NotificationType::EmergencyAccessGranted,
+        &AlertPriority::Critical,
+        &s(&env, "Emergency"),
+        &s(&env, "Emergency access"),
+        &None,
+        &None,
+    );
+    assert_eq!(
+        client.get_notification(&user, &high).status,
+        NotificationStatus::Deferred
+    );
+    assert_eq!(
+        client.get_notification(&user, &critical).status,
+        NotificationStatus::Pending
+    );
+    assert_eq!(client.get_unread_count(&user), 1);
+    assert_eq!(client.release_deferred(&user), 0);
+
+    env.ledger().set_timestamp(86_400 + 7 * 3_600);
+    assert_eq!(client.release_deferred(&user), 1);
+    assert_eq!(
+        client.get_notification(&user, &high).status,
+        NotificationStatus::Pending
+    );
+    assert_eq!(client.get_unread_count(&user), 2);
+}
+
+#[test]
+fn test_quiet_hours_out_of_range_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut prefs = make_prefs(&env, true, AlertPriority::Low);
+    prefs.quiet_start_secs = 86_400;
+    assert!(matches!(
+        client.try_set_preferences(&user, &prefs),
+        Err(Ok(Error::InvalidQuietHours))
+    ));
+}
+
 // ==================== Alert Rules ====================
 
 #[test]
@@ -906,6 +1514,50 @@ fn test_trigger_rule_with_no_recipients_returns_empty() {
     );
 }
 
+#[test]
+fn test_evaluate_and_trigger_fires_only_matching_rules() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let watcher = Address::generate(&env);
+    env.mock_all_auths();
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(watcher.clone());
+
+    let anomaly_rule = client.create_alert_rule(
+        &admin,
+        &s(&env, "Anomaly"),
+        &(NotificationType::AnomalyDetected as u32),
+        &AlertPriority::Medium,
+        &recipients,
+    );
+    client.create_alert_rule(
+        &admin,
+        &s(&env, "Access"),
+        &(NotificationType::AccessRequested as u32),
+        &AlertPriority::Low,
+        &recipients,
+    );
+    // Same type, but demands a higher priority than the event carries.
+    client.create_alert_rule(
+        &admin,
+        &s(&env, "Critical anomaly"),
+        &(NotificationType::AnomalyDetected as u32),
+        &AlertPriority::Critical,
+        &recipients,
+    );
+
+    let fired = client.evaluate_and_trigger(
+        &admin,
+        &(NotificationType::AnomalyDetected as u32),
+        &AlertPriority::High,
+        &Some(7u64),
+    );
+    assert_eq!(fired.len(), 1);
+    assert_eq!(fired.get(0).unwrap(), anomaly_rule);
+    assert_eq!(client.get_unread_count(&watcher), 1);
+}
+
 // ==================== Templates ====================
 
 #[test]
@@ -980,6 +1632,113 @@ fn test_multiple_locale_templates_are_independent() {
     );
 }
 
+#[test]
+fn test_create_from_template_substitutes_params() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_template(
+        &admin,
+        &NotificationTemplate {
+            notif_type: NotificationType::RecordUpdated as u32,
+            locale: s(&env, "en"),
+            title: s(&env, "Record {0} updated"),
+            message: s(&env, "{1}, record {0} has changed."),
+            default_priority: AlertPriority::High,
+            updated_at: 0,
+        },
+    );
+
+    let mut params = Vec::new(&env);
+    params.push_back(s(&env, "42"));
+    params.push_back(s(&env, "Alice"));
+    let id = client.create_from_template(
+        &admin,
+        &user,
+        &NotificationType::RecordUpdated,
+        &s(&env, "en"),
+        &params,
+    );
+    let notif = client.get_notification(&user, &id);
+    assert_eq!(notif.title, s(&env, "Record 42 updated"));
+    assert_eq!(notif.message, s(&env, "Alice, record 42 has changed."));
+    assert_eq!(notif.priority, AlertPriority::High);
+
+    // Unknown locale falls back to the configured default.
+    client.set_default_locale(&admin, &s(&env, "en"));
+    let id = client.create_from_template(
+        &admin,
+        &user,
+        &NotificationType::RecordUpdated,
+        &s(&env, "fr"),
+        &params,
+    );
+    assert_eq!(
+        client.get_notification(&user, &id).title,
+        s(&env, "Record 42 updated")
+    );
+}
+
+#[test]
+fn test_create_from_template_rejects_overflow_and_missing_locale() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_template(
+        &admin,
+        &NotificationTemplate {
+            notif_type: NotificationType::SystemAlert as u32,
+            locale: s(&env, "en"),
+            title: s(&env, "Alert: {0}"),
+            message: s(&env, "{0}"),
+            default_priority: AlertPriority::Low,
+            updated_at: 0,
+        },
+    );
+
+    let mut params = Vec::new(&env);
+    params.push_back(s(&env, &"x".repeat(95)));
+    assert!(matches!(
+        client.try_create_from_template(
+            &admin,
+            &user,
+            &NotificationType::SystemAlert,
+            &s(&env, "en"),
+            &params,
+        ),
+        Err(Ok(Error::TitleTooLong))
+    ));
+    assert!(matches!(
+        client.try_create_from_template(
+            &admin,
+            &user,
+            &NotificationType::SystemAlert,
+            &s(&env, "de"),
+            &params,
+        ),
+        Err(Ok(Error::TemplateNotFound))
+    ));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..11u32 {
+        too_many.push_back(s(&env, "x"));
+    }
+    assert!(matches!(
+        client.try_create_from_template(
+            &admin,
+            &user,
+            &NotificationType::SystemAlert,
+            &s(&env, "en"),
+            &too_many,
+        ),
+        Err(Ok(Error::TooManyTemplateParams))
+    ));
+}
+
 // ==================== Analytics ====================
 
 #[test]
@@ -1116,6 +1875,51 @@ fn test_non_admin_cannot_get_analytics() {
     ));
 }
 
+#[test]
+fn test_analytics_range_buckets_sends_by_day() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    // Day 10: 1 send, day 11: 3 sends, day 12: none, day 13: 2 sends.
+    for (day, sends) in [(10u64, 1u32), (11, 3), (13, 2)] {
+        env.ledger().set_timestamp(day * 86_400 + 120);
+        for _ in 0..sends {
+            client.create_notification(
+                &admin,
+                &user,
+                &NotificationType::RecordCreated,
+                &AlertPriority::Low,
+                &s(&env, "T"),
+                &s(&env, "B"),
+                &None,
+                &None,
+            );
+        }
+    }
+
+    let buckets = client.get_analytics_range(&admin, &10, &13);
+    assert_eq!(buckets.len(), 4);
+    assert_eq!(buckets.get(0).unwrap(), (10, 1));
+    assert_eq!(buckets.get(1).unwrap(), (11, 3));
+    assert_eq!(buckets.get(2).unwrap(), (12, 0));
+    assert_eq!(buckets.get(3).unwrap(), (13, 2));
+
+    assert!(matches!(
+        client.try_get_analytics_range(&admin, &13, &10),
+        Err(Ok(Error::InvalidTimeRange))
+    ));
+    assert!(matches!(
+        client.try_get_analytics_range(&admin, &0, &90),
+        Err(Ok(Error::InvalidTimeRange))
+    ));
+    assert!(matches!(
+        client.try_get_analytics_range(&user, &10, &13),
+        Err(Ok(Error::Unauthorized))
+    ));
+}
+
 // ==================== Rate Limiting ====================
 
 #[test]
@@ -1154,6 +1958,84 @@ fn test_rate_limit_blocks_after_100_calls() {
     ));
 }
 
+#[test]
+fn test_sender_rate_limit_override_raises_quota() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, admin) = setup(&env);
+    let trusted = Address::generate(&env);
+    let third_party = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.add_authorized_sender(&admin, &trusted);
+    client.add_authorized_sender(&admin, &third_party);
+    client.set_sender_rate_limit(&admin, &trusted, &150, &3_600);
+
+    for sender in [trusted.clone(), third_party.clone()] {
+        let user = Address::generate(&env);
+        for _ in 0..100u32 {
+            client.create_notification(
+                &sender,
+                &user,
+                &NotificationType::SystemAlert,
+                &AlertPriority::Low,
+                &s(&env, "T"),
+                &s(&env, "B"),
+                &None,
+                &None,
+            );
+        }
+    }
+
+    let user = Address::generate(&env);
+    assert!(client
+        .try_create_notification(
+            &trusted,
+            &user,
+            &NotificationType::SystemAlert,
+            &AlertPriority::Low,
+            &s(&env, "T"),
+            &s(&env, "B"),
+            &None,
+            &None,
+        )
+        .is_ok());
+    assert!(matches!(
+        client.try_create_notification(
+            &third_party,
+            &user,
+            &NotificationType::SystemAlert,
+            &AlertPriority::Low,
+            &s(&env, "T"),
+            &s(&env, "B"),
+            &None,
+            &None,
+        ),
+        Err(Ok(Error::RateLimitExceeded))
+    ));
+}
+
+#[test]
+fn test_sender_rate_limit_rejects_zero_values() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    env.mock_all_auths();
+
+    assert!(matches!(
+        client.try_set_sender_rate_limit(&admin, &sender, &0, &3_600),
+        Err(Ok(Error::InvalidRateLimit))
+    ));
+    assert!(matches!(
+        client.try_set_sender_rate_limit(&admin, &sender, &10, &0),
+        Err(Ok(Error::InvalidRateLimit))
+    ));
+    assert!(matches!(
+        client.try_set_sender_rate_limit(&sender, &sender, &10, &60),
+        Err(Ok(Error::Unauthorized))
+    ));
+}
+
 // ==================== Integration ====================
 
 #[test]
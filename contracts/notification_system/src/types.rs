@@ -3,7 +3,7 @@ use soroban_sdk::{contracttype, Address, Map, String, Vec};
 // ==================== Channel & Priority ====================
 
 /// Preferred delivery channel for notifications.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[contracttype]
 pub enum NotificationChannel {
     /// Soroban on-chain event log — always available.
@@ -60,6 +60,8 @@ pub enum NotificationStatus {
     Read,
     /// Dismissed / no longer surfaced in default queries.
     Archived,
+    /// Held during the recipient's quiet hours; promoted by `release_deferred`.
+    Deferred,
 }
 
 // ==================== Core Records ====================
@@ -74,8 +76,15 @@ pub struct NotificationPreferences {
     pub min_priority: AlertPriority,
     /// Preferred delivery channel.
     pub channel: NotificationChannel,
+    /// Extra channels to deliver on alongside `channel`.
+    pub channels: Vec<NotificationChannel>,
     /// Opted-in `NotificationType` repr values. Empty = all types allowed.
     pub enabled_types: Vec<u32>,
+    /// Start of the daily quiet window, in seconds since midnight UTC.
+    /// Equal start and end disables quiet hours; start > end wraps midnight.
+    pub quiet_start_secs: u32,
+    /// End (exclusive) of the daily quiet window, in seconds since midnight UTC.
+    pub quiet_end_secs: u32,
     pub updated_at: u64,
 }
 
@@ -97,8 +106,17 @@ pub struct Notification {
     pub reference_id: Option<u64>,
     pub created_at: u64,
     pub read_at: Option<u64>,
+    /// Set when an off-chain delivery agent confirms delivery.
+    pub delivered_at: Option<u64>,
     /// Ledger timestamp after which the notification is considered stale.
     pub expires_at: Option<u64>,
+    /// Number of underlying events this entry represents (> 1 for digests).
+    pub item_count: u32,
+    /// Every channel off-chain agents should dispatch to, without duplicates.
+    /// Critical notifications always carry all channels.
+    pub channels: Vec<NotificationChannel>,
+    /// Hidden from default queries until this ledger timestamp; still unread.
+    pub snoozed_until: Option<u64>,
 }
 
 // ==================== Alert Rules ====================
@@ -163,6 +181,17 @@ pub struct NotificationFilter {
     pub offset: u32,
 }
 
+/// Outcome of `create_bulk_notifications`. `suppressed` counts recipients
+/// whose preferences archived the notification on arrival; `delivered`
+/// counts the rest, including ones deferred by quiet hours.
+#[derive(Clone)]
+#[contracttype]
+pub struct BulkResult {
+    pub ids: Vec<u64>,
+    pub delivered: u32,
+    pub suppressed: u32,
+}
+
 /// Paginated result set returned by `get_notifications`.
 #[derive(Clone)]
 #[contracttype]
@@ -196,3 +225,12 @@ pub struct SenderRateLimit {
     pub count: u32,
     pub window_start: u64,
 }
+
+/// Admin-configured rate limit for a specific sender, replacing the
+/// contract-wide defaults.
+#[derive(Clone)]
+#[contracttype]
+pub struct SenderRateConfig {
+    pub max_calls: u32,
+    pub window_secs: u64,
+}
@@ -0,0 +1,178 @@
+#![no_std]
+
+//! # Access Control Commons
+//!
+//! Shared admin, pause, and role-check primitives so every contract applies
+//! the same semantics:
+//! - **AdminStore**: a single admin address with checked transfer
+//! - **Pausable**: a boolean pause flag with `require_not_paused`
+//! - **RoleGuard**: wraps a contract-supplied role predicate
+//! - **ReentrancyGuard**: a lock held across external contract calls
+//!
+//! Each primitive is bound to a storage key and tier chosen by the calling
+//! contract, so adopting it does not move existing storage entries. All
+//! checks return `Result`; contracts map `AccessError` onto their own error
+//! enum to keep their ABI unchanged.
+
+pub mod reentrancy;
+
+#[cfg(test)]
+mod test;
+
+pub use reentrancy::ReentrancyGuard;
+
+use soroban_sdk::{contracterror, Address, Env, IntoVal, Val};
+
+/// Errors returned by the access-control primitives.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccessError {
+    Unauthorized = 1,
+    NotInitialized = 2,
+    Paused = 3,
+    Reentrant = 4,
+}
+
+/// Storage tier a primitive reads from and writes to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StorageTier {
+    Instance,
+    Persistent,
+}
+
+impl StorageTier {
+    fn get<K, V>(self, env: &Env, key: &K) -> Option<V>
+    where
+        K: IntoVal<Env, Val>,
+        V: soroban_sdk::TryFromVal<Env, Val>,
+    {
+        match self {
+            StorageTier::Instance => env.storage().instance().get(key),
+            StorageTier::Persistent => env.storage().persistent().get(key),
+        }
+    }
+
+    fn set<K, V>(self, env: &Env, key: &K, value: &V)
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+    {
+        match self {
+            StorageTier::Instance => env.storage().instance().set(key, value),
+            StorageTier::Persistent => env.storage().persistent().set(key, value),
+        }
+    }
+}
+
+/// Single admin address stored under a contract-owned key.
+pub struct AdminStore<K> {
+    key: K,
+    tier: StorageTier,
+}
+
+impl<K: IntoVal<Env, Val>> AdminStore<K> {
+    pub const fn new(key: K, tier: StorageTier) -> Self {
+        Self { key, tier }
+    }
+
+    pub fn get(&self, env: &Env) -> Option<Address> {
+        self.tier.get(env, &self.key)
+    }
+
+    pub fn read(&self, env: &Env) -> Result<Address, AccessError> {
+        self.get(env).ok_or(AccessError::NotInitialized)
+    }
+
+    pub fn set(&self, env: &Env, admin: &Address) {
+        self.tier.set(env, &self.key, admin);
+    }
+
+    /// False when no admin has been stored.
+    pub fn is_admin(&self, env: &Env, addr: &Address) -> bool {
+        self.get(env).is_some_and(|admin| admin == *addr)
+    }
+
+    pub fn require_admin(&self, env: &Env, caller: &Address) -> Result<(), AccessError> {
+        if self.is_admin(env, caller) {
+            Ok(())
+        } else {
+            Err(AccessError::Unauthorized)
+        }
+    }
+
+    /// Replaces the admin. `current` must be the stored admin; the caller is
+    /// responsible for `require_auth`.
+    pub fn transfer(
+        &self,
+        env: &Env,
+        current: &Address,
+        new_admin: &Address,
+    ) -> Result<(), AccessError> {
+        self.read(env)?;
+        self.require_admin(env, current)?;
+        self.set(env, new_admin);
+        Ok(())
+    }
+}
+
+/// Boolean pause flag stored under a contract-owned key. A missing entry
+/// reads as unpaused.
+pub struct Pausable<K> {
+    key: K,
+    tier: StorageTier,
+}
+
+impl<K: IntoVal<Env, Val>> Pausable<K> {
+    pub const fn new(key: K, tier: StorageTier) -> Self {
+        Self { key, tier }
+    }
+
+    pub fn is_paused(&self, env: &Env) -> bool {
+        self.tier.get(env, &self.key).unwrap_or(false)
+    }
+
+    pub fn set_paused(&self, env: &Env, paused: bool) {
+        self.tier.set(env, &self.key, &paused);
+    }
+
+    pub fn pause(&self, env: &Env) {
+        self.set_paused(env, true);
+    }
+
+    pub fn unpause(&self, env: &Env) {
+        self.set_paused(env, false);
+    }
+
+    pub fn require_not_paused(&self, env: &Env) -> Result<(), AccessError> {
+        if self.is_paused(env) {
+            Err(AccessError::Paused)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Role check backed by a contract-supplied predicate, e.g. an RBAC lookup
+/// or an allow-list read.
+pub struct RoleGuard<F> {
+    check: F,
+}
+
+impl<F: Fn(&Env, &Address) -> bool> RoleGuard<F> {
+    pub const fn new(check: F) -> Self {
+        Self { check }
+    }
+
+    pub fn has_role(&self, env: &Env, addr: &Address) -> bool {
+        (self.check)(env, addr)
+    }
+
+    pub fn require(&self, env: &Env, caller: &Address) -> Result<(), AccessError> {
+        if self.has_role(env, caller) {
+            Ok(())
+        } else {
+            Err(AccessError::Unauthorized)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{Env, IntoVal, Val};
+
+use crate::AccessError;
+
+/// Contract-wide lock held in instance storage while an external call is in
+/// flight. A nested `enter` before the matching `exit` is rejected.
+///
+/// Storage writes are reverted when the outer invocation fails, so a lock
+/// left behind by an `Err` return never persists.
+pub struct ReentrancyGuard<K> {
+    key: K,
+}
+
+impl<K: IntoVal<Env, Val>> ReentrancyGuard<K> {
+    pub const fn new(key: K) -> Self {
+        Self { key }
+    }
+
+    pub fn is_locked(&self, env: &Env) -> bool {
+        env.storage().instance().get(&self.key).unwrap_or(false)
+    }
+
+    pub fn enter(&self, env: &Env) -> Result<(), AccessError> {
+        if self.is_locked(env) {
+            return Err(AccessError::Reentrant);
+        }
+        env.storage().instance().set(&self.key, &true);
+        Ok(())
+    }
+
+    pub fn exit(&self, env: &Env) {
+        env.storage().instance().remove(&self.key);
+    }
+
+    /// Runs `f` between `enter` and `exit`.
+    pub fn with_guard<R>(&self, env: &Env, f: impl FnOnce() -> R) -> Result<R, AccessError> {
+        self.enter(env)?;
+        let result = f();
+        self.exit(env);
+        Ok(result)
+    }
+}
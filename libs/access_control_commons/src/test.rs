@@ -0,0 +1,136 @@
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+enum TestKey {
+    Admin,
+    Paused,
+    Lock,
+}
+
+const ADMIN: AdminStore<TestKey> = AdminStore::new(TestKey::Admin, StorageTier::Instance);
+const PAUSE: Pausable<TestKey> = Pausable::new(TestKey::Paused, StorageTier::Persistent);
+const LOCK: ReentrancyGuard<TestKey> = ReentrancyGuard::new(TestKey::Lock);
+
+/// Minimal contract providing a registered storage context for testing.
+#[contract]
+struct TestContext;
+
+#[contractimpl]
+impl TestContext {
+    pub fn __stub() {}
+}
+
+fn with_contract<R>(env: &Env, f: impl FnOnce() -> R) -> R {
+    let id = env.register_contract(None, TestContext);
+    env.as_contract(&id, f)
+}
+
+#[test]
+fn test_admin_transfer() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let next = Address::generate(&env);
+    with_contract(&env, || {
+        ADMIN.set(&env, &admin);
+        assert!(ADMIN.is_admin(&env, &admin));
+
+        assert_eq!(ADMIN.transfer(&env, &admin, &next), Ok(()));
+        assert_eq!(ADMIN.read(&env), Ok(next.clone()));
+        assert!(!ADMIN.is_admin(&env, &admin));
+        assert_eq!(
+            ADMIN.require_admin(&env, &admin),
+            Err(AccessError::Unauthorized)
+        );
+        assert_eq!(ADMIN.require_admin(&env, &next), Ok(()));
+    });
+}
+
+#[test]
+fn test_transfer_rejects_non_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let intruder = Address::generate(&env);
+    with_contract(&env, || {
+        ADMIN.set(&env, &admin);
+        assert_eq!(
+            ADMIN.transfer(&env, &intruder, &intruder),
+            Err(AccessError::Unauthorized)
+        );
+        assert_eq!(ADMIN.read(&env), Ok(admin));
+    });
+}
+
+#[test]
+fn test_uninitialized_admin() {
+    let env = Env::default();
+    let anyone = Address::generate(&env);
+    with_contract(&env, || {
+        assert!(!ADMIN.is_admin(&env, &anyone));
+        assert_eq!(ADMIN.read(&env), Err(AccessError::NotInitialized));
+        assert_eq!(
+            ADMIN.transfer(&env, &anyone, &anyone),
+            Err(AccessError::NotInitialized)
+        );
+    });
+}
+
+#[test]
+fn test_pause_toggle() {
+    let env = Env::default();
+    with_contract(&env, || {
+        assert!(!PAUSE.is_paused(&env));
+        assert_eq!(PAUSE.require_not_paused(&env), Ok(()));
+
+        PAUSE.pause(&env);
+        assert!(PAUSE.is_paused(&env));
+        assert_eq!(PAUSE.require_not_paused(&env), Err(AccessError::Paused));
+
+        PAUSE.unpause(&env);
+        assert!(!PAUSE.is_paused(&env));
+        assert_eq!(PAUSE.require_not_paused(&env), Ok(()));
+    });
+}
+
+#[test]
+fn test_role_guard_rejects_unauthorized() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    with_contract(&env, || {
+        ADMIN.set(&env, &admin);
+        let guard = RoleGuard::new(|env: &Env, addr: &Address| ADMIN.is_admin(env, addr));
+        assert_eq!(guard.require(&env, &admin), Ok(()));
+        assert_eq!(
+            guard.require(&env, &outsider),
+            Err(AccessError::Unauthorized)
+        );
+    });
+}
+
+#[test]
+fn test_reentrancy_guard_rejects_nested_entry() {
+    let env = Env::default();
+    with_contract(&env, || {
+        assert_eq!(LOCK.enter(&env), Ok(()));
+        assert_eq!(LOCK.enter(&env), Err(AccessError::Reentrant));
+        assert_eq!(LOCK.with_guard(&env, || ()), Err(AccessError::Reentrant));
+        LOCK.exit(&env);
+        assert!(!LOCK.is_locked(&env));
+    });
+}
+
+#[test]
+fn test_with_guard_releases_lock() {
+    let env = Env::default();
+    with_contract(&env, || {
+        let nested = LOCK.with_guard(&env, || {
+            assert!(LOCK.is_locked(&env));
+            LOCK.with_guard(&env, || ())
+        });
+        assert_eq!(nested, Ok(Err(AccessError::Reentrant)));
+        assert!(!LOCK.is_locked(&env));
+        assert_eq!(LOCK.with_guard(&env, || 7u32), Ok(7));
+    });
+}